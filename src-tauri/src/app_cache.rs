@@ -0,0 +1,163 @@
+use crate::{logger, steam};
+use crate::appinfo_vdf_parser::open_appinfo_vdf;
+
+use std::{path::PathBuf, sync::Mutex, collections::HashMap};
+
+use serde_json::{Map, Value};
+use tauri::{AppHandle, Manager};
+use unicode_normalization::{UnicodeNormalization, char::is_combining_mark};
+
+#[derive(Clone, serde::Serialize)]
+#[allow(non_snake_case)]
+pub struct AppName {
+  appId: u32,
+  name: String
+}
+
+#[derive(Clone, serde::Serialize)]
+#[allow(non_snake_case)]
+pub struct NameIdMaps {
+  pub nameIdMap: Map<String, Value>,
+  pub idNameMap: Map<String, Value>
+}
+
+/// Caches the names of the user's apps so they only need to be read from appinfo.vdf once.
+#[derive(Default)]
+pub struct AppInfoCache {
+  app_names: Mutex<Option<Vec<AppName>>>
+}
+
+/// Gets the names of the apps in the provided appinfo.vdf contents.
+fn get_app_names_from_appinfo(appinfo_vdf: &Map<String, Value>) -> Vec<AppName> {
+  let mut app_names: Vec<AppName> = Vec::new();
+  let entries_val = appinfo_vdf.get("entries");
+
+  if entries_val.is_some() && entries_val.unwrap().is_array() {
+    for entry in entries_val.unwrap().as_array().unwrap().iter() {
+      let appid = entry.get("id").and_then(| id | id.as_u64());
+      let name_val = entry.get("common").and_then(| common | common.get("name"));
+
+      if appid.is_some() && name_val.is_some() {
+        let name_val = name_val.unwrap();
+        let name: String = if name_val.is_string() { name_val.as_str().unwrap().to_owned() } else { name_val.to_string() };
+
+        app_names.push(AppName { appId: appid.unwrap() as u32, name });
+      }
+    }
+  }
+
+  return app_names;
+}
+
+/// Updates the cached app names from the provided appinfo.vdf contents.
+pub fn set_cached_app_names(app_handle: &AppHandle, appinfo_vdf: &Map<String, Value>) {
+  let app_cache = app_handle.state::<AppInfoCache>();
+  let mut app_names = app_cache.app_names.lock().expect("Should have been able to lock the app name cache.");
+  *app_names = Some(get_app_names_from_appinfo(appinfo_vdf));
+}
+
+/// Drops the cached app names so they're read from appinfo.vdf again the next time they're needed.
+pub fn clear_cached_app_names(app_handle: &AppHandle) {
+  let app_cache = app_handle.state::<AppInfoCache>();
+  *app_cache.app_names.lock().expect("Should have been able to lock the app name cache.") = None;
+}
+
+/// Gets the names of the user's apps, reading appinfo.vdf if they haven't been cached yet.
+pub fn get_cached_app_names(app_handle: &AppHandle) -> Vec<AppName> {
+  let app_cache = app_handle.state::<AppInfoCache>();
+  let mut app_names = app_cache.app_names.lock().expect("Should have been able to lock the app name cache.");
+
+  if app_names.is_none() {
+    logger::log_tagged_to_core_file(app_handle, "app_cache", "App names weren't cached. Reading appinfo.vdf...", 0);
+
+    let appinfo_path: PathBuf = PathBuf::from(steam::get_appinfo_path(app_handle.to_owned(), None));
+    let mut names: Vec<AppName> = match open_appinfo_vdf(&appinfo_path) {
+      Ok(appinfo_vdf) => get_app_names_from_appinfo(&appinfo_vdf),
+      Err(err) => {
+        logger::log_tagged_to_core_file(app_handle, "app_cache", format!("Failed to read appinfo.vdf: {}. Only registry app names will be used.", err).as_str(), 2);
+        Vec::new()
+      }
+    };
+
+    for registry_app in steam::get_steam_apps(app_handle.to_owned()).into_iter() {
+      if !names.iter().any(| app | app.appId == registry_app.appId) && !registry_app.name.is_empty() {
+        names.push(AppName { appId: registry_app.appId, name: registry_app.name });
+      }
+    }
+
+    *app_names = Some(names);
+  }
+
+  return app_names.as_ref().unwrap().clone();
+}
+
+/// Gets the names of the user's apps, mapped by appid.
+pub fn get_app_name_map(app_handle: &AppHandle) -> HashMap<u32, String> {
+  return get_cached_app_names(app_handle).into_iter().map(| app | (app.appId, app.name)).collect();
+}
+
+/// Gets the names of the user's apps mapped to their appids, and the reverse, in the shape the import and export commands take.
+/// If several apps share a name, the one listed first in appinfo.vdf keeps it.
+pub fn get_name_id_maps(app_handle: &AppHandle) -> NameIdMaps {
+  let mut name_id_map: Map<String, Value> = Map::new();
+  let mut id_name_map: Map<String, Value> = Map::new();
+
+  for app in get_cached_app_names(app_handle).into_iter() {
+    if !app.name.is_empty() && !name_id_map.contains_key(&app.name) {
+      name_id_map.insert(app.name.to_owned(), Value::String(app.appId.to_string()));
+    }
+
+    id_name_map.insert(app.appId.to_string(), Value::String(app.name));
+  }
+
+  return NameIdMaps { nameIdMap: name_id_map, idNameMap: id_name_map };
+}
+
+#[tauri::command]
+/// Builds the maps between the user's app names and appids, so the frontend doesn't need to build them itself. They're built from the cached app names.
+pub async fn build_name_id_maps(app_handle: AppHandle) -> String {
+  let maps: NameIdMaps = get_name_id_maps(&app_handle);
+  logger::log_tagged_to_core_file(&app_handle, "app_cache", format!("Built name maps for {} apps.", maps.idNameMap.len()).as_str(), 0);
+
+  return serde_json::to_string(&maps).expect("Should have been able to serialize name maps.");
+}
+
+/// Lowercases a string and strips its accents so names can be compared loosely.
+fn normalize_name(name: &str) -> String {
+  return name.nfd().filter(| c | !is_combining_mark(*c)).collect::<String>().to_lowercase();
+}
+
+/// Ranks how well a name matches a query. Lower is better, and None means it doesn't match.
+fn rank_name_match(name: &str, query: &str) -> Option<u8> {
+  if name == query {
+    return Some(0);
+  } else if name.starts_with(query) {
+    return Some(1);
+  } else if name.split_whitespace().any(| word | word.starts_with(query)) {
+    return Some(2);
+  } else if name.contains(query) {
+    return Some(3);
+  }
+
+  return None;
+}
+
+#[tauri::command]
+/// Searches the user's apps by name, returning the best matches as a JSON array.
+pub async fn search_apps(app_handle: AppHandle, query: String, limit: usize) -> String {
+  let normalized_query: String = normalize_name(query.trim());
+  let mut matches: Vec<(u8, AppName)> = Vec::new();
+
+  for app in get_cached_app_names(&app_handle).into_iter() {
+    if let Some(rank) = rank_name_match(&normalize_name(&app.name), &normalized_query) {
+      matches.push((rank, app));
+    }
+  }
+
+  matches.sort_by(| (rank_a, app_a), (rank_b, app_b) | rank_a.cmp(rank_b).then(app_a.name.len().cmp(&app_b.name.len())));
+
+  let results: Vec<AppName> = matches.into_iter().take(limit).map(| (_, app) | app).collect();
+  logger::log_tagged_to_core_file(&app_handle, "app_cache", format!("Found {} apps matching \"{}\".", results.len(), query).as_str(), 0);
+
+  return serde_json::to_string(&results).expect("Should have been able to serialize search results.");
+}
@@ -0,0 +1,36 @@
+use crate::{logger, steam};
+
+use std::path::PathBuf;
+
+use tauri::AppHandle;
+
+/// Gets the dir the app's settings and other persistent data, like art profiles, are stored in.
+pub fn resolve_app_config_dir(app_handle: &AppHandle) -> PathBuf {
+  return app_handle.to_owned().path_resolver().app_config_dir().expect("Tried to resolve app config dir and failed.");
+}
+
+/// Gets the dir the app's caches, like downloaded grids, are stored in.
+pub fn resolve_app_cache_dir(app_handle: &AppHandle) -> PathBuf {
+  return app_handle.to_owned().path_resolver().app_cache_dir().expect("Tried to resolve app cache dir and failed.");
+}
+
+/// Creates a dir if it doesn't exist, then gets it as a string.
+fn ensure_app_dir(app_handle: &AppHandle, dir: PathBuf) -> String {
+  if !steam::ensure_directory_exists(app_handle, &dir) {
+    logger::log_tagged_to_core_file(app_handle, "app_dirs", format!("Failed to create {}.", dir.display()).as_str(), 2);
+  }
+
+  return steam::path_to_string(&dir);
+}
+
+#[tauri::command]
+/// Gets the dir the app's settings and other persistent data are stored in, creating it if needed.
+pub fn get_app_data_dir(app_handle: AppHandle) -> String {
+  return ensure_app_dir(&app_handle, resolve_app_config_dir(&app_handle));
+}
+
+#[tauri::command]
+/// Gets the dir the app's caches are stored in, creating it if needed.
+pub fn get_app_cache_dir(app_handle: AppHandle) -> String {
+  return ensure_app_dir(&app_handle, resolve_app_cache_dir(&app_handle));
+}
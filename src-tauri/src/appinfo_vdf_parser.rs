@@ -1,23 +1,79 @@
 use std::{path::PathBuf, fs};
 use std::io::Read;
 
+use flate2::read::GzDecoder;
 use serde_json::{Value, Map};
 
 use crate::reader::Reader;
 use crate::vdf_reader::read_entry_map;
 
-/// Opens the appinfo.vdf file and returns the values as JSON.
-pub fn open_appinfo_vdf(path: &PathBuf) -> Map<String, Value> {
-  let mut file = fs::File::open(path).expect("Path should have existed.");
+/// The magic bytes every gzip stream starts with.
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Reads the bytes of the appinfo.vdf file, decompressing it if it was gzipped.
+fn read_appinfo_bytes(path: &PathBuf) -> Result<Vec<u8>, String> {
+  let mut buffer: Vec<u8> = fs::read(path).map_err(| err | format!("Failed to read {}: {}", path.display(), err))?;
 
-  let metadata = fs::metadata(path).expect("unable to read metadata");
-  let mut buffer = vec![0; metadata.len() as usize];
-  file.read(&mut buffer).expect("buffer overflow");
+  if buffer.starts_with(&GZIP_MAGIC) {
+    let mut decompressed: Vec<u8> = Vec::new();
+    GzDecoder::new(buffer.as_slice()).read_to_end(&mut decompressed).map_err(| err | format!("Failed to decompress {}: {}", path.display(), err))?;
+    buffer = decompressed;
+  }
+
+  return Ok(buffer);
+}
+
+/// Opens the appinfo.vdf file and returns the values as JSON.
+pub fn open_appinfo_vdf(path: &PathBuf) -> Result<Map<String, Value>, String> {
+  let buffer: Vec<u8> = read_appinfo_bytes(path)?;
   
   let buf_slice = buffer.as_slice();
   let mut reader = Reader::new(buf_slice);
 
-  return read(&mut reader);
+  return Ok(read(&mut reader));
+}
+
+/// Finds a single app in the appinfo.vdf file, skipping over every other app's entry using its size header.
+/// Returns None if the app isn't in it, or if it couldn't be read.
+pub fn find_app_info(path: &PathBuf, appid: u32) -> Option<Map<String, Value>> {
+  let buffer: Vec<u8> = read_appinfo_bytes(path).ok()?;
+  let mut reader = Reader::new(buffer.as_slice());
+
+  let magic = reader.read_uint32(true);
+  let _universe = reader.read_uint32(true);
+
+  let skip: usize = if magic == 0x07564428 { 64 } else if magic == 0x07564427 { 44 } else { return None; };
+
+  while reader.remaining() >= 8 {
+    let id: u32 = reader.read_uint32(true);
+    if id == 0x00000000 {
+      break;
+    }
+
+    // ? The size counts everything after itself, so the skipped fields are measured from before it.
+    let size: usize = reader.read_uint32(true) as usize;
+    if (size as u64) > reader.remaining() {
+      return None;
+    }
+
+    if id != appid {
+      reader.seek(size, 1);
+      continue;
+    }
+
+    reader.seek(skip - 4, 1);
+
+    let _null_prefix = reader.read_uint8(true);
+    let name: String = reader.read_string(None).to_owned();
+
+    let mut entry: Map<String, Value> = read_entry_map(&mut reader);
+    entry.insert(String::from("name"), Value::String(name));
+    entry.insert(String::from("id"), Value::Number(id.into()));
+
+    return Some(entry);
+  }
+
+  return None;
 }
 
 /// Reads the appinfo.vdf file and returns the values as JSON.
@@ -73,4 +129,20 @@ fn read_app_sections(reader: &mut Reader, skip: u8) -> Vec<Value> {
   }
 
   return entries;
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn corrupt_gzipped_appinfo_is_an_error() {
+    let appinfo_path: PathBuf = std::env::temp_dir().join(format!("sarm_corrupt_appinfo_{}.vdf", std::process::id()));
+    fs::write(&appinfo_path, [0x1f, 0x8b, 0x00, 0x01, 0x02]).unwrap();
+
+    assert!(read_appinfo_bytes(&appinfo_path).is_err());
+    assert!(find_app_info(&appinfo_path, 620).is_none());
+
+    fs::remove_file(&appinfo_path).unwrap();
+  }
+}
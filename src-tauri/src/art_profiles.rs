@@ -0,0 +1,158 @@
+use crate::{app_dirs, backups, logger, steam, ChangedPath, GridImageCache, SaveSummary};
+
+use std::{path::PathBuf, fs};
+
+use chrono::prelude::*;
+use tauri::AppHandle;
+
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+#[allow(non_snake_case)]
+struct ArtProfile {
+  name: String,
+  createdAt: String,
+  bundled: bool,
+  grids: GridImageCache
+}
+
+/// Gets the directory art profiles are stored in.
+fn get_art_profiles_directory(app_handle: &AppHandle) -> PathBuf {
+  let app_config_dir: PathBuf = app_dirs::resolve_app_config_dir(app_handle);
+  return app_config_dir.join("art_profiles");
+}
+
+/// Checks that a profile name can be used as a file name.
+fn is_valid_profile_name(name: &str) -> bool {
+  let trimmed: &str = name.trim();
+  return !trimmed.is_empty() && trimmed != "." && trimmed != ".." && !name.contains(['/', '\\', ':', '*', '?', '"', '<', '>', '|']);
+}
+
+/// Reads a saved art profile.
+fn read_art_profile(app_handle: &AppHandle, name: &str) -> Result<ArtProfile, String> {
+  let profile_path: PathBuf = get_art_profiles_directory(app_handle).join(format!("{}.json", name));
+  let contents: String = fs::read_to_string(&profile_path).map_err(| err | format!("Failed to read profile {}: {}", name, err))?;
+  return serde_json::from_str(&contents).map_err(| err | format!("Failed to parse profile {}: {}", name, err));
+}
+
+/// Copies a profile's grids next to it, so it keeps working after the grids dir changes. Returns the grids pointing at the copies.
+fn bundle_grids(app_handle: &AppHandle, bundle_dir: &PathBuf, grids: &GridImageCache) -> Result<GridImageCache, String> {
+  if bundle_dir.exists() {
+    fs::remove_dir_all(bundle_dir).map_err(| err | err.to_string())?;
+  }
+
+  if !steam::ensure_directory_exists(app_handle, bundle_dir) {
+    return Err(format!("Failed to create {}", bundle_dir.display()));
+  }
+
+  let mut bundled_grids: GridImageCache = GridImageCache::new();
+
+  for (appid, app_grids) in grids.iter() {
+    for (grid_type, grid_path) in app_grids.iter() {
+      let grid_path_buf: PathBuf = PathBuf::from(grid_path);
+      let filename = grid_path_buf.file_name().ok_or(format!("{} has no file name.", grid_path))?;
+      let bundled_path: PathBuf = bundle_dir.join(filename);
+
+      fs::copy(&grid_path_buf, &bundled_path).map_err(| err | format!("Failed to copy {}: {}", grid_path, err))?;
+
+      bundled_grids.entry(appid.to_owned()).or_default().insert(grid_type.to_owned(), steam::path_to_string(&bundled_path));
+    }
+  }
+
+  return Ok(bundled_grids);
+}
+
+#[tauri::command]
+/// Saves the user's current grids as a named profile. Profiles keep their own copies of the grids by default.
+/// Unbundled profiles reference the files in the grids dir instead, so they only reapply art that's still there.
+pub fn save_art_profile(app_handle: AppHandle, steam_active_user_id: String, name: String, bundle: Option<bool>) -> bool {
+  if !is_valid_profile_name(&name) {
+    logger::log_tagged_to_core_file(&app_handle, "art_profiles", format!("Invalid profile name \"{}\".", name).as_str(), 1);
+    return false;
+  }
+
+  let grids_dir: PathBuf = PathBuf::from(steam::get_grids_directory(app_handle.to_owned(), steam_active_user_id, None));
+  let profiles_dir: PathBuf = get_art_profiles_directory(&app_handle);
+  // ? The grids dir's files change whenever other art is applied, so a profile only reliably restores its art if it has its own copies.
+  let bundled: bool = bundle.unwrap_or(true);
+  let mut grids: GridImageCache = crate::get_current_grids(&app_handle, &grids_dir);
+
+  if !steam::ensure_directory_exists(&app_handle, &profiles_dir) {
+    return false;
+  }
+
+  if bundled {
+    match bundle_grids(&app_handle, &profiles_dir.join(&name), &grids) {
+      Ok(bundled_grids) => grids = bundled_grids,
+      Err(err) => {
+        logger::log_tagged_to_core_file(&app_handle, "art_profiles", format!("Failed to bundle grids for profile {}: {}", name, err).as_str(), 2);
+        return false;
+      }
+    }
+  }
+
+  let profile: ArtProfile = ArtProfile {
+    name: name.to_owned(),
+    createdAt: Local::now().to_rfc3339(),
+    bundled,
+    grids
+  };
+  let profile_contents: String = serde_json::to_string_pretty(&profile).expect("Should have been able to serialize art profile.");
+
+  if let Err(err) = fs::write(profiles_dir.join(format!("{}.json", name)), profile_contents) {
+    logger::log_tagged_to_core_file(&app_handle, "art_profiles", format!("Failed to write profile {}: {}", name, err).as_str(), 2);
+    return false;
+  }
+
+  logger::log_tagged_to_core_file(&app_handle, "art_profiles", format!("Saved art profile {}.", name).as_str(), 0);
+  return true;
+}
+
+#[tauri::command]
+/// Lists the names of the saved art profiles.
+pub fn list_art_profiles(app_handle: AppHandle) -> Vec<String> {
+  let profiles_dir: PathBuf = get_art_profiles_directory(&app_handle);
+  let mut names: Vec<String> = Vec::new();
+
+  if let Ok(entries) = fs::read_dir(&profiles_dir) {
+    for entry in entries.flatten() {
+      let entry_path: PathBuf = entry.path();
+
+      if entry_path.is_file() && entry_path.extension().is_some_and(| extension | extension == "json") {
+        if let Some(name) = entry_path.file_stem().and_then(| stem | stem.to_str()) {
+          names.push(name.to_owned());
+        }
+      }
+    }
+  }
+
+  names.sort();
+  return names;
+}
+
+#[tauri::command]
+/// Applies a saved art profile to the user's grids. Grids the profile doesn't have are left alone.
+pub fn apply_art_profile(app_handle: AppHandle, steam_active_user_id: String, name: String) -> String {
+  let profile: ArtProfile = match read_art_profile(&app_handle, &name) {
+    Ok(profile) => profile,
+    Err(err) => {
+      logger::log_tagged_to_core_file(&app_handle, "art_profiles", &err, 2);
+      return serde_json::to_string(&SaveSummary::default()).expect("Should have been able to serialize save summary.");
+    }
+  };
+
+  let grids_dir: PathBuf = PathBuf::from(steam::get_grids_directory(app_handle.to_owned(), steam_active_user_id, None));
+  let current_grids: GridImageCache = crate::get_current_grids(&app_handle, &grids_dir);
+
+  // ? Grids the profile references in place are already applied, and copying a file onto itself would empty it.
+  let changed_paths: Vec<ChangedPath> = crate::filter_paths(&app_handle, &grids_dir, &profile.grids, &current_grids).into_iter()
+    .filter(| changed_path | !steam::paths_match(&changed_path.sourcePath, &changed_path.targetPath))
+    .collect();
+
+  if !changed_paths.is_empty() {
+    backups::create_backup(&app_handle, &grids_dir, &crate::get_changed_grid_files(&changed_paths));
+  }
+
+  let summary: SaveSummary = crate::apply_changed_paths(&app_handle, &changed_paths);
+  logger::log_tagged_to_core_file(&app_handle, "art_profiles", format!("Applied art profile {}. {} applied, {} failed.", name, summary.applied, summary.failed).as_str(), 0);
+
+  return serde_json::to_string(&summary).expect("Should have been able to serialize save summary.");
+}
@@ -0,0 +1,237 @@
+use crate::{app_dirs, grids_snapshot, logger, settings, steam, zip_controller};
+
+use std::{path::PathBuf, fs::{self, File, OpenOptions}, io::{self, BufReader}};
+
+use chrono::prelude::*;
+use tauri::AppHandle;
+
+/// The number of backups kept when the user hasn't configured a retention count.
+const DEFAULT_BACKUP_RETENTION: usize = 5;
+
+/// The format of the timestamp backup names start with.
+const BACKUP_TIMESTAMP_FORMAT: &str = "%Y-%m-%d_%H-%M-%S";
+
+/// How many names are tried for a backup before giving up, if backups made at the same time already have them.
+const MAX_BACKUP_NAME_ATTEMPTS: usize = 10;
+
+#[derive(Clone, serde::Serialize)]
+#[allow(non_snake_case)]
+pub struct BackupInfo {
+  name: String,
+  timestamp: Option<String>,
+  sizeBytes: u64,
+  fileCount: usize,
+  valid: bool
+}
+
+/// Gets the directory a grids dir's backups are stored in. They're kept in the app's data dir so steam and its cloud sync never see them,
+/// in a folder named after the grids dir's path so each user's backups stay separate.
+pub fn get_backups_directory(app_handle: &AppHandle, grids_dir: &PathBuf) -> PathBuf {
+  let grids_dir_key: String = grids_snapshot::hash_bytes(steam::path_to_string(grids_dir).as_bytes());
+  return app_dirs::resolve_app_config_dir(app_handle).join("backups").join(grids_dir_key);
+}
+
+/// Gets the number of backups to keep. 0 means backups are disabled.
+fn get_backup_retention(app_handle: &AppHandle) -> usize {
+  let retention_setting = settings::get_setting(app_handle, "backupRetention");
+
+  if retention_setting.is_some() {
+    let retention_res = retention_setting.unwrap().as_i64();
+
+    if retention_res.is_some() && retention_res.unwrap() >= 0 {
+      return retention_res.unwrap() as usize;
+    }
+  }
+
+  return DEFAULT_BACKUP_RETENTION;
+}
+
+/// Gets the paths of the backups in the backups directory, sorted oldest first.
+fn get_backup_paths(app_handle: &AppHandle, backups_dir: &PathBuf) -> Vec<PathBuf> {
+  let mut backup_paths: Vec<PathBuf> = Vec::new();
+
+  if backups_dir.is_dir() {
+    let backups_dir_contents = match fs::read_dir(backups_dir) {
+      Ok(contents) => contents,
+      Err(err) => {
+        logger::log_tagged_to_core_file(app_handle, "backups", format!("Failed to read {}: {}", backups_dir.display(), err).as_str(), 2);
+        return backup_paths;
+      }
+    };
+
+    for entry in backups_dir_contents.flatten() {
+      let entry_path: PathBuf = entry.path();
+
+      if entry_path.is_file() && entry_path.extension().is_some() && entry_path.extension().unwrap() == "zip" {
+        backup_paths.push(entry_path);
+      }
+    }
+  }
+
+  // ? Backup names start with their timestamp, so sorting by name sorts them by age.
+  backup_paths.sort();
+  return backup_paths;
+}
+
+/// Deletes the oldest backups until only the configured number remain.
+fn prune_backups(app_handle: &AppHandle, backups_dir: &PathBuf, retention: usize) {
+  let backup_paths: Vec<PathBuf> = get_backup_paths(app_handle, backups_dir);
+
+  if backup_paths.len() > retention {
+    for backup_path in backup_paths[..(backup_paths.len() - retention)].iter() {
+      let remove_res = fs::remove_file(backup_path);
+
+      if remove_res.is_ok() {
+        logger::log_tagged_to_core_file(app_handle, "backups", format!("Removed old backup {}.", backup_path.display()).as_str(), 0);
+      } else {
+        let err = remove_res.err().unwrap();
+        logger::log_tagged_to_core_file(app_handle, "backups", format!("Failed to remove old backup {}: {}", backup_path.display(), err.to_string()).as_str(), 1);
+      }
+    }
+  }
+}
+
+/// Creates a new backup file named after the current time, with milliseconds so saves in the same second get their own backups.
+/// Existing backups are never overwritten. If one already has the name, a counter is added after it so names still sort by age.
+fn create_backup_file(backups_dir: &PathBuf) -> io::Result<(PathBuf, File)> {
+  let now: DateTime<Local> = Local::now();
+  let timestamp: String = format!("{}.{:03}", now.format(BACKUP_TIMESTAMP_FORMAT), now.timestamp_subsec_millis());
+
+  for attempt in 0..MAX_BACKUP_NAME_ATTEMPTS {
+    let backup_name: String = if attempt == 0 { format!("{}_grids_backup.zip", timestamp) } else { format!("{}_grids_backup_{}.zip", timestamp, attempt) };
+    let backup_path: PathBuf = backups_dir.join(backup_name);
+
+    match OpenOptions::new().write(true).create_new(true).open(&backup_path) {
+      Ok(backup_file) => return Ok((backup_path, backup_file)),
+      Err(err) if err.kind() == io::ErrorKind::AlreadyExists => continue,
+      Err(err) => return Err(err)
+    }
+  }
+
+  return Err(io::Error::new(io::ErrorKind::AlreadyExists, format!("Backups named {} already exist.", timestamp)));
+}
+
+/// Backs up the grids a save is about to overwrite or remove to a zip, then removes any backups beyond the retention count.
+/// Only the provided paths that exist are backed up. Returns the backup's path if one was made.
+pub fn create_backup(app_handle: &AppHandle, grids_dir: &PathBuf, grid_paths: &[PathBuf]) -> Option<PathBuf> {
+  let retention: usize = get_backup_retention(app_handle);
+
+  if retention == 0 {
+    logger::log_tagged_to_core_file(app_handle, "backups", "Backups are disabled. Skipping...", 0);
+    return None;
+  }
+
+  let mut existing_paths: Vec<PathBuf> = grid_paths.iter().filter(| grid_path | grid_path.is_file()).cloned().collect();
+  existing_paths.sort();
+  existing_paths.dedup();
+
+  if existing_paths.is_empty() {
+    logger::log_tagged_to_core_file(app_handle, "backups", "None of the changed grids exist yet, so there's nothing to back up.", 0);
+    return None;
+  }
+
+  let backups_dir: PathBuf = get_backups_directory(app_handle, grids_dir);
+
+  if !steam::ensure_directory_exists(app_handle, &backups_dir) {
+    logger::log_tagged_to_core_file(app_handle, "backups", "Failed to create the backups dir, so grids weren't backed up.", 2);
+    return None;
+  }
+
+  let (backup_path, backup_file) = match create_backup_file(&backups_dir) {
+    Ok(backup) => backup,
+    Err(err) => {
+      logger::log_tagged_to_core_file(app_handle, "backups", format!("Failed to create a backup in {}: {}", backups_dir.display(), err).as_str(), 2);
+      return None;
+    }
+  };
+
+  return match zip_controller::generate_grid_backup_zip(grids_dir, &existing_paths, backup_file) {
+    Ok(file_count) => {
+      logger::log_tagged_to_core_file(app_handle, "backups", format!("Backed up {} grids to {}.", file_count, backup_path.display()).as_str(), 0);
+      prune_backups(app_handle, &backups_dir, retention);
+      Some(backup_path)
+    },
+    Err(err) => {
+      logger::log_tagged_to_core_file(app_handle, "backups", format!("Failed to back up grids: {}", err).as_str(), 2);
+      let _ = fs::remove_file(&backup_path);
+      None
+    }
+  };
+}
+
+/// Gets when a backup was made from the timestamp at the start of its name.
+fn get_backup_timestamp(backup_name: &str) -> Option<String> {
+  let timestamp_str: &str = backup_name.get(..19)?;
+  let naive_timestamp: NaiveDateTime = NaiveDateTime::parse_from_str(timestamp_str, BACKUP_TIMESTAMP_FORMAT).ok()?;
+
+  return Local.from_local_datetime(&naive_timestamp).earliest().map(| timestamp | timestamp.to_rfc3339());
+}
+
+/// Reads a backup's size and entry count from its zip's central directory, without extracting it. Backups that can't be read are marked invalid.
+fn read_backup_info(app_handle: &AppHandle, backup_path: &PathBuf) -> BackupInfo {
+  let name: String = backup_path.file_name().and_then(| name | name.to_str()).unwrap_or("").to_owned();
+  let size_bytes: u64 = fs::metadata(backup_path).map(| metadata | metadata.len()).unwrap_or(0);
+  let timestamp: Option<String> = get_backup_timestamp(&name);
+
+  let archive_res = File::open(backup_path).map_err(| err | err.to_string())
+    .and_then(| backup_file | zip::ZipArchive::new(BufReader::new(backup_file)).map_err(| err | err.to_string()));
+
+  return match archive_res {
+    Ok(archive) => BackupInfo { name, timestamp, sizeBytes: size_bytes, fileCount: archive.len(), valid: true },
+    Err(err) => {
+      logger::log_tagged_to_core_file(app_handle, "backups", format!("Backup {} is corrupt: {}", name, err).as_str(), 1);
+      BackupInfo { name, timestamp, sizeBytes: size_bytes, fileCount: 0, valid: false }
+    }
+  };
+}
+
+#[tauri::command]
+/// Lists the user's grid backups with their size and number of files, newest first.
+pub fn list_backups(app_handle: AppHandle, steam_active_user_id: String) -> Vec<BackupInfo> {
+  let grids_dir: PathBuf = PathBuf::from(steam::get_grids_directory(app_handle.to_owned(), steam_active_user_id, None));
+  let backup_paths: Vec<PathBuf> = get_backup_paths(&app_handle, &get_backups_directory(&app_handle, &grids_dir));
+
+  return backup_paths.iter().rev().map(| backup_path | read_backup_info(&app_handle, backup_path)).collect();
+}
+
+#[tauri::command]
+/// Deletes one of the user's grid backups.
+pub fn delete_backup(app_handle: AppHandle, steam_active_user_id: String, backup_name: String) -> bool {
+  if backup_name.contains("/") || backup_name.contains("\\") || backup_name.contains("..") {
+    logger::log_tagged_to_core_file(&app_handle, "backups", format!("Invalid backup name {}.", backup_name).as_str(), 2);
+    return false;
+  }
+
+  let grids_dir: PathBuf = PathBuf::from(steam::get_grids_directory(app_handle.to_owned(), steam_active_user_id, None));
+  let backup_path: PathBuf = get_backups_directory(&app_handle, &grids_dir).join(&backup_name);
+
+  let remove_res = fs::remove_file(&backup_path);
+
+  if remove_res.is_ok() {
+    logger::log_tagged_to_core_file(&app_handle, "backups", format!("Deleted backup {}.", backup_name).as_str(), 0);
+    return true;
+  } else {
+    let err = remove_res.err().unwrap();
+    logger::log_tagged_to_core_file(&app_handle, "backups", format!("Failed to delete backup {}: {}", backup_name, err.to_string()).as_str(), 2);
+    return false;
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn backups_made_at_the_same_time_get_their_own_files() {
+    let backups_dir: PathBuf = std::env::temp_dir().join(format!("sarm_backups_{}", std::process::id()));
+    fs::create_dir_all(&backups_dir).unwrap();
+
+    let (first_path, _first_file) = create_backup_file(&backups_dir).unwrap();
+    let (second_path, _second_file) = create_backup_file(&backups_dir).unwrap();
+
+    assert_ne!(first_path, second_path);
+    assert!(get_backup_timestamp(first_path.file_name().unwrap().to_str().unwrap()).is_some());
+
+    fs::remove_dir_all(&backups_dir).unwrap();
+  }
+}
@@ -0,0 +1,90 @@
+use crate::{app_cache, app_dirs, download_cache, logger};
+
+use std::{path::{Path, PathBuf}, fs};
+
+use tauri::AppHandle;
+
+#[derive(Clone, serde::Serialize)]
+#[allow(non_snake_case)]
+pub struct ClearCachesResult {
+  freedBytes: u64
+}
+
+/// Deletes a file, returning its size if it was deleted.
+fn delete_cache_file(app_handle: &AppHandle, path: &Path) -> u64 {
+  let size: u64 = match fs::metadata(path) {
+    Ok(metadata) => metadata.len(),
+    Err(_) => return 0
+  };
+
+  if let Err(err) = fs::remove_file(path) {
+    logger::log_tagged_to_core_file(app_handle, "caches", format!("Failed to delete {}: {}", path.display(), err).as_str(), 2);
+    return 0;
+  }
+
+  return size;
+}
+
+/// Deletes every file in a cache dir and its subdirs, returning the number of bytes freed.
+/// The dirs themselves are kept, since the frontend expects them to exist once it's initialized.
+fn clear_cache_dir(app_handle: &AppHandle, dir: &Path) -> u64 {
+  let dir_contents = match fs::read_dir(dir) {
+    Ok(contents) => contents,
+    Err(_) => return 0
+  };
+
+  let mut freed_bytes: u64 = 0;
+
+  for entry in dir_contents.flatten() {
+    let path: PathBuf = entry.path();
+
+    if path.is_dir() {
+      freed_bytes += clear_cache_dir(app_handle, &path);
+    } else {
+      freed_bytes += delete_cache_file(app_handle, &path);
+    }
+  }
+
+  return freed_bytes;
+}
+
+/// Gets the dir grids previewed from SteamGridDB are cached in.
+fn get_thumbnail_cache_dir(app_handle: &AppHandle) -> PathBuf {
+  let app_cache_dir: PathBuf = app_dirs::resolve_app_cache_dir(app_handle);
+  return app_cache_dir.join("grids");
+}
+
+/// Clears a single cache, returning the number of bytes freed.
+fn clear_cache(app_handle: &AppHandle, cache: &str) -> u64 {
+  return match cache {
+    "thumbnails" => clear_cache_dir(app_handle, &get_thumbnail_cache_dir(app_handle)),
+    "downloads" => delete_cache_file(app_handle, &download_cache::get_download_cache_path(app_handle)) + clear_cache_dir(app_handle, &download_cache::get_url_import_staging_dir(app_handle)),
+    "appinfo" => {
+      // ? App names are only cached in memory, so clearing them doesn't free any disk space.
+      app_cache::clear_cached_app_names(app_handle);
+      0
+    },
+    _ => {
+      logger::log_tagged_to_core_file(app_handle, "caches", format!("Unknown cache {}. Skipping.", cache).as_str(), 1);
+      0
+    }
+  };
+}
+
+#[tauri::command]
+/// Clears the provided caches. Supports "thumbnails", "downloads", "appinfo", and "all".
+pub fn clear_caches(app_handle: AppHandle, which: Vec<String>) -> ClearCachesResult {
+  let mut caches: Vec<&str> = which.iter().map(| cache | cache.as_str()).collect();
+
+  if caches.contains(&"all") {
+    caches = vec!["thumbnails", "downloads", "appinfo"];
+  }
+
+  caches.sort();
+  caches.dedup();
+
+  let freed_bytes: u64 = caches.into_iter().map(| cache | clear_cache(&app_handle, cache)).sum();
+
+  logger::log_tagged_to_core_file(&app_handle, "caches", format!("Cleared caches, freeing {} bytes.", freed_bytes).as_str(), 0);
+  return ClearCachesResult { freedBytes: freed_bytes };
+}
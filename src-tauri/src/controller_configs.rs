@@ -0,0 +1,134 @@
+use crate::{logger, steam};
+use crate::shortcuts_vdf_parser::{is_binary_vdf, try_open_binary_vdf, write_shortcuts_vdf};
+
+use std::{borrow::Cow, path::{Component, Path, PathBuf}, fs};
+
+use keyvalues_parser::{Obj, Vdf, Value as VdfValue};
+use serde_json::{Map, Value};
+use tauri::AppHandle;
+
+/// Gets the path of a config file in the user's config dir. Paths have to be relative, stay inside the dir, and point to a vdf file.
+fn resolve_config_path(app_handle: &AppHandle, steam_active_user_id: &str, config_path: &str) -> Result<PathBuf, String> {
+  let relative_path: &Path = Path::new(config_path);
+
+  if !relative_path.components().all(| component | matches!(component, Component::Normal(_))) {
+    return Err(format!("{} isn't a path inside the user's config dir.", config_path));
+  }
+
+  if relative_path.extension().and_then(| extension | extension.to_str()).map(| extension | extension.to_lowercase()) != Some(String::from("vdf")) {
+    return Err(format!("{} isn't a vdf file.", config_path));
+  }
+
+  let config_dir: PathBuf = steam::resolve_steam_root(None).join("userdata").join(steam::resolve_active_user_id(app_handle, steam_active_user_id)).join("config");
+  return Ok(config_dir.join(relative_path));
+}
+
+/// Converts JSON with a single root key into a text vdf.
+fn json_to_text_vdf(data: &Map<String, Value>) -> Result<Vdf<'static>, String> {
+  let (root_key, root_value) = match data.iter().next() {
+    Some(root) if data.len() == 1 => root,
+    _ => return Err(String::from("Text vdfs need exactly one root key."))
+  };
+
+  let root_map: &Map<String, Value> = root_value.as_object().ok_or(format!("The root {} should have been an object.", root_key))?;
+  let mut root_obj: Obj = Obj::new();
+  crate::merge_json_into_vdf_obj(&mut root_obj, root_map);
+
+  return Ok(Vdf { key: Cow::from(root_key.to_owned()), value: VdfValue::Obj(root_obj) });
+}
+
+#[tauri::command]
+/// Reads a controller config vdf from the user's config dir as JSON. Both the text and binary vdf formats are supported.
+pub fn read_controller_config(app_handle: AppHandle, steam_active_user_id: String, config_path: String) -> String {
+  let path: PathBuf = match resolve_config_path(&app_handle, &steam_active_user_id, &config_path) {
+    Ok(path) => path,
+    Err(err) => {
+      logger::log_tagged_to_core_file(&app_handle, "controller_configs", &err, 2);
+      return "{}".to_owned();
+    }
+  };
+
+  let contents: Vec<u8> = match fs::read(&path) {
+    Ok(contents) => contents,
+    Err(err) => {
+      logger::log_tagged_to_core_file(&app_handle, "controller_configs", format!("Failed to read {}: {}", path.display(), err).as_str(), 1);
+      return "{}".to_owned();
+    }
+  };
+
+  if is_binary_vdf(&contents) {
+    return match try_open_binary_vdf(&path) {
+      Ok(config) => serde_json::to_string(&config).expect("Should have been able to serialize controller config."),
+      Err(err) => {
+        logger::log_tagged_to_core_file(&app_handle, "controller_configs", format!("Failed to parse {}: {}", path.display(), err).as_str(), 2);
+        "{}".to_owned()
+      }
+    };
+  }
+
+  let text: String = String::from_utf8_lossy(&contents).into_owned();
+
+  return match Vdf::parse(&text) {
+    Ok(vdf) => {
+      let mut config: Map<String, Value> = Map::new();
+      config.insert(vdf.key.to_string(), Value::Object(vdf.value.get_obj().map(steam::vdf_obj_to_json).unwrap_or_default()));
+
+      serde_json::to_string(&config).expect("Should have been able to serialize controller config.")
+    },
+    Err(err) => {
+      logger::log_tagged_to_core_file(&app_handle, "controller_configs", format!("Failed to parse {}: {}", path.display(), err).as_str(), 2);
+      "{}".to_owned()
+    }
+  };
+}
+
+#[tauri::command]
+/// Writes a controller config vdf in the user's config dir, replacing its contents. Existing files keep their format, and new ones are written as text.
+pub fn write_controller_config(app_handle: AppHandle, steam_active_user_id: String, config_path: String, data: Map<String, Value>) -> bool {
+  let path: PathBuf = match resolve_config_path(&app_handle, &steam_active_user_id, &config_path) {
+    Ok(path) => path,
+    Err(err) => {
+      logger::log_tagged_to_core_file(&app_handle, "controller_configs", &err, 2);
+      return false;
+    }
+  };
+
+  let is_binary: bool = fs::read(&path).map(| contents | is_binary_vdf(&contents)).unwrap_or(false);
+
+  if path.exists() {
+    if let Err(err) = fs::copy(&path, path.with_extension("vdf.bak")) {
+      logger::log_tagged_to_core_file(&app_handle, "controller_configs", format!("Failed to back up {}: {}", path.display(), err).as_str(), 2);
+      return false;
+    }
+  } else if let Some(parent_dir) = path.parent() {
+    if !steam::ensure_directory_exists(&app_handle, parent_dir) {
+      return false;
+    }
+  }
+
+  if is_binary {
+    let success: bool = write_shortcuts_vdf(&path, Value::Object(data));
+
+    if success {
+      logger::log_tagged_to_core_file(&app_handle, "controller_configs", format!("Saved {}.", path.display()).as_str(), 0);
+    }
+
+    return success;
+  }
+
+  let vdf: Vdf = match json_to_text_vdf(&data) {
+    Ok(vdf) => vdf,
+    Err(err) => {
+      logger::log_tagged_to_core_file(&app_handle, "controller_configs", format!("Can't write {}: {}", path.display(), err).as_str(), 2);
+      return false;
+    }
+  };
+
+  if let Err(err) = fs::write(&path, vdf.to_string()) {
+    logger::log_tagged_to_core_file(&app_handle, "controller_configs", format!("Failed to write {}: {}", path.display(), err).as_str(), 2);
+    return false;
+  }
+
+  logger::log_tagged_to_core_file(&app_handle, "controller_configs", format!("Saved {}.", path.display()).as_str(), 0);
+  return true;
+}
@@ -0,0 +1,130 @@
+use crate::{logger, settings, steam, zip_controller};
+
+use std::{path::PathBuf, fs};
+
+use serde_json::{Map, Value};
+use tauri::AppHandle;
+
+/// The text secrets are replaced with in the diagnostics export.
+const REDACTED: &str = "[REDACTED]";
+
+/// Checks if a setting holds a credential, like the SteamGridDB or Steam API keys.
+fn is_secret_key(key: &str) -> bool {
+  let key: String = key.to_lowercase();
+  return key.contains("key") || key.contains("token") || key.contains("password");
+}
+
+/// Replaces every credential in a settings value, collecting the original secrets so they can be scrubbed from the logs as well.
+fn redact_value(value: &mut Value, is_secret: bool, secrets: &mut Vec<String>) {
+  match value {
+    Value::Object(map) => {
+      for (key, child) in map.iter_mut() {
+        redact_value(child, is_secret || is_secret_key(key), secrets);
+      }
+    },
+    Value::Array(array) => {
+      for child in array.iter_mut() {
+        redact_value(child, is_secret, secrets);
+      }
+    },
+    Value::String(secret) if is_secret && !secret.is_empty() => {
+      secrets.push(secret.to_owned());
+      *value = Value::String(REDACTED.to_owned());
+    },
+    _ => {}
+  }
+}
+
+/// Reads a log file, replacing any secrets that ended up in it.
+fn read_redacted_log(log_path: &PathBuf, secrets: &Vec<String>) -> Option<Vec<u8>> {
+  let mut contents: String = String::from_utf8_lossy(&fs::read(log_path).ok()?).into_owned();
+
+  for secret in secrets.iter() {
+    contents = contents.replace(secret, REDACTED);
+  }
+
+  return Some(contents.into_bytes());
+}
+
+/// Describes where steam's files are expected to be, and whether they exist.
+fn get_paths_report(app_handle: &AppHandle, steam_active_user_id: Option<String>) -> Map<String, Value> {
+  let mut paths: Vec<(&str, PathBuf)> = Vec::new();
+
+  match steam::get_steam_root_dir() {
+    Ok(steam_root) => paths.push(("steamRoot", steam_root)),
+    Err(err) => logger::log_tagged_to_core_file(app_handle, "diagnostics", format!("Couldn't find steam root for diagnostics: {}", err).as_str(), 1)
+  }
+
+  paths.push(("appinfo", PathBuf::from(steam::get_appinfo_path(app_handle.to_owned(), None))));
+  paths.push(("libraryCache", PathBuf::from(steam::get_library_cache_directory(app_handle.to_owned(), None))));
+
+  if let Some(user_id) = steam_active_user_id {
+    paths.push(("grids", steam::get_user_grids_dir_path(app_handle, &steam::resolve_active_user_id(app_handle, &user_id))));
+    paths.push(("shortcuts", PathBuf::from(steam::get_shortcuts_path(app_handle.to_owned(), user_id.to_owned(), None))));
+    paths.push(("localconfig", PathBuf::from(steam::get_localconfig_path(app_handle.to_owned(), user_id, None))));
+  }
+
+  let mut report: Map<String, Value> = Map::new();
+
+  for (name, path) in paths.into_iter() {
+    let mut entry: Map<String, Value> = Map::new();
+    entry.insert(String::from("path"), Value::String(steam::normalize_path(path.to_str().unwrap_or(""))));
+    entry.insert(String::from("exists"), Value::Bool(path.exists()));
+
+    // ? Paths like the grids dir are sometimes symlinked elsewhere, which is worth knowing when art doesn't load.
+    let real_path: PathBuf = steam::resolve_real_path(&path);
+
+    if real_path != path {
+      entry.insert(String::from("realPath"), Value::String(steam::path_to_string(&real_path)));
+    }
+
+    report.insert(name.to_owned(), Value::Object(entry));
+  }
+
+  // ? Newer steam clients moved the library cache to per-app folders, which changes where default art is found.
+  if let Some(Value::Object(cache_entry)) = report.get_mut("libraryCache") {
+    let layout: &str = steam::library_cache_layout(&steam::get_library_cache_dirs(None));
+    cache_entry.insert(String::from("layout"), Value::String(layout.to_owned()));
+  }
+
+  return report;
+}
+
+/// Gets the app's version and the system it's running on.
+fn get_version_info(app_handle: &AppHandle) -> Map<String, Value> {
+  let package_info = app_handle.package_info();
+
+  let mut version_info: Map<String, Value> = Map::new();
+  version_info.insert(String::from("name"), Value::String(package_info.name.to_owned()));
+  version_info.insert(String::from("version"), Value::String(package_info.version.to_string()));
+  version_info.insert(String::from("os"), Value::String(std::env::consts::OS.to_owned()));
+  version_info.insert(String::from("arch"), Value::String(std::env::consts::ARCH.to_owned()));
+
+  return version_info;
+}
+
+#[tauri::command]
+/// Bundles the logs, steam paths, version info, and redacted settings into a zip for bug reports.
+pub fn export_diagnostics(app_handle: AppHandle, dest_path: String, steam_active_user_id: Option<String>) -> bool {
+  logger::log_tagged_to_core_file(&app_handle, "diagnostics", "Exporting diagnostics...", 0);
+
+  let mut secrets: Vec<String> = Vec::new();
+  let mut settings_value: Value = fs::read_to_string(settings::get_settings_path(&app_handle)).ok()
+    .and_then(| contents | serde_json::from_str(&contents).ok())
+    .unwrap_or(Value::Object(Map::new()));
+  redact_value(&mut settings_value, false, &mut secrets);
+
+  let mut files: Vec<(String, Vec<u8>)> = Vec::new();
+  files.push((String::from("version.json"), serde_json::to_vec_pretty(&get_version_info(&app_handle)).expect("Should have been able to serialize version info.")));
+  files.push((String::from("paths.json"), serde_json::to_vec_pretty(&get_paths_report(&app_handle, steam_active_user_id)).expect("Should have been able to serialize paths report.")));
+  files.push((String::from("settings.json"), serde_json::to_vec_pretty(&settings_value).expect("Should have been able to serialize settings.")));
+
+  for log_path in [logger::get_core_log_path(&app_handle), logger::get_batch_apply_log_path(&app_handle)] {
+    if let Some(log_contents) = read_redacted_log(&log_path, &secrets) {
+      let log_name: String = log_path.file_name().and_then(| name | name.to_str()).unwrap_or("app.log").to_owned();
+      files.push((format!("logs/{}", log_name), log_contents));
+    }
+  }
+
+  return zip_controller::generate_files_zip(&app_handle, PathBuf::from(dest_path), &files);
+}
@@ -0,0 +1,67 @@
+use crate::{logger, steam};
+
+use std::path::{Path, PathBuf};
+
+use tauri::AppHandle;
+
+#[cfg(unix)]
+/// Gets the bytes available to the user on the volume containing a path.
+fn get_volume_free_space(path: &Path) -> Result<u64, String> {
+  use std::{ffi::CString, os::unix::ffi::OsStrExt};
+
+  let c_path: CString = CString::new(path.as_os_str().as_bytes()).map_err(| err | err.to_string())?;
+  let mut stats: libc::statvfs = unsafe { std::mem::zeroed() };
+
+  if unsafe { libc::statvfs(c_path.as_ptr(), &mut stats) } != 0 {
+    return Err(std::io::Error::last_os_error().to_string());
+  }
+
+  return Ok((stats.f_bavail as u64).saturating_mul(stats.f_frsize as u64));
+}
+
+#[cfg(windows)]
+/// Gets the bytes available to the user on the volume containing a path.
+fn get_volume_free_space(path: &Path) -> Result<u64, String> {
+  use std::{iter::once, os::windows::ffi::OsStrExt, ptr::null_mut};
+  use windows_sys::Win32::Storage::FileSystem::GetDiskFreeSpaceExW;
+
+  let wide_path: Vec<u16> = path.as_os_str().encode_wide().chain(once(0)).collect();
+  let mut free_bytes: u64 = 0;
+
+  if unsafe { GetDiskFreeSpaceExW(wide_path.as_ptr(), &mut free_bytes, null_mut(), null_mut()) } == 0 {
+    return Err(std::io::Error::last_os_error().to_string());
+  }
+
+  return Ok(free_bytes);
+}
+
+/// Gets the bytes available on the volume a path is or will be on. Dirs that don't exist yet are checked through their closest existing parent.
+pub fn get_free_space_for(path: &Path) -> Result<u64, String> {
+  let existing_path: &Path = path.ancestors().find(| ancestor | ancestor.exists()).ok_or(format!("None of {} exists.", path.display()))?;
+  return get_volume_free_space(existing_path);
+}
+
+/// Checks that there's room for a download of the provided size in a dir.
+pub fn ensure_free_space(dir: &Path, needed_bytes: u64) -> Result<(), String> {
+  let free_bytes: u64 = get_free_space_for(dir)?;
+
+  if free_bytes < needed_bytes {
+    return Err(format!("Not enough space on the drive containing {}. {} bytes are free, but up to {} bytes are needed.", dir.display(), free_bytes, needed_bytes));
+  }
+
+  return Ok(());
+}
+
+#[tauri::command]
+/// Gets the bytes available on the drive holding the user's grids dir. Returns 0 if it couldn't be read.
+pub fn get_free_space(app_handle: AppHandle, steam_active_user_id: String) -> u64 {
+  let grids_dir: PathBuf = PathBuf::from(steam::get_grids_directory(app_handle.to_owned(), steam_active_user_id, None));
+
+  return match get_free_space_for(&grids_dir) {
+    Ok(free_bytes) => free_bytes,
+    Err(err) => {
+      logger::log_tagged_to_core_file(&app_handle, "disk_space", format!("Failed to get free space for {}: {}", grids_dir.display(), err).as_str(), 2);
+      0
+    }
+  };
+}
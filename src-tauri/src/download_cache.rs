@@ -0,0 +1,66 @@
+use crate::{app_dirs, logger};
+
+use std::{collections::HashMap, path::PathBuf, fs, sync::Mutex};
+
+use tauri::AppHandle;
+
+/// Held while the download cache is read and rewritten, so concurrent downloads don't drop each other's entries.
+static DOWNLOAD_CACHE_LOCK: Mutex<()> = Mutex::new(());
+
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+#[allow(non_snake_case)]
+pub struct CachedDownload {
+  pub etag: Option<String>,
+  pub lastModified: Option<String>,
+  pub destPath: String
+}
+
+/// Gets the path of the file storing the cache validators of downloaded grids.
+pub fn get_download_cache_path(app_handle: &AppHandle) -> PathBuf {
+  let app_cache_dir: PathBuf = app_dirs::resolve_app_cache_dir(app_handle);
+  return app_cache_dir.join("download_cache.json");
+}
+
+/// Gets the dir grids downloaded from urls are staged in before being saved.
+pub fn get_url_import_staging_dir(app_handle: &AppHandle) -> PathBuf {
+  let app_cache_dir: PathBuf = app_dirs::resolve_app_cache_dir(app_handle);
+  return app_cache_dir.join("url_imports");
+}
+
+/// Reads the cache validators of every downloaded grid.
+fn read_download_cache(app_handle: &AppHandle) -> HashMap<String, CachedDownload> {
+  return fs::read_to_string(get_download_cache_path(app_handle)).ok()
+    .and_then(| contents | serde_json::from_str(&contents).ok())
+    .unwrap_or_default();
+}
+
+/// Gets the cache validators for a url, if it was downloaded to the provided path before and the file is still there.
+pub fn get_cached_download(app_handle: &AppHandle, url: &str, dest_path: &str) -> Option<CachedDownload> {
+  let cached_download: CachedDownload = read_download_cache(app_handle).remove(url)?;
+
+  if cached_download.destPath != dest_path || !PathBuf::from(dest_path).is_file() {
+    return None;
+  }
+
+  return Some(cached_download);
+}
+
+/// Stores the cache validators the server sent for a url.
+pub fn set_cached_download(app_handle: &AppHandle, url: &str, cached_download: CachedDownload) {
+  // ? A panic while writing doesn't leave the cache unusable, so a poisoned lock is still taken.
+  let _cache_guard = DOWNLOAD_CACHE_LOCK.lock().unwrap_or_else(| poisoned | poisoned.into_inner());
+
+  let download_cache_path: PathBuf = get_download_cache_path(app_handle);
+  let mut download_cache: HashMap<String, CachedDownload> = read_download_cache(app_handle);
+  download_cache.insert(url.to_owned(), cached_download);
+
+  if let Some(cache_dir) = download_cache_path.parent() {
+    let _ = fs::create_dir_all(cache_dir);
+  }
+
+  let cache_contents: String = serde_json::to_string(&download_cache).expect("Should have been able to serialize download cache.");
+
+  if let Err(err) = fs::write(&download_cache_path, cache_contents) {
+    logger::log_tagged_to_core_file(app_handle, "download_cache", format!("Failed to write download cache: {}", err).as_str(), 1);
+  }
+}
@@ -0,0 +1,44 @@
+use crate::logger;
+
+use std::{collections::HashMap, sync::{Arc, Mutex}};
+
+use tauri::{AppHandle, Manager};
+use tokio::sync::Notify;
+
+/// Tracks the in-flight downloads that can be cancelled, by job id.
+#[derive(Default)]
+pub struct DownloadJobs {
+  jobs: Mutex<HashMap<String, Arc<Notify>>>
+}
+
+/// Registers a download job, returning the notifier that fires when it is cancelled.
+pub fn register_download_job(app_handle: &AppHandle, job_id: &str) -> Arc<Notify> {
+  let cancel_notify: Arc<Notify> = Arc::new(Notify::new());
+  let download_jobs = app_handle.state::<DownloadJobs>();
+  download_jobs.jobs.lock().expect("Should have been able to lock download jobs.").insert(job_id.to_owned(), cancel_notify.clone());
+
+  return cancel_notify;
+}
+
+/// Removes a download job once it has finished or been cancelled.
+pub fn finish_download_job(app_handle: &AppHandle, job_id: &str) {
+  let download_jobs = app_handle.state::<DownloadJobs>();
+  download_jobs.jobs.lock().expect("Should have been able to lock download jobs.").remove(job_id);
+}
+
+#[tauri::command]
+/// Cancels an in-flight download. Returns false if no download with the job id is running.
+pub fn cancel_download(app_handle: AppHandle, job_id: String) -> bool {
+  let download_jobs = app_handle.state::<DownloadJobs>();
+  let cancel_notify: Option<Arc<Notify>> = download_jobs.jobs.lock().expect("Should have been able to lock download jobs.").remove(&job_id);
+
+  if let Some(cancel_notify) = cancel_notify {
+    // ? notify_one stores a permit, so the cancel isn't lost if the download isn't waiting yet.
+    cancel_notify.notify_one();
+    logger::log_tagged_to_core_file(&app_handle, "download_jobs", format!("Cancelled download job {}.", job_id).as_str(), 0);
+    return true;
+  }
+
+  logger::log_tagged_to_core_file(&app_handle, "download_jobs", format!("No download job {} to cancel.", job_id).as_str(), 1);
+  return false;
+}
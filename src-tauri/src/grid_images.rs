@@ -0,0 +1,443 @@
+use std::{path::PathBuf, fs, io::{Cursor, Read}, collections::hash_map::DefaultHasher, hash::{Hash, Hasher}};
+
+use image::{io::Reader as ImageReader, imageops::FilterType, DynamicImage, ImageFormat};
+use image::codecs::{jpeg::JpegEncoder, png::PngEncoder, webp::WebPEncoder};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use tauri::AppHandle;
+
+use crate::logger;
+use crate::settings;
+use crate::steam;
+
+/// The largest width or height an optimized grid can have when the setting isn't set.
+const DEFAULT_OPTIMIZE_MAX_DIMENSION: u32 = 1920;
+/// The JPEG quality used for optimized grids when the setting isn't set.
+const DEFAULT_OPTIMIZE_QUALITY: u8 = 90;
+
+#[derive(Clone, serde::Serialize)]
+#[allow(non_snake_case)]
+pub struct OptimizeResult {
+  bytesSaved: u64,
+  path: String
+}
+
+/// How far an image's aspect ratio can be from the expected one and still be considered a match.
+const ASPECT_RATIO_TOLERANCE: f64 = 0.05;
+/// How far an image's aspect ratio can be from a grid type's and still be guessed as that type.
+const GUESS_RATIO_TOLERANCE: f64 = 0.15;
+/// The largest image, in bytes, that will be encoded as a base64 preview.
+const MAX_PREVIEW_SIZE: usize = 8 * 1024 * 1024;
+
+#[derive(Clone, Copy, serde::Serialize)]
+pub struct Dimensions {
+  width: u32,
+  height: u32
+}
+
+#[derive(Clone, serde::Serialize)]
+pub struct GridDimensions {
+  width: u32,
+  height: u32,
+  matches: bool,
+  expected: Dimensions
+}
+
+/// Gets the canonical dimensions Steam uses for a grid type.
+pub fn get_expected_dimensions(grid_type: &str) -> Option<Dimensions> {
+  return match grid_type {
+    "Capsule" => Some(Dimensions { width: 600, height: 900 }),
+    "Wide Capsule" => Some(Dimensions { width: 920, height: 430 }),
+    "Hero" => Some(Dimensions { width: 1920, height: 620 }),
+    "Logo" => Some(Dimensions { width: 1280, height: 720 }),
+    "Icon" => Some(Dimensions { width: 256, height: 256 }),
+    _ => None
+  };
+}
+
+/// Checks if an image's dimensions have the right aspect ratio for a grid type.
+pub fn dimensions_match(grid_type: &str, width: u32, height: u32, expected: &Dimensions) -> bool {
+  // ? Steam fits logos inside the hero without stretching them, so any ratio works.
+  if grid_type == "Logo" {
+    return width > 0 && height > 0;
+  }
+
+  if height == 0 {
+    return false;
+  }
+
+  let ratio: f64 = width as f64 / height as f64;
+  let expected_ratio: f64 = expected.width as f64 / expected.height as f64;
+
+  return ((ratio - expected_ratio) / expected_ratio).abs() <= ASPECT_RATIO_TOLERANCE;
+}
+
+/// Reads an image's dimensions from its header without decoding the pixels.
+pub fn read_image_dimensions(app_handle: &AppHandle, src_path: &PathBuf) -> Option<(u32, u32)> {
+  let reader = match ImageReader::open(src_path).and_then(| reader | reader.with_guessed_format()) {
+    Ok(reader) => reader,
+    Err(err) => {
+      logger::log_tagged_to_core_file(app_handle, "grid_images", format!("Failed to open image {}: {}", src_path.display(), err).as_str(), 2);
+      return None;
+    }
+  };
+
+  return match reader.into_dimensions() {
+    Ok(dimensions) => Some(dimensions),
+    Err(err) => {
+      logger::log_tagged_to_core_file(app_handle, "grid_images", format!("Failed to read dimensions of {}: {}", src_path.display(), err).as_str(), 2);
+      None
+    }
+  };
+}
+
+/// Fully decodes an image to check it isn't truncated or corrupt. Reading the header alone misses damage past it.
+pub fn decode_image_fully(path: &PathBuf) -> Result<(), String> {
+  let reader = ImageReader::open(path).and_then(| reader | reader.with_guessed_format()).map_err(| err | err.to_string())?;
+
+  if reader.format().is_none() {
+    return Err(String::from("Not a recognized image format."));
+  }
+
+  reader.decode().map_err(| err | err.to_string())?;
+  return Ok(());
+}
+
+#[tauri::command]
+/// Checks if an image can be fully decoded, catching corrupt or truncated files that still have valid magic bytes.
+pub fn can_decode_image(app_handle: AppHandle, path: String) -> bool {
+  return match decode_image_fully(&PathBuf::from(&path)) {
+    Ok(()) => true,
+    Err(err) => {
+      logger::log_tagged_to_core_file(&app_handle, "grid_images", format!("{} couldn't be decoded: {}", path, err).as_str(), 1);
+      false
+    }
+  };
+}
+
+/// Reads an image's dimensions and format from its header, without decoding the rest of it.
+pub fn read_image_header(path: &PathBuf) -> Result<(u32, u32, String), String> {
+  let reader = ImageReader::open(path).and_then(| reader | reader.with_guessed_format()).map_err(| err | err.to_string())?;
+  let format: String = reader.format().map(| format | format!("{:?}", format).to_lowercase()).unwrap_or(String::from("unknown"));
+  let (width, height) = reader.into_dimensions().map_err(| err | err.to_string())?;
+
+  return Ok((width, height, format));
+}
+
+/// Gets the file extension matching an image's actual contents, based on its magic bytes.
+pub fn sniff_image_extension(path: &PathBuf) -> Option<&'static str> {
+  let mut header: [u8; 16] = [0; 16];
+  let mut file = fs::File::open(path).ok()?;
+  let read_len: usize = file.read(&mut header).ok()?;
+
+  return sniff_image_bytes(&header[..read_len]);
+}
+
+/// Gets the file extension matching the start of an image's data, based on its magic bytes.
+pub fn sniff_image_bytes(header: &[u8]) -> Option<&'static str> {
+  return match image::guess_format(header).ok()? {
+    ImageFormat::Png => Some(".png"),
+    ImageFormat::Jpeg => Some(".jpg"),
+    ImageFormat::WebP => Some(".webp"),
+    ImageFormat::Ico => Some(".ico"),
+    _ => None
+  };
+}
+
+/// Hashes an image by its decoded pixels, so re-encodes of the same image in other formats hash the same.
+/// Files that can't be decoded are hashed by their bytes instead.
+pub fn hash_image_file(path: &PathBuf) -> Option<u64> {
+  let contents: Vec<u8> = fs::read(path).ok()?;
+  let mut hasher: DefaultHasher = DefaultHasher::new();
+
+  match image::load_from_memory(&contents) {
+    Ok(image) => {
+      let pixels = image.to_rgba8();
+      pixels.dimensions().hash(&mut hasher);
+      pixels.as_raw().hash(&mut hasher);
+    },
+    Err(_) => contents.hash(&mut hasher)
+  }
+
+  return Some(hasher.finish());
+}
+
+/// Encodes an image in the provided format. Quality only applies to JPEGs, since WebPs are always encoded losslessly.
+fn encode_image(image: &DynamicImage, format: ImageFormat, quality: u8) -> Option<Vec<u8>> {
+  let mut buffer: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+
+  let encode_res = match format {
+    // ? JPEGs can't store transparency, so the alpha channel has to be dropped first.
+    ImageFormat::Jpeg => DynamicImage::ImageRgb8(image.to_rgb8()).write_with_encoder(JpegEncoder::new_with_quality(&mut buffer, quality)),
+    ImageFormat::Png => image.write_with_encoder(PngEncoder::new(&mut buffer)),
+    // ? The WebP encoder only takes 8 bit RGB(A), so other color types are converted first.
+    ImageFormat::WebP if image.color().has_alpha() => DynamicImage::ImageRgba8(image.to_rgba8()).write_with_encoder(WebPEncoder::new_lossless(&mut buffer)),
+    ImageFormat::WebP => DynamicImage::ImageRgb8(image.to_rgb8()).write_with_encoder(WebPEncoder::new_lossless(&mut buffer)),
+    _ => return None
+  };
+
+  return encode_res.ok().map(| _ | buffer.into_inner());
+}
+
+/// Gets the format optimized grids should be converted to, from a format name. "original" or anything unknown keeps each grid's format.
+fn parse_optimize_format(format: &str) -> Option<ImageFormat> {
+  return match format.to_lowercase().as_str() {
+    "webp" => Some(ImageFormat::WebP),
+    _ => None
+  };
+}
+
+/// Checks if the user has turned on grid optimization.
+fn is_optimize_enabled(app_handle: &AppHandle) -> bool {
+  return settings::get_setting(app_handle, "optimizeGrids").and_then(| value | value.as_bool()).unwrap_or(false);
+}
+
+/// Gets the format the user wants optimized grids converted to, if any.
+fn get_optimize_format(app_handle: &AppHandle) -> Option<ImageFormat> {
+  return settings::get_setting(app_handle, "optimizeFormat").and_then(| value | value.as_str().and_then(parse_optimize_format));
+}
+
+/// Checks if saved grids will be converted to webp when they're optimized.
+pub fn converts_grids_to_webp(app_handle: &AppHandle) -> bool {
+  return is_optimize_enabled(app_handle) && get_optimize_format(app_handle) == Some(ImageFormat::WebP);
+}
+
+/// Checks that encoded image data decodes as the expected format and size, so a bad encode never replaces a grid.
+fn is_valid_encoded_image(encoded: &[u8], format: ImageFormat, width: u32, height: u32) -> bool {
+  return image::load_from_memory_with_format(encoded, format).is_ok_and(| decoded | decoded.width() == width && decoded.height() == height);
+}
+
+/// Shrinks an image so its largest side is at most max_dimension, keeping its aspect ratio. Images are also converted if an output format is provided.
+/// Returns the number of bytes saved, along with the image's path, which changes extension if it was converted.
+pub fn optimize_grid_image(app_handle: &AppHandle, path: &PathBuf, max_dimension: u32, quality: u8, output_format: Option<ImageFormat>) -> (u64, PathBuf) {
+  let (width, height) = match read_image_dimensions(app_handle, path) {
+    Some(dimensions) => dimensions,
+    None => return (0, path.to_owned())
+  };
+
+  let reader = match ImageReader::open(path).and_then(| reader | reader.with_guessed_format()) {
+    Ok(reader) => reader,
+    Err(_) => return (0, path.to_owned())
+  };
+
+  let format: ImageFormat = match reader.format() {
+    Some(format @ (ImageFormat::Jpeg | ImageFormat::Png | ImageFormat::WebP)) => format,
+    _ => {
+      logger::log_tagged_to_core_file(app_handle, "grid_images", format!("Can't optimize {}. Only png, jpg, and webp are supported.", path.display()).as_str(), 1);
+      return (0, path.to_owned());
+    }
+  };
+
+  let target_format: ImageFormat = output_format.unwrap_or(format);
+  let needs_resize: bool = width > max_dimension || height > max_dimension;
+
+  if !needs_resize && target_format == format {
+    logger::log_tagged_to_core_file(app_handle, "grid_images", format!("{} is already {}x{}. Skipping optimization.", path.display(), width, height).as_str(), 0);
+    return (0, path.to_owned());
+  }
+
+  let image: DynamicImage = match reader.decode() {
+    Ok(image) => image,
+    Err(err) => {
+      logger::log_tagged_to_core_file(app_handle, "grid_images", format!("Failed to decode {}: {}", path.display(), err).as_str(), 2);
+      return (0, path.to_owned());
+    }
+  };
+
+  // ? resize keeps the aspect ratio, fitting the image inside the bounds.
+  let resized: DynamicImage = if needs_resize { image.resize(max_dimension, max_dimension, FilterType::Lanczos3) } else { image };
+  let original_size: u64 = fs::metadata(path).map(| metadata | metadata.len()).unwrap_or(0);
+
+  let encoded: Vec<u8> = match encode_image(&resized, target_format, quality) {
+    Some(encoded) => encoded,
+    None => {
+      logger::log_tagged_to_core_file(app_handle, "grid_images", format!("Failed to encode optimized {}.", path.display()).as_str(), 2);
+      return (0, path.to_owned());
+    }
+  };
+
+  if encoded.len() as u64 >= original_size {
+    logger::log_tagged_to_core_file(app_handle, "grid_images", format!("Optimizing {} didn't make it smaller. Keeping the original.", path.display()).as_str(), 0);
+    return (0, path.to_owned());
+  }
+
+  let target_path: PathBuf = if target_format == format {
+    path.to_owned()
+  } else {
+    if !is_valid_encoded_image(&encoded, target_format, resized.width(), resized.height()) {
+      logger::log_tagged_to_core_file(app_handle, "grid_images", format!("Converting {} didn't produce a valid image. Keeping the original.", path.display()).as_str(), 2);
+      return (0, path.to_owned());
+    }
+
+    let extension: &str = target_format.extensions_str().first().copied().unwrap_or("webp");
+    path.with_extension(extension)
+  };
+
+  if let Err(err) = fs::write(&target_path, &encoded) {
+    logger::log_tagged_to_core_file(app_handle, "grid_images", format!("Failed to write optimized {}: {}", target_path.display(), err).as_str(), 2);
+    return (0, path.to_owned());
+  }
+
+  // ? Steam would pick up either copy, so if the original can't be removed the converted one is removed instead.
+  if target_path != *path {
+    if let Err(err) = fs::remove_file(path) {
+      logger::log_tagged_to_core_file(app_handle, "grid_images", format!("Failed to remove {} after converting it: {}", path.display(), err).as_str(), 2);
+      let _ = fs::remove_file(&target_path);
+      return (0, path.to_owned());
+    }
+  }
+
+  let bytes_saved: u64 = original_size - encoded.len() as u64;
+  logger::log_tagged_to_core_file(app_handle, "grid_images", format!("Optimized {} from {}x{} to {}x{} as {}, saving {} bytes.", path.display(), width, height, resized.width(), resized.height(), target_path.display(), bytes_saved).as_str(), 0);
+
+  return (bytes_saved, target_path);
+}
+
+/// Optimizes a grid if the user has turned on grid optimization in their settings. Returns the grid's path, which changes if it was converted to another format.
+pub fn optimize_grid_if_enabled(app_handle: &AppHandle, path: &PathBuf) -> PathBuf {
+  if !is_optimize_enabled(app_handle) {
+    return path.to_owned();
+  }
+
+  let max_dimension: u32 = settings::get_setting(app_handle, "optimizeMaxDimension")
+    .and_then(| value | value.as_u64())
+    .filter(| value | *value > 0)
+    .map(| value | value.min(u32::MAX as u64) as u32)
+    .unwrap_or(DEFAULT_OPTIMIZE_MAX_DIMENSION);
+  let quality: u8 = settings::get_setting(app_handle, "optimizeQuality")
+    .and_then(| value | value.as_u64())
+    .map(| value | value.clamp(1, 100) as u8)
+    .unwrap_or(DEFAULT_OPTIMIZE_QUALITY);
+
+  return optimize_grid_image(app_handle, path, max_dimension, quality, get_optimize_format(app_handle)).1;
+}
+
+#[tauri::command]
+/// Shrinks an oversized grid in place, optionally converting it to another format like "webp". Returns the number of bytes saved and the grid's path.
+pub fn optimize_grid(app_handle: AppHandle, path: String, max_dimension: u32, quality: u8, format: Option<String>) -> OptimizeResult {
+  let output_format: Option<ImageFormat> = format.as_deref().and_then(parse_optimize_format);
+  let (bytes_saved, optimized_path) = optimize_grid_image(&app_handle, &PathBuf::from(path), max_dimension.max(1), quality.clamp(1, 100), output_format);
+
+  return OptimizeResult { bytesSaved: bytes_saved, path: steam::path_to_string(&optimized_path) };
+}
+
+/// Gets the mime type to use in a data uri for an image format.
+fn get_image_mime_type(format: ImageFormat) -> Option<&'static str> {
+  return match format {
+    ImageFormat::Png => Some("image/png"),
+    ImageFormat::Jpeg => Some("image/jpeg"),
+    ImageFormat::WebP => Some("image/webp"),
+    ImageFormat::Ico => Some("image/x-icon"),
+    _ => None
+  };
+}
+
+/// Reads an image as a base64 data uri, downscaling it to fit within max_dimension if provided.
+fn read_image_data_uri(path: &PathBuf, max_dimension: Option<u32>) -> Result<String, String> {
+  let contents: Vec<u8> = fs::read(path).map_err(| err | err.to_string())?;
+  let mut format: ImageFormat = image::guess_format(&contents).map_err(| err | err.to_string())?;
+  let mut mime_type: &str = get_image_mime_type(format).ok_or(format!("Unsupported image format {:?}", format))?;
+  let mut bytes: Vec<u8> = contents;
+
+  if let Some(max_dimension) = max_dimension.filter(| max_dimension | *max_dimension > 0) {
+    let image: DynamicImage = image::load_from_memory_with_format(&bytes, format).map_err(| err | err.to_string())?;
+
+    if image.width() > max_dimension || image.height() > max_dimension {
+      // ? Icos can't be re-encoded, so downscaled icons are sent as pngs.
+      if format == ImageFormat::Ico {
+        format = ImageFormat::Png;
+        mime_type = "image/png";
+      }
+
+      let resized: DynamicImage = image.resize(max_dimension, max_dimension, FilterType::Triangle);
+      bytes = encode_image(&resized, format, DEFAULT_OPTIMIZE_QUALITY).ok_or(String::from("Failed to encode downscaled image."))?;
+    }
+  }
+
+  if bytes.len() > MAX_PREVIEW_SIZE {
+    return Err(format!("Image is {} bytes, which is over the {} byte preview limit.", bytes.len(), MAX_PREVIEW_SIZE));
+  }
+
+  return Ok(format!("data:{};base64,{}", mime_type, STANDARD.encode(&bytes)));
+}
+
+#[tauri::command]
+/// Reads an image as a base64 data uri for previews, optionally downscaling it. Returns an empty string if it couldn't be read or is too large.
+pub fn read_image_as_base64(app_handle: AppHandle, path: String, max_dimension: Option<u32>) -> String {
+  return match read_image_data_uri(&PathBuf::from(&path), max_dimension) {
+    Ok(data_uri) => data_uri,
+    Err(err) => {
+      logger::log_tagged_to_core_file(&app_handle, "grid_images", format!("Failed to preview {}: {}", path, err).as_str(), 2);
+      String::new()
+    }
+  };
+}
+
+/// Guesses which grid type an image is meant for. Images with transparent pixels are logos, or icons if they're square.
+/// Opaque images are matched to the grid type with the closest aspect ratio, as long as it's close enough.
+fn guess_image_grid_type(image: &DynamicImage) -> Option<&'static str> {
+  let (width, height) = (image.width(), image.height());
+
+  if width == 0 || height == 0 {
+    return None;
+  }
+
+  let ratio: f64 = width as f64 / height as f64;
+  let is_transparent: bool = image.color().has_alpha() && image.to_rgba8().pixels().any(| pixel | pixel[3] < 255);
+
+  if is_transparent {
+    return Some(if (ratio - 1.0).abs() <= ASPECT_RATIO_TOLERANCE { "Icon" } else { "Logo" });
+  }
+
+  let mut differences: Vec<(&'static str, f64)> = ["Capsule", "Wide Capsule", "Hero", "Icon"].into_iter()
+    .filter_map(| grid_type | {
+      let expected: Dimensions = get_expected_dimensions(grid_type)?;
+      let expected_ratio: f64 = expected.width as f64 / expected.height as f64;
+
+      return Some((grid_type, ((ratio - expected_ratio) / expected_ratio).abs()));
+    })
+    .collect();
+  differences.sort_by(| (_, a), (_, b) | a.total_cmp(b));
+
+  let (best_type, best_difference) = differences[0];
+
+  if best_difference > GUESS_RATIO_TOLERANCE {
+    return None;
+  }
+
+  return Some(best_type);
+}
+
+#[tauri::command]
+/// Guesses which grid type an image is meant for from its aspect ratio and transparency. Returns None if it doesn't clearly fit one.
+pub fn guess_grid_type(app_handle: AppHandle, src_path: String) -> Option<String> {
+  let image: DynamicImage = match ImageReader::open(&src_path).and_then(| reader | reader.with_guessed_format()).map_err(| err | err.to_string()).and_then(| reader | reader.decode().map_err(| err | err.to_string())) {
+    Ok(image) => image,
+    Err(err) => {
+      logger::log_tagged_to_core_file(&app_handle, "grid_images", format!("Failed to read {} to guess its grid type: {}", src_path, err).as_str(), 2);
+      return None;
+    }
+  };
+
+  return guess_image_grid_type(&image).map(| grid_type | grid_type.to_owned());
+}
+
+#[tauri::command]
+/// Checks if an image's dimensions fit the expected aspect ratio of a grid type.
+pub fn check_grid_dimensions(app_handle: AppHandle, src_path: String, grid_type: String) -> Option<GridDimensions> {
+  let expected: Dimensions = match get_expected_dimensions(&grid_type) {
+    Some(expected) => expected,
+    None => {
+      logger::log_tagged_to_core_file(&app_handle, "grid_images", format!("Unexpected grid type {}", grid_type).as_str(), 2);
+      return None;
+    }
+  };
+
+  let (width, height) = read_image_dimensions(&app_handle, &PathBuf::from(&src_path))?;
+
+  return Some(GridDimensions {
+    width,
+    height,
+    matches: dimensions_match(&grid_type, width, height, &expected),
+    expected
+  });
+}
@@ -0,0 +1,110 @@
+use crate::{logger, steam};
+
+use std::{collections::BTreeMap, path::PathBuf, fs};
+
+use chrono::prelude::*;
+use tauri::AppHandle;
+
+#[derive(Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct SnapshotEntry {
+  hash: String,
+  mtime: u64,
+  bytes: u64
+}
+
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+#[allow(non_snake_case)]
+pub struct GridsSnapshot {
+  gridsDir: String,
+  createdAt: String,
+  files: BTreeMap<String, SnapshotEntry>
+}
+
+#[derive(Clone, serde::Serialize)]
+pub struct GridsSnapshotDiff {
+  added: Vec<String>,
+  removed: Vec<String>,
+  modified: Vec<String>
+}
+
+/// Hashes bytes with 64 bit FNV-1a. Hashes are saved and compared across runs, so they have to be stable, which std's hasher isn't.
+pub fn hash_bytes(bytes: &[u8]) -> String {
+  let mut hash: u64 = 0xcbf29ce484222325;
+
+  for byte in bytes.iter() {
+    hash ^= *byte as u64;
+    hash = hash.wrapping_mul(0x100000001b3);
+  }
+
+  return format!("{:016x}", hash);
+}
+
+/// Hashes a file's contents.
+fn hash_file_contents(path: &PathBuf) -> Option<String> {
+  let contents: Vec<u8> = fs::read(path).ok()?;
+  return Some(hash_bytes(&contents));
+}
+
+/// Records the hash, modified time, and size of every file in a grids dir, keyed by its path relative to the dir.
+fn take_snapshot(app_handle: &AppHandle, grids_dir: &PathBuf) -> GridsSnapshot {
+  let mut files: BTreeMap<String, SnapshotEntry> = BTreeMap::new();
+
+  for grid_path in steam::list_grid_files(grids_dir).into_iter() {
+    let relative_path: String = grid_path.strip_prefix(grids_dir).map(| relative | steam::path_to_string(&relative.to_path_buf())).unwrap_or_else(| _ | steam::path_to_string(&grid_path));
+
+    let (metadata, hash) = match (fs::metadata(&grid_path), hash_file_contents(&grid_path)) {
+      (Ok(metadata), Some(hash)) => (metadata, hash),
+      _ => {
+        logger::log_tagged_to_core_file(app_handle, "grids_snapshot", format!("Skipping {}, it couldn't be read.", grid_path.display()).as_str(), 1);
+        continue;
+      }
+    };
+
+    let mtime: u64 = metadata.modified().ok()
+      .and_then(| modified | modified.duration_since(std::time::UNIX_EPOCH).ok())
+      .map(| since_epoch | since_epoch.as_secs())
+      .unwrap_or(0);
+
+    files.insert(relative_path, SnapshotEntry { hash, mtime, bytes: metadata.len() });
+  }
+
+  return GridsSnapshot {
+    gridsDir: steam::path_to_string(grids_dir),
+    createdAt: Local::now().to_rfc3339(),
+    files
+  };
+}
+
+#[tauri::command]
+/// Takes a snapshot of the user's grids dir, so it can be compared later to see what changed it.
+pub fn snapshot_grids(app_handle: AppHandle, steam_active_user_id: String) -> String {
+  let grids_dir: PathBuf = PathBuf::from(steam::get_grids_directory(app_handle.to_owned(), steam_active_user_id, None));
+  let snapshot: GridsSnapshot = take_snapshot(&app_handle, &grids_dir);
+
+  logger::log_tagged_to_core_file(&app_handle, "grids_snapshot", format!("Took a snapshot of {} grid files.", snapshot.files.len()).as_str(), 0);
+  return serde_json::to_string(&snapshot).expect("Should have been able to serialize grids snapshot.");
+}
+
+#[tauri::command]
+/// Compares the user's grids dir to an earlier snapshot, listing the files that were added, removed, or modified since.
+/// A file only counts as modified if its contents changed, since steam sometimes touches files without changing them.
+pub fn diff_grids_snapshot(app_handle: AppHandle, steam_active_user_id: String, prior_json: String) -> Result<GridsSnapshotDiff, String> {
+  let prior: GridsSnapshot = serde_json::from_str(&prior_json).map_err(| err | format!("Invalid grids snapshot: {}", err))?;
+
+  let grids_dir: PathBuf = PathBuf::from(steam::get_grids_directory(app_handle.to_owned(), steam_active_user_id, None));
+  let current: GridsSnapshot = take_snapshot(&app_handle, &grids_dir);
+
+  if !steam::paths_match(&prior.gridsDir, &current.gridsDir) {
+    logger::log_tagged_to_core_file(&app_handle, "grids_snapshot", format!("Comparing a snapshot of {} against {}.", prior.gridsDir, current.gridsDir).as_str(), 1);
+  }
+
+  let added: Vec<String> = current.files.keys().filter(| path | !prior.files.contains_key(*path)).cloned().collect();
+  let removed: Vec<String> = prior.files.keys().filter(| path | !current.files.contains_key(*path)).cloned().collect();
+  let modified: Vec<String> = current.files.iter()
+    .filter(| (path, entry) | prior.files.get(*path).is_some_and(| prior_entry | prior_entry.hash != entry.hash))
+    .map(| (path, _) | path.to_owned())
+    .collect();
+
+  logger::log_tagged_to_core_file(&app_handle, "grids_snapshot", format!("Since {}: {} added, {} removed, {} modified.", prior.createdAt, added.len(), removed.len(), modified.len()).as_str(), 0);
+  return Ok(GridsSnapshotDiff { added, removed, modified });
+}
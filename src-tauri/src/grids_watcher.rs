@@ -0,0 +1,77 @@
+use crate::{logger, steam};
+
+use std::{path::PathBuf, sync::Mutex, time::Duration};
+
+use notify::{RecommendedWatcher, RecursiveMode};
+use notify_debouncer_mini::{new_debouncer, DebounceEventResult, Debouncer};
+use tauri::{AppHandle, Manager};
+
+/// How long the grids dir needs to be quiet before changes are sent to the frontend.
+const DEBOUNCE_DURATION: Duration = Duration::from_millis(500);
+
+#[derive(Clone, serde::Serialize)]
+struct GridsChangedPayload {
+  paths: Vec<String>
+}
+
+/// Holds the watcher for the active user's grids dir, if one has been started.
+#[derive(Default)]
+pub struct GridsWatcher {
+  debouncer: Mutex<Option<Debouncer<RecommendedWatcher>>>
+}
+
+#[tauri::command]
+/// Watches the user's grids dir, emitting grids-changed when files in it are created, modified, or deleted.
+pub fn watch_grids_directory(app_handle: AppHandle, steam_active_user_id: String) -> bool {
+  let grids_dir: PathBuf = PathBuf::from(steam::get_grids_directory(app_handle.to_owned(), steam_active_user_id, None));
+  let event_app_handle: AppHandle = app_handle.to_owned();
+
+  let debouncer_res = new_debouncer(DEBOUNCE_DURATION, move | res: DebounceEventResult | {
+    match res {
+      Ok(events) => {
+        let paths: Vec<String> = events.into_iter().map(| event | steam::path_to_string(&event.path)).collect();
+
+        if !paths.is_empty() {
+          // ? The watcher keeps running if the frontend can't be reached, so a failed emit is only logged.
+          if let Err(err) = event_app_handle.emit_all("grids-changed", GridsChangedPayload { paths }) {
+            logger::log_tagged_to_core_file(&event_app_handle, "grids_watcher", format!("Failed to emit grids-changed: {}", err).as_str(), 2);
+          }
+        }
+      },
+      Err(err) => {
+        logger::log_tagged_to_core_file(&event_app_handle, "grids_watcher", format!("Error while watching grids dir: {}", err).as_str(), 2);
+      }
+    }
+  });
+
+  let mut debouncer = match debouncer_res {
+    Ok(debouncer) => debouncer,
+    Err(err) => {
+      logger::log_tagged_to_core_file(&app_handle, "grids_watcher", format!("Failed to create grids dir watcher: {}", err).as_str(), 2);
+      return false;
+    }
+  };
+
+  if let Err(err) = debouncer.watcher().watch(&grids_dir, RecursiveMode::NonRecursive) {
+    logger::log_tagged_to_core_file(&app_handle, "grids_watcher", format!("Failed to watch {}: {}", grids_dir.display(), err).as_str(), 2);
+    return false;
+  }
+
+  // ? Replacing the old debouncer drops it, which stops watching the previous user's grids.
+  let watcher_state = app_handle.state::<GridsWatcher>();
+  *watcher_state.debouncer.lock().expect("Should have been able to lock the grids watcher.") = Some(debouncer);
+
+  logger::log_tagged_to_core_file(&app_handle, "grids_watcher", format!("Watching {} for changes.", grids_dir.display()).as_str(), 0);
+  return true;
+}
+
+#[tauri::command]
+/// Stops watching the user's grids dir.
+pub fn unwatch_grids_directory(app_handle: AppHandle) {
+  let watcher_state = app_handle.state::<GridsWatcher>();
+  let old_debouncer = watcher_state.debouncer.lock().expect("Should have been able to lock the grids watcher.").take();
+
+  if old_debouncer.is_some() {
+    logger::log_tagged_to_core_file(&app_handle, "grids_watcher", "Stopped watching grids dir.", 0);
+  }
+}
@@ -0,0 +1,119 @@
+use crate::logger;
+
+use std::{path::PathBuf, fs, env};
+
+use serde_json::{Map, Value};
+use tauri::AppHandle;
+
+#[cfg(not(target_os = "windows"))]
+use home::home_dir;
+
+#[derive(Clone, serde::Serialize)]
+#[allow(non_snake_case)]
+pub struct HeroicGame {
+  appId: String,
+  name: String,
+  runner: String
+}
+
+/// The library files Heroic caches, along with the runner their games use.
+const HEROIC_LIBRARY_FILES: [(&str, &str); 4] = [
+  ("store_cache/legendary_library.json", "legendary"),
+  ("store_cache/gog_library.json", "gog"),
+  ("store_cache/nile_library.json", "nile"),
+  ("sideload_apps/library.json", "sideload")
+];
+
+#[cfg(target_os = "windows")]
+/// Gets the possible Heroic config directories for windows systems.
+fn get_heroic_config_dirs() -> Vec<PathBuf> {
+  let mut config_dirs: Vec<PathBuf> = Vec::new();
+
+  if let Ok(app_data) = env::var("APPDATA") {
+    config_dirs.push(PathBuf::from(app_data).join("heroic"));
+  }
+
+  return config_dirs;
+}
+
+#[cfg(not(target_os = "windows"))]
+/// Gets the possible Heroic config directories for linux systems.
+fn get_heroic_config_dirs() -> Vec<PathBuf> {
+  let mut config_dirs: Vec<PathBuf> = Vec::new();
+
+  if let Ok(xdg_config_home) = env::var("XDG_CONFIG_HOME") {
+    config_dirs.push(PathBuf::from(xdg_config_home).join("heroic"));
+  }
+
+  if let Some(pc_home_dir) = home_dir() {
+    config_dirs.push(pc_home_dir.join(".config/heroic"));
+    config_dirs.push(pc_home_dir.join(".var/app/com.heroicgameslauncher.hgl/config/heroic"));
+  }
+
+  return config_dirs;
+}
+
+/// Reads the games from one of Heroic's library files.
+fn read_heroic_library(app_handle: &AppHandle, library_path: &PathBuf, runner: &str) -> Vec<HeroicGame> {
+  let mut games: Vec<HeroicGame> = Vec::new();
+
+  let contents_res = fs::read_to_string(library_path);
+  if contents_res.is_err() {
+    return games;
+  }
+
+  let library_res = serde_json::from_str::<Map<String, Value>>(&contents_res.unwrap());
+  if library_res.is_err() {
+    logger::log_tagged_to_core_file(app_handle, "heroic", format!("Failed to parse Heroic library {}.", library_path.display()).as_str(), 1);
+    return games;
+  }
+
+  let library: Map<String, Value> = library_res.unwrap();
+  // ? The legendary cache stores its games under "library", while the others use "games".
+  let entries_val = library.get("library").or(library.get("games"));
+
+  if entries_val.is_some() && entries_val.unwrap().is_array() {
+    for entry in entries_val.unwrap().as_array().unwrap().iter() {
+      let app_name = entry.get("app_name").and_then(| val | val.as_str());
+      let title = entry.get("title").and_then(| val | val.as_str());
+
+      if app_name.is_some() && title.is_some() {
+        games.push(HeroicGame {
+          appId: app_name.unwrap().to_owned(),
+          name: title.unwrap().to_owned(),
+          runner: runner.to_owned()
+        });
+      }
+    }
+  }
+
+  return games;
+}
+
+#[tauri::command]
+/// Gets the games in the user's Heroic Games Launcher library as a JSON array.
+pub fn get_heroic_games(app_handle: AppHandle) -> String {
+  logger::log_tagged_to_core_file(&app_handle, "heroic", "Checking for Heroic games...", 0);
+
+  let mut games: Vec<HeroicGame> = Vec::new();
+
+  for config_dir in get_heroic_config_dirs().into_iter() {
+    if !config_dir.is_dir() {
+      continue;
+    }
+
+    for (library_file, runner) in HEROIC_LIBRARY_FILES {
+      let library_path: PathBuf = config_dir.join(library_file);
+
+      for game in read_heroic_library(&app_handle, &library_path, runner).into_iter() {
+        if !games.iter().any(| existing | existing.appId == game.appId && existing.runner == game.runner) {
+          games.push(game);
+        }
+      }
+    }
+  }
+
+  logger::log_tagged_to_core_file(&app_handle, "heroic", format!("Loaded {} Heroic games.", games.len()).as_str(), 0);
+
+  return serde_json::to_string(&games).expect("Should have been able to serialize Heroic games.");
+}
@@ -0,0 +1,130 @@
+use crate::logger;
+
+use std::{fs::File, io::{Read, Seek, SeekFrom}, path::PathBuf, sync::{Arc, Mutex}, time::Duration};
+
+use notify::{RecommendedWatcher, RecursiveMode};
+use notify_debouncer_mini::{new_debouncer, DebounceEventResult, Debouncer};
+use tauri::{AppHandle, Manager};
+
+/// How long the log needs to be quiet before new lines are sent to the frontend.
+const DEBOUNCE_DURATION: Duration = Duration::from_millis(200);
+
+#[derive(Clone, serde::Serialize)]
+struct LogLinePayload {
+  line: String
+}
+
+/// Holds the watcher for core.log, if a stream has been started.
+#[derive(Default)]
+pub struct LogStream {
+  debouncer: Mutex<Option<Debouncer<RecommendedWatcher>>>
+}
+
+/// Reads the complete lines appended to the log since the provided offset, advancing it past them.
+fn read_new_lines(log_path: &PathBuf, offset: &mut u64) -> Vec<String> {
+  let mut log_file: File = match File::open(log_path) {
+    Ok(log_file) => log_file,
+    Err(_) => return Vec::new()
+  };
+
+  let log_len: u64 = log_file.metadata().map(| metadata | metadata.len()).unwrap_or(0);
+
+  // ? The log is truncated when the app launches, so start from the top if it shrank.
+  if log_len < *offset {
+    *offset = 0;
+  }
+
+  if log_file.seek(SeekFrom::Start(*offset)).is_err() {
+    return Vec::new();
+  }
+
+  let mut appended: Vec<u8> = Vec::new();
+  if log_file.read_to_end(&mut appended).is_err() {
+    return Vec::new();
+  }
+
+  // ? Leave a partially written line for the next read.
+  let complete_len: usize = match appended.iter().rposition(| byte | *byte == b'\n') {
+    Some(last_newline) => last_newline + 1,
+    None => return Vec::new()
+  };
+
+  *offset += complete_len as u64;
+
+  return String::from_utf8_lossy(&appended[..complete_len]).lines()
+    .map(| line | line.trim_end_matches('\r').to_owned())
+    .filter(| line | !line.is_empty())
+    .collect();
+}
+
+#[tauri::command]
+/// Watches core.log, emitting each line appended to it as a log-line event.
+pub fn start_log_stream(app_handle: AppHandle) -> bool {
+  let log_path: PathBuf = logger::get_core_log_path(&app_handle);
+  let start_offset: u64 = log_path.metadata().map(| metadata | metadata.len()).unwrap_or(0);
+  let offset: Arc<Mutex<u64>> = Arc::new(Mutex::new(start_offset));
+
+  let event_app_handle: AppHandle = app_handle.to_owned();
+  let event_log_path: PathBuf = log_path.to_owned();
+
+  // ? Logging an error writes to core.log and triggers the watcher again, so each failure is only logged until the stream recovers.
+  let mut logged_failure: bool = false;
+
+  let debouncer_res = new_debouncer(DEBOUNCE_DURATION, move | res: DebounceEventResult | {
+    let failure: Option<String> = match res {
+      Ok(_) => {
+        let mut offset = offset.lock().unwrap_or_else(| poisoned | poisoned.into_inner());
+        let mut emit_err: Option<String> = None;
+
+        for line in read_new_lines(&event_log_path, &mut offset) {
+          if let Err(err) = event_app_handle.emit_all("log-line", LogLinePayload { line }) {
+            emit_err = Some(format!("Failed to emit log-line: {}", err));
+          }
+        }
+
+        emit_err
+      },
+      Err(err) => Some(format!("Error while streaming log: {}", err))
+    };
+
+    match failure {
+      Some(failure) if !logged_failure => {
+        logged_failure = true;
+        logger::log_tagged_to_core_file(&event_app_handle, "log_stream", &failure, 2);
+      },
+      Some(_) => {},
+      None => logged_failure = false
+    }
+  });
+
+  let mut debouncer = match debouncer_res {
+    Ok(debouncer) => debouncer,
+    Err(err) => {
+      logger::log_tagged_to_core_file(&app_handle, "log_stream", format!("Failed to create log watcher: {}", err).as_str(), 2);
+      return false;
+    }
+  };
+
+  if let Err(err) = debouncer.watcher().watch(&log_path, RecursiveMode::NonRecursive) {
+    logger::log_tagged_to_core_file(&app_handle, "log_stream", format!("Failed to watch {}: {}", log_path.display(), err).as_str(), 2);
+    return false;
+  }
+
+  // ? Replacing the old debouncer drops it, which ends any stream that was already running.
+  let stream_state = app_handle.state::<LogStream>();
+  *stream_state.debouncer.lock().expect("Should have been able to lock the log stream.") = Some(debouncer);
+
+  logger::log_tagged_to_core_file(&app_handle, "log_stream", "Started streaming core.log.", 0);
+  return true;
+}
+
+#[tauri::command]
+/// Stops streaming core.log.
+pub fn stop_log_stream(app_handle: AppHandle) {
+  let stream_state = app_handle.state::<LogStream>();
+  let old_debouncer = stream_state.debouncer.lock().expect("Should have been able to lock the log stream.").take();
+
+  if old_debouncer.is_some() {
+    logger::log_tagged_to_core_file(&app_handle, "log_stream", "Stopped streaming core.log.", 0);
+  }
+}
@@ -1,5 +1,6 @@
 use std::path::PathBuf;
 use std::io::*;
+use std::sync::{Mutex, MutexGuard};
 use std::fs::{
   create_dir_all,
   File,
@@ -9,6 +10,14 @@ use std::fs::{
 use tauri::AppHandle;
 use chrono::prelude::*;
 
+/// Held while a log file is written to, so lines logged from different threads aren't interleaved.
+static LOG_FILE_LOCK: Mutex<()> = Mutex::new(());
+
+/// Locks the log files. A panic while logging doesn't leave the files unusable, so a poisoned lock is still taken.
+fn lock_log_files() -> MutexGuard<'static, ()> {
+  return LOG_FILE_LOCK.lock().unwrap_or_else(| poisoned | poisoned.into_inner());
+}
+
 /// Gets the log file path for this app.
 pub fn get_core_log_path(app_handle: &AppHandle) -> PathBuf {
   let app_log_dir: PathBuf = app_handle.to_owned().path_resolver().app_log_dir().expect("Tried to resolve app log dir and failed.");
@@ -31,8 +40,24 @@ pub fn get_batch_apply_log_path(app_handle: &AppHandle) -> PathBuf {
   return app_log_dir.join("batch-apply.log");
 }
 
+/// Gets the name of a numeric log level.
+fn get_level_name(level: u8) -> &'static str {
+  return if level == 0 { "INFO" } else if level == 1 { "WARN" } else { "ERROR" };
+}
+
 /// General function to log a message to the provided file.
 pub fn log_to_file(log_path: &PathBuf, message: &str, level: u8) {
+  log_tagged_to_file(log_path, None, message, level);
+}
+
+/// Logs a message to the provided file, tagged with the module or source it came from.
+pub fn log_tagged_to_file(log_path: &PathBuf, source: Option<&str>, message: &str, level: u8) {
+  let level_name: &str = get_level_name(level);
+  let timestamp: String = Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string();
+  let source_tag: String = source.map(| source | format!(" [{}]", source)).unwrap_or_default();
+  let line: String = format!("{timestamp} [{level_name}]{source_tag} {message}\n");
+
+  let log_lock = lock_log_files();
   let log_file_res = OpenOptions::new()
     .create(true)
     .write(true)
@@ -42,26 +67,27 @@ pub fn log_to_file(log_path: &PathBuf, message: &str, level: u8) {
   if log_file_res.is_ok() {
     let mut log_file = log_file_res.unwrap();
 
-    let level_name: &str = if level == 0 { "INFO" } else if level == 1 { "WARNING" } else { "ERROR" };
-  
-    let now: DateTime<Local> = Local::now();
-    let hour: u32 = now.hour();
-    let min: u32 = now.minute();
-    let sec: u32 = now.second();
-  
-    if let Err(e) = writeln!(log_file, "[Steam Art Manager] [{hour}:{min}:{sec}] [{level_name}]: {message}") {
+    // ? Writing the line in one call keeps it intact even if another process appends to the file too.
+    if let Err(e) = log_file.write_all(line.as_bytes()) {
       eprintln!("Couldn't write to file: {}", e);
     }
   } else {
+    // ? The panic hook logs too, so the lock has to be released first or it would deadlock.
+    drop(log_lock);
     panic!("Error opening log file! Log file path: {}", log_path.display());
   }
 }
 
+/// Logs a message to core.log, tagged with the module or source it came from.
+pub fn log_tagged_to_core_file(app_handle: &AppHandle, source: &str, message: &str, level: u8) {
+  let log_path: PathBuf = get_core_log_path(app_handle);
+  log_tagged_to_file(&log_path, Some(source), message, level);
+}
+
 #[tauri::command]
-/// Logs a message to file with level 0 (info), 1 (warn), or 2 (err) to core.log.
+/// Logs a message from the frontend to file with level 0 (info), 1 (warn), or 2 (err) to core.log. The backend logs with log_tagged_to_core_file instead.
 pub fn log_to_core_file(app_handle: AppHandle, message: &str, level: u8) {
-  let log_path: PathBuf = get_core_log_path(&app_handle);
-  log_to_file(&log_path, message, level);
+  log_tagged_to_core_file(&app_handle, "frontend", message, level);
 }
 
 #[tauri::command]
@@ -75,10 +101,16 @@ pub fn log_to_batch_apply_file(app_handle: AppHandle, message: &str, level: u8)
 /// Cleans the log file for a new launch of the app.
 pub fn clean_out_log(app_handle: AppHandle) {
   let core_log_path: PathBuf = get_core_log_path(&app_handle);
-  File::create(&core_log_path).expect("Core log path should have existed.");
-
   let batch_apply_log_path: PathBuf = get_core_log_path(&app_handle);
-  File::create(&batch_apply_log_path).expect("Batch Apply log path should have existed.");
 
-  log_to_core_file(app_handle, "Initialized logging file", 0);
+  // ? Truncating is done under the lock, but any panic has to happen after it's released since the panic hook logs.
+  let log_lock = lock_log_files();
+  let core_log_res = File::create(&core_log_path);
+  let batch_apply_log_res = File::create(&batch_apply_log_path);
+  drop(log_lock);
+
+  core_log_res.expect("Core log path should have existed.");
+  batch_apply_log_res.expect("Batch Apply log path should have existed.");
+
+  log_tagged_to_core_file(&app_handle, "logger", "Initialized logging file", 0);
 }
\ No newline at end of file
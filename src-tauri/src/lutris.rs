@@ -0,0 +1,136 @@
+use crate::logger;
+
+use std::{path::PathBuf, fs};
+
+use serde_yaml::{Mapping, Value as YamlValue};
+use tauri::AppHandle;
+
+#[cfg(target_os = "linux")]
+use home::home_dir;
+
+#[derive(Clone, serde::Serialize)]
+#[allow(non_snake_case)]
+pub struct LutrisGame {
+  slug: String,
+  name: String,
+  runner: String,
+  exe: String,
+  workingDir: String
+}
+
+#[cfg(target_os = "linux")]
+/// Gets the possible Lutris game config directories for linux systems.
+fn get_lutris_games_dirs() -> Vec<PathBuf> {
+  let mut games_dirs: Vec<PathBuf> = Vec::new();
+
+  if let Some(pc_home_dir) = home_dir() {
+    games_dirs.push(pc_home_dir.join(".config/lutris/games"));
+    games_dirs.push(pc_home_dir.join(".local/share/lutris/games"));
+    games_dirs.push(pc_home_dir.join(".var/app/net.lutris.Lutris/config/lutris/games"));
+    games_dirs.push(pc_home_dir.join(".var/app/net.lutris.Lutris/data/lutris/games"));
+  }
+
+  return games_dirs;
+}
+
+#[cfg(not(target_os = "linux"))]
+/// Lutris is only available on linux, so there are no game config directories.
+fn get_lutris_games_dirs() -> Vec<PathBuf> {
+  return Vec::new();
+}
+
+/// Gets a game's slug and display name from its config file name, which looks like "<slug>-<timestamp>.yml".
+/// The real name is only stored in Lutris' pga.db, so this is an approximation made by title casing the slug (ex: "The Witcher 3 Wild Hunt").
+fn get_name_from_config_filename(filename: &str) -> (String, String) {
+  let stem: &str = filename.trim_end_matches(".yml");
+  let mut slug: &str = stem;
+
+  if let Some(dash_index) = stem.rfind("-") {
+    if stem[(dash_index + 1)..].chars().all(| c | c.is_ascii_digit()) {
+      slug = &stem[..dash_index];
+    }
+  }
+
+  let name: String = slug.split("-").filter(| word | !word.is_empty()).map(| word | {
+    let mut chars = word.chars();
+    let first = chars.next().unwrap();
+    return first.to_uppercase().collect::<String>() + chars.as_str();
+  }).collect::<Vec<String>>().join(" ");
+
+  return (slug.to_owned(), name);
+}
+
+/// Reads a Lutris game from its config file.
+fn read_lutris_game_config(app_handle: &AppHandle, config_path: &PathBuf) -> Option<LutrisGame> {
+  let contents: String = fs::read_to_string(config_path).ok()?;
+  let config_res = serde_yaml::from_str::<Mapping>(&contents);
+
+  if config_res.is_err() {
+    logger::log_tagged_to_core_file(app_handle, "lutris", format!("Failed to parse Lutris config {}.", config_path.display()).as_str(), 1);
+    return None;
+  }
+
+  let config: Mapping = config_res.unwrap();
+  let filename: &str = config_path.file_name()?.to_str()?;
+  let (slug, name) = get_name_from_config_filename(filename);
+
+  let game_section = config.get("game");
+  let exe: String = game_section.and_then(| game | game.get("exe")).and_then(| exe | exe.as_str()).unwrap_or("").to_owned();
+  let working_dir: String = game_section.and_then(| game | game.get("working_dir")).and_then(| dir | dir.as_str()).unwrap_or("").to_owned();
+
+  // ? Besides "game" and "system", the top level key of the config is the game's runner.
+  let mut runner: String = String::from("");
+  for (key, _) in config.iter() {
+    if let YamlValue::String(key_str) = key {
+      if key_str != "game" && key_str != "system" {
+        runner = key_str.to_owned();
+        break;
+      }
+    }
+  }
+
+  return Some(LutrisGame { slug, name, runner, exe, workingDir: working_dir });
+}
+
+#[tauri::command]
+/// Gets the games in the user's Lutris library as a JSON array.
+/// Names are approximated from the config slugs, so punctuation and capitalization may not match what Lutris shows.
+pub fn get_lutris_games(app_handle: AppHandle) -> String {
+  logger::log_tagged_to_core_file(&app_handle, "lutris", "Checking for Lutris games...", 0);
+
+  let mut games: Vec<LutrisGame> = Vec::new();
+
+  for games_dir in get_lutris_games_dirs().into_iter() {
+    if !games_dir.is_dir() {
+      continue;
+    }
+
+    let games_dir_contents = match fs::read_dir(&games_dir) {
+      Ok(contents) => contents,
+      Err(err) => {
+        logger::log_tagged_to_core_file(&app_handle, "lutris", format!("Failed to read {}: {}. Skipping...", games_dir.display(), err).as_str(), 1);
+        continue;
+      }
+    };
+
+    for entry in games_dir_contents.flatten() {
+      let entry_path: PathBuf = entry.path();
+
+      if entry_path.is_file() && entry_path.extension().is_some() && entry_path.extension().unwrap() == "yml" {
+        let game = read_lutris_game_config(&app_handle, &entry_path);
+
+        if game.is_some() {
+          let game = game.unwrap();
+
+          if !games.iter().any(| existing | existing.slug == game.slug) {
+            games.push(game);
+          }
+        }
+      }
+    }
+  }
+
+  logger::log_tagged_to_core_file(&app_handle, "lutris", format!("Loaded {} Lutris games.", games.len()).as_str(), 0);
+
+  return serde_json::to_string(&games).expect("Should have been able to serialize Lutris games.");
+}
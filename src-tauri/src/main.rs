@@ -9,12 +9,32 @@ mod zip_controller;
 mod appinfo_vdf_parser;
 mod shortcuts_vdf_parser;
 mod vdf_reader;
-
-use std::{path::PathBuf, collections::HashMap, fs::{self, File}, io::Write, time::Duration, panic::{self, Location}, process::exit, fmt::Arguments};
-
-use appinfo_vdf_parser::open_appinfo_vdf;
+mod settings;
+mod backups;
+mod heroic;
+mod lutris;
+mod app_cache;
+mod grid_images;
+mod grids_watcher;
+mod diagnostics;
+mod download_cache;
+mod download_jobs;
+mod steamgriddb;
+mod log_stream;
+mod store_art;
+mod art_profiles;
+mod caches;
+mod controller_configs;
+mod disk_space;
+mod grids_snapshot;
+mod proton;
+mod app_dirs;
+
+use std::{path::PathBuf, collections::HashMap, borrow::Cow, fs::{self, File}, io::{self, Write}, time::Duration, panic::{self, Location}, process::exit, fmt::Arguments};
+
+use appinfo_vdf_parser::{open_appinfo_vdf, find_app_info};
 use serde_json::{Map, Value};
-use shortcuts_vdf_parser::{open_shortcuts_vdf, write_shortcuts_vdf};
+use shortcuts_vdf_parser::{open_shortcuts_vdf, write_shortcuts_vdf, try_write_shortcuts_vdf, try_open_shortcuts_vdf, CheckedShortcuts};
 
 use home::home_dir;
 
@@ -26,7 +46,7 @@ use tauri::{
   api::dialog::{blocking::{FileDialogBuilder, MessageDialogBuilder}, MessageDialogButtons},
   FsScope, Manager
 };
-use keyvalues_parser::Vdf;
+use keyvalues_parser::{Vdf, Obj, Value as VdfValue};
 
 #[derive(Clone, serde::Serialize)]
 struct Payload {
@@ -47,6 +67,203 @@ struct CleanConflicts {
 
 type GridImageCache = HashMap<String, HashMap<String, String>>;
 
+#[derive(Clone, serde::Serialize)]
+#[allow(non_snake_case)]
+struct SaveProgress {
+  current: usize,
+  total: usize,
+  appId: String,
+  gridType: String
+}
+
+#[derive(Clone, serde::Serialize)]
+#[allow(non_snake_case)]
+struct SaveError {
+  appId: String,
+  gridType: String,
+  error: String
+}
+
+#[derive(Clone, serde::Serialize)]
+#[allow(non_snake_case)]
+struct SaveWarning {
+  appId: String,
+  gridType: String,
+  sourcePath: String,
+  warning: String
+}
+
+#[derive(Clone, Default, serde::Serialize)]
+#[allow(non_snake_case)]
+struct SaveSummary {
+  applied: usize,
+  removed: usize,
+  failed: usize,
+  errors: Vec<SaveError>,
+  warnings: Vec<SaveWarning>,
+  changedPaths: Vec<ChangedPath>,
+  failedPaths: Vec<ChangedPath>
+}
+
+#[derive(Clone, serde::Serialize)]
+struct ManifestImportResult {
+  applied: usize,
+  missing: Vec<String>,
+  error: Option<String>
+}
+
+#[derive(Clone, serde::Serialize)]
+struct GridFile {
+  name: String,
+  path: String
+}
+
+#[derive(Clone, serde::Serialize)]
+struct GridRename {
+  from: String,
+  to: String
+}
+
+#[derive(Clone, serde::Serialize)]
+struct GridRemoveFailure {
+  path: String,
+  error: String
+}
+
+#[derive(Clone, serde::Serialize)]
+struct GridRemoveResult {
+  removed: Vec<String>,
+  failed: Vec<GridRemoveFailure>
+}
+
+#[derive(Clone, serde::Serialize)]
+#[allow(non_snake_case)]
+struct AppArtCoverage {
+  appId: String,
+  filled: Vec<String>,
+  missing: Vec<String>
+}
+
+#[derive(Clone, serde::Serialize)]
+#[allow(non_snake_case)]
+struct ArtCoverage {
+  apps: Vec<AppArtCoverage>,
+  fullyThemed: usize,
+  partiallyThemed: usize,
+  untouched: usize
+}
+
+#[derive(Clone, serde::Serialize)]
+#[allow(non_snake_case)]
+struct UserSaveResult {
+  success: bool,
+  changedCount: usize,
+  error: Option<String>
+}
+
+#[derive(Clone, serde::Serialize)]
+#[allow(non_snake_case)]
+struct ShortcutsStatus {
+  exists: bool,
+  path: String,
+  shortcutCount: usize
+}
+
+#[derive(Clone, serde::Serialize)]
+#[allow(non_snake_case)]
+struct ShortcutIssue {
+  shortcutId: Option<String>,
+  issue: String
+}
+
+#[derive(Clone, serde::Serialize)]
+#[allow(non_snake_case)]
+struct BrokenShortcutIcon {
+  appId: String,
+  appName: String,
+  icon: String
+}
+
+#[derive(Clone, serde::Serialize)]
+struct ShortcutsValidation {
+  valid: bool,
+  issues: Vec<ShortcutIssue>
+}
+
+#[derive(Clone, serde::Serialize)]
+struct GridsWritableStatus {
+  writable: bool,
+  reason: Option<String>
+}
+
+#[derive(Clone, serde::Serialize)]
+#[allow(non_snake_case)]
+struct ArtProfileDiff {
+  appId: String,
+  gridType: String,
+  change: String,
+  from: Option<String>,
+  to: Option<String>
+}
+
+#[derive(Clone, serde::Serialize)]
+#[allow(non_snake_case)]
+struct GridFileInfo {
+  path: String,
+  appId: Option<String>,
+  gridType: Option<String>,
+  width: u32,
+  height: u32,
+  format: String,
+  bytes: u64,
+  mtime: u64
+}
+
+#[derive(Clone, serde::Serialize)]
+#[allow(non_snake_case)]
+struct LocalArtInfo {
+  path: String,
+  source: String,
+  appId: Option<String>,
+  gridType: Option<String>,
+  guessedGridType: Option<String>,
+  width: u32,
+  height: u32,
+  format: String
+}
+
+#[derive(Clone, serde::Deserialize)]
+#[allow(non_snake_case)]
+struct UrlImportJob {
+  appId: String,
+  gridType: String,
+  url: String
+}
+
+#[derive(Clone, serde::Serialize)]
+#[allow(non_snake_case)]
+struct DownloadTestResult {
+  status: Option<u16>,
+  contentType: Option<String>,
+  bytes: u64,
+  isImage: bool,
+  detectedFormat: Option<String>,
+  error: Option<String>
+}
+
+#[derive(Clone, serde::Serialize)]
+#[allow(non_snake_case)]
+struct UrlImportResult {
+  appId: String,
+  gridType: String,
+  success: bool,
+  path: Option<String>,
+  error: Option<String>
+}
+
+/// The file extensions grids can be saved with.
+const GRID_IMAGE_EXTENSIONS: [&str; 5] = [".png", ".jpg", ".jpeg", ".webp", ".ico"];
+
 #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq, Clone)]
 #[allow(non_snake_case)]
 struct ChangedPath {
@@ -57,50 +274,102 @@ struct ChangedPath {
   sourcePath: String
 }
 
-/// Gets a grid's file name based on its type.
-fn get_grid_filename(app_handle: &AppHandle, appid: &str, grid_type: &str, image_type: &str) -> String {
-  match grid_type {
-    "Capsule" => return format!("{}p{}", appid, image_type),
-    "Wide Capsule" => return format!("{}{}", appid, image_type),
-    "Hero" => return format!("{}_hero{}", appid, image_type),
-    "Logo" => return format!("{}_logo{}", appid, image_type),
-    "Icon" => return format!("{}_icon{}", appid, image_type),
-    _ => {
-      logger::log_to_core_file(app_handle.to_owned(), format!("Unexpected grid type {}", grid_type).as_str(), 2);
-      panic!("Unexpected grid type {}", grid_type);
+/// Maps each grid type to the suffix its file names have between the appid and the extension.
+type GridTypeTable = Vec<(String, String)>;
+
+/// The grid types Steam supports and their file name suffixes, in the order they're listed in art manifests.
+const DEFAULT_GRID_TYPE_SUFFIXES: [(&str, &str); 5] = [("Capsule", "p"), ("Wide Capsule", ""), ("Hero", "_hero"), ("Logo", "_logo"), ("Icon", "_icon")];
+
+/// Gets the grid types and their file name suffixes, including any the user added with the customGridTypes setting.
+fn get_grid_type_table(app_handle: &AppHandle) -> GridTypeTable {
+  let mut grid_types: GridTypeTable = DEFAULT_GRID_TYPE_SUFFIXES.iter().map(| (grid_type, suffix) | (grid_type.to_string(), suffix.to_string())).collect();
+  let custom_types: Map<String, Value> = settings::get_setting(app_handle, "customGridTypes").and_then(| value | value.as_object().cloned()).unwrap_or_default();
+
+  for (grid_type, suffix_val) in custom_types.iter() {
+    let suffix: &str = suffix_val.as_str().unwrap_or("");
+
+    // ? Suffixes can't be empty or start with a digit, otherwise they couldn't be told apart from the appid.
+    let is_valid_suffix: bool = !suffix.is_empty() && !suffix.starts_with(| c: char | c.is_ascii_digit()) && suffix.chars().all(| c | c.is_ascii_alphanumeric() || c == '_' || c == '-');
+    let is_taken: bool = grid_types.iter().any(| (existing_type, existing_suffix) | existing_type == grid_type || existing_suffix == suffix);
+
+    if !is_valid_suffix || is_taken {
+      logger::log_tagged_to_core_file(app_handle, "main", format!("Ignoring custom grid type {} with suffix \"{}\".", grid_type, suffix).as_str(), 1);
+      continue;
+    }
+
+    grid_types.push((grid_type.to_owned(), suffix.to_owned()));
+  }
+
+  return grid_types;
+}
+
+/// Checks if a grid type is in the grid type table.
+fn is_known_grid_type(grid_types: &GridTypeTable, grid_type: &str) -> bool {
+  return grid_types.iter().any(| (known_type, _) | known_type == grid_type);
+}
+
+/// Gets a grid's file name based on its type. Returns an error if the type isn't in the grid type table.
+fn get_grid_filename(grid_types: &GridTypeTable, appid: &str, grid_type: &str, image_type: &str) -> Result<String, String> {
+  let (_, suffix) = grid_types.iter().find(| (known_type, _) | known_type == grid_type).ok_or(format!("Unexpected grid type {}", grid_type))?;
+  return Ok(format!("{}{}{}", appid, suffix, image_type));
+}
+
+/// Gets a grid's appid and type from its file name. This is the inverse of get_grid_filename.
+fn parse_grid_filename(grid_types: &GridTypeTable, filename: &str) -> Option<(String, String)> {
+  let format_start_index = filename.rfind(".")?;
+  let name: &str = &filename[..format_start_index];
+  let image_type: String = filename[format_start_index..].to_lowercase();
+
+  if !GRID_IMAGE_EXTENSIONS.contains(&image_type.as_str()) {
+    return None;
+  }
+
+  // ? Longer suffixes need to be checked first, otherwise "_hero" etc would never be reached, and Wide Capsule's empty suffix always comes last.
+  let mut suffixes: Vec<&(String, String)> = grid_types.iter().collect();
+  suffixes.sort_by(| (_, suffix_a), (_, suffix_b) | suffix_b.len().cmp(&suffix_a.len()));
+
+  for (grid_type, suffix) in suffixes.into_iter() {
+    if let Some(appid) = name.strip_suffix(suffix.as_str()) {
+      if !appid.is_empty() && appid.chars().all(| c | c.is_ascii_digit()) {
+        return Some((appid.to_owned(), grid_type.to_owned()));
+      }
     }
   }
+
+  return None;
 }
 
 /// Adjusts the path of a grid based on its type.
-fn adjust_path(app_handle: &AppHandle, appid: &str, path: &str, grid_type: &str) -> String {
+fn adjust_path(grid_types: &GridTypeTable, appid: &str, path: &str, grid_type: &str) -> Result<String, String> {
   let format_start_index = path.rfind(".").expect("Path should have had a file extension.");
   let image_type = &path[format_start_index..];
-  return get_grid_filename(app_handle, appid, grid_type, image_type);
+  return get_grid_filename(grid_types, appid, grid_type, image_type);
 }
 
 /// Filters the grid paths based on which have change.
-fn filter_paths(app_handle: &AppHandle, steam_active_user_id: String, current_paths: &GridImageCache, original_paths: &GridImageCache) -> Vec<ChangedPath> {
-  let grids_dir = PathBuf::from(steam::get_grids_directory(app_handle.to_owned(), steam_active_user_id));
+fn filter_paths(app_handle: &AppHandle, grids_dir: &PathBuf, current_paths: &GridImageCache, original_paths: &GridImageCache) -> Vec<ChangedPath> {
   let mut res:Vec<ChangedPath> = Vec::new();
+  let grid_types: GridTypeTable = get_grid_type_table(app_handle);
+  let keep_webp: bool = grid_images::converts_grids_to_webp(app_handle);
 
   for (appid, grids_map) in current_paths.into_iter() {
     for (grid_type, source_path) in grids_map.into_iter() {
-      let mut grid_path: &String = &String::from("");
-
-      if original_paths.get(appid.as_str()).is_some() && original_paths.get(appid.as_str()).unwrap().get(grid_type.as_str()).is_some() {
-        grid_path = original_paths.get(appid.as_str()).unwrap().get(grid_type.as_str()).unwrap();
-      }
-
-      let grid_path_owned = grid_path.to_owned();
+      let grid_path_owned = get_cached_grid_path(original_paths, appid, grid_type).cloned().unwrap_or_default();
       let source_path_owned = source_path.to_owned();
 
-      if source_path_owned != grid_path_owned {
+      if !steam::paths_match(&source_path_owned, &grid_path_owned) {
         let target_path;
 
+        // ? Grids are always written to the top level since that's where Steam reads them, even if the old one was in an appid subfolder.
         if source_path != "REMOVE" {
-          let adjusted_path = adjust_path(app_handle, appid.as_str(), source_path_owned.as_str(), grid_type.as_str()).replace("\\", "/");
-          target_path = String::from(grids_dir.join(adjusted_path).to_str().unwrap()).replace("\\", "/");
+          let adjusted_path = match adjust_path(&grid_types, appid.as_str(), source_path_owned.as_str(), grid_type.as_str()) {
+            Ok(adjusted_path) => adjusted_path,
+            Err(err) => {
+              logger::log_tagged_to_core_file(app_handle, "main", format!("Skipping {} for {}: {}", grid_type, appid, err).as_str(), 2);
+              continue;
+            }
+          };
+          target_path = steam::path_to_string(&grids_dir.join(adjusted_path));
         } else {
           target_path = String::from("REMOVE");
         }
@@ -108,12 +377,13 @@ fn filter_paths(app_handle: &AppHandle, steam_active_user_id: String, current_pa
         let mut changed_path = ChangedPath {
           appId: appid.to_owned(),
           gridType: grid_type.to_owned(),
-          oldPath: grid_path_owned.replace("\\", "/"),
+          oldPath: steam::normalize_path(&grid_path_owned),
           targetPath: target_path.to_owned(),
-          sourcePath: source_path_owned.replace("\\", "/")
+          sourcePath: steam::normalize_path(&source_path_owned)
         };
 
-        if changed_path.targetPath.ends_with(".webp") {
+        // ? WebPs are saved as jpgs, unless the user has grids converted to webp when optimizing, since those would just be converted back.
+        if changed_path.targetPath.ends_with(".webp") && !keep_webp {
           let target: String = changed_path.targetPath;
           let mut jpg_target: String = target[..target.len() - 5].to_owned();
           jpg_target.push_str(".jpg");
@@ -129,6 +399,78 @@ fn filter_paths(app_handle: &AppHandle, steam_active_user_id: String, current_pa
   return res;
 }
 
+/// Gets the path of an app's grid of the provided type, if it has one.
+fn get_cached_grid_path<'a>(grid_cache: &'a GridImageCache, appid: &str, grid_type: &str) -> Option<&'a String> {
+  return grid_cache.get(appid).and_then(| app_grids | app_grids.get(grid_type));
+}
+
+/// Compares two art profiles, listing the grids only in the second, only in the first, and the ones whose paths differ.
+fn diff_grid_caches(profile_a: &GridImageCache, profile_b: &GridImageCache) -> Vec<ArtProfileDiff> {
+  let mut diffs: Vec<ArtProfileDiff> = Vec::new();
+  let mut keys: Vec<(&String, &String)> = profile_a.iter().chain(profile_b.iter())
+    .flat_map(| (appid, app_grids) | app_grids.keys().map(move | grid_type | (appid, grid_type)))
+    .collect();
+  keys.sort();
+  keys.dedup();
+
+  for (appid, grid_type) in keys.into_iter() {
+    // ? REMOVE marks a grid that's been cleared, so it's treated the same as not having one.
+    let path_a: Option<&String> = get_cached_grid_path(profile_a, appid, grid_type).filter(| path | path.as_str() != "REMOVE");
+    let path_b: Option<&String> = get_cached_grid_path(profile_b, appid, grid_type).filter(| path | path.as_str() != "REMOVE");
+
+    let change: &str = match (path_a, path_b) {
+      (None, Some(_)) => "added",
+      (Some(_), None) => "removed",
+      (Some(a), Some(b)) if !steam::paths_match(a, b) => "changed",
+      _ => continue
+    };
+
+    diffs.push(ArtProfileDiff {
+      appId: appid.to_owned(),
+      gridType: grid_type.to_owned(),
+      change: change.to_owned(),
+      from: path_a.cloned(),
+      to: path_b.cloned()
+    });
+  }
+
+  return diffs;
+}
+
+#[tauri::command]
+/// Compares two serialized art profiles without touching the disk. Returns an error if either profile isn't a valid grid cache.
+fn diff_art_profiles(profile_a: String, profile_b: String) -> Result<String, String> {
+  let profile_a_dict: GridImageCache = serde_json::from_str(profile_a.as_str()).map_err(| err | format!("Failed to parse the first art profile: {}", err))?;
+  let profile_b_dict: GridImageCache = serde_json::from_str(profile_b.as_str()).map_err(| err | format!("Failed to parse the second art profile: {}", err))?;
+
+  return Ok(serde_json::to_string(&diff_grid_caches(&profile_a_dict, &profile_b_dict)).expect("Should have been able to serialize art profile diff."));
+}
+
+/// Gets the grid paths that differ between the current and original art.
+fn get_changed_paths(app_handle: &AppHandle, steam_active_user_id: String, current_art: &str, original_art: &str) -> Vec<ChangedPath> {
+  let current_art_dict: GridImageCache = serde_json::from_str(current_art).unwrap();
+  let original_art_dict: GridImageCache = serde_json::from_str(original_art).unwrap();
+
+  let grids_dir = PathBuf::from(steam::get_grids_directory(app_handle.to_owned(), steam_active_user_id, None));
+
+  return filter_paths(app_handle, &grids_dir, &current_art_dict, &original_art_dict);
+}
+
+/// Gets a shortcut's appid the way its grids are named. Steam writes appids as unsigned 32 bit numbers, but they can end up stored as negative signed values or strings.
+fn normalize_shortcut_appid(appid: &Value) -> Option<String> {
+  let appid_num: i64 = match appid {
+    Value::Number(num) => num.as_i64()?,
+    Value::String(appid_str) => appid_str.trim().parse::<i64>().ok()?,
+    _ => return None
+  };
+
+  if appid_num < 0 {
+    return i32::try_from(appid_num).ok().map(| signed_appid | (signed_appid as u32).to_string());
+  }
+
+  return u32::try_from(appid_num).ok().map(| unsigned_appid | unsigned_appid.to_string());
+}
+
 /// Checks for shortcut grid changes.
 fn check_for_shortcut_changes(shortcut_icons: &Map<String, Value>, original_shortcut_icons: &Map<String, Value>) -> bool {
   for (shortcut_id, icon) in shortcut_icons.to_owned().into_iter() {
@@ -140,393 +482,2811 @@ fn check_for_shortcut_changes(shortcut_icons: &Map<String, Value>, original_shor
     }
   }
 
-  return false;
+  return false;
+}
+
+#[tauri::command]
+/// Lists the files in the user's grids dir, including ones in appid subfolders. Top-level files come first since they're the ones Steam uses.
+fn list_grid_files(app_handle: AppHandle, steam_active_user_id: String) -> Vec<GridFile> {
+  let grids_dir_path: String = steam::get_grids_directory(app_handle.to_owned(), steam_active_user_id, None);
+
+  return steam::list_grid_files(&PathBuf::from(grids_dir_path)).into_iter().map(| grid_path | GridFile {
+    name: grid_path.file_name().map(| name | name.to_string_lossy().into_owned()).unwrap_or_default(),
+    path: steam::path_to_string(&grid_path)
+  }).collect();
+}
+
+#[tauri::command]
+/// Gets the existing grids for an app, mapped from grid type to path.
+fn get_existing_art(app_handle: AppHandle, steam_active_user_id: String, appid: String) -> Map<String, Value> {
+  let grids_dir_path: String = steam::get_grids_directory(app_handle.to_owned(), steam_active_user_id, None);
+  let grid_types: GridTypeTable = get_grid_type_table(&app_handle);
+  let mut existing_art: Map<String, Value> = Map::new();
+
+  for grid_path in steam::list_grid_files(&PathBuf::from(grids_dir_path)).into_iter() {
+    let filename_str: &str = grid_path.file_name().and_then(| name | name.to_str()).unwrap_or("");
+
+    if let Some((id, grid_type)) = parse_grid_filename(&grid_types, filename_str) {
+      if id == appid && !existing_art.contains_key(&grid_type) {
+        let grid_path_str: String = steam::path_to_string(&grid_path);
+        existing_art.insert(grid_type, Value::String(grid_path_str));
+      }
+    }
+  }
+
+  logger::log_tagged_to_core_file(&app_handle, "main", format!("Found {} existing grids for {}.", existing_art.len(), appid).as_str(), 0);
+
+  return existing_art;
+}
+
+/// Gets the user's current grids, mapped from appid to grid type to path. Top-level grids take priority over ones in appid subfolders.
+fn get_current_grids(app_handle: &AppHandle, grids_dir: &PathBuf) -> GridImageCache {
+  let grid_types: GridTypeTable = get_grid_type_table(app_handle);
+  let mut current_grids: GridImageCache = HashMap::new();
+
+  for grid_path in steam::list_grid_files(grids_dir).into_iter() {
+    let filename_str: &str = grid_path.file_name().and_then(| name | name.to_str()).unwrap_or("");
+
+    if let Some((appid, grid_type)) = parse_grid_filename(&grid_types, filename_str) {
+      let app_grids: &mut HashMap<String, String> = current_grids.entry(appid).or_default();
+
+      if !app_grids.contains_key(&grid_type) {
+        app_grids.insert(grid_type, steam::path_to_string(&grid_path));
+      }
+    }
+  }
+
+  return current_grids;
+}
+
+#[tauri::command]
+/// Gets the appids that have at least one custom grid, sorted numerically.
+async fn get_customized_appids(app_handle: AppHandle, steam_active_user_id: String) -> Vec<String> {
+  let grids_dir: PathBuf = PathBuf::from(steam::get_grids_directory(app_handle.to_owned(), steam_active_user_id, None));
+  let mut appids: Vec<String> = get_current_grids(&app_handle, &grids_dir).into_keys().collect();
+  appids.sort_by_key(| appid | appid.parse::<u64>().unwrap_or(u64::MAX));
+
+  logger::log_tagged_to_core_file(&app_handle, "main", format!("Found {} apps with custom grids.", appids.len()).as_str(), 0);
+
+  return appids;
+}
+
+#[tauri::command]
+/// Counts the custom grids each app has.
+async fn get_custom_grid_counts(app_handle: AppHandle, steam_active_user_id: String) -> HashMap<String, usize> {
+  let grids_dir: PathBuf = PathBuf::from(steam::get_grids_directory(app_handle.to_owned(), steam_active_user_id, None));
+
+  return get_current_grids(&app_handle, &grids_dir).into_iter()
+    .map(| (appid, app_grids) | (appid, app_grids.len()))
+    .collect();
+}
+
+#[tauri::command]
+/// Reports which of the five standard grid types each of the provided apps has, along with how many apps have all, some, or none of them.
+async fn get_art_coverage(app_handle: AppHandle, steam_active_user_id: String, appids: Vec<String>) -> String {
+  let grids_dir: PathBuf = PathBuf::from(steam::get_grids_directory(app_handle.to_owned(), steam_active_user_id, None));
+  let current_grids: GridImageCache = get_current_grids(&app_handle, &grids_dir);
+
+  let mut coverage: ArtCoverage = ArtCoverage { apps: Vec::new(), fullyThemed: 0, partiallyThemed: 0, untouched: 0 };
+
+  for appid in appids.into_iter() {
+    let app_grids: Option<&HashMap<String, String>> = current_grids.get(&appid);
+    let (filled, missing): (Vec<&str>, Vec<&str>) = DEFAULT_GRID_TYPE_SUFFIXES.iter()
+      .map(| (grid_type, _) | *grid_type)
+      .partition(| grid_type | app_grids.is_some_and(| grids | grids.contains_key(*grid_type)));
+
+    if missing.is_empty() {
+      coverage.fullyThemed += 1;
+    } else if filled.is_empty() {
+      coverage.untouched += 1;
+    } else {
+      coverage.partiallyThemed += 1;
+    }
+
+    coverage.apps.push(AppArtCoverage {
+      appId: appid,
+      filled: filled.into_iter().map(| grid_type | grid_type.to_owned()).collect(),
+      missing: missing.into_iter().map(| grid_type | grid_type.to_owned()).collect()
+    });
+  }
+
+  logger::log_tagged_to_core_file(&app_handle, "main", format!("Art coverage: {} fully themed, {} partially themed, {} untouched.", coverage.fullyThemed, coverage.partiallyThemed, coverage.untouched).as_str(), 0);
+
+  return serde_json::to_string(&coverage).expect("Should have been able to serialize art coverage.");
+}
+
+#[tauri::command]
+/// Lists every image in the user's grids dir with its size, dimensions, format, and the app and grid type it's for. Unreadable files are skipped.
+async fn list_grids_detailed(app_handle: AppHandle, steam_active_user_id: String) -> String {
+  let grids_dir: PathBuf = PathBuf::from(steam::get_grids_directory(app_handle.to_owned(), steam_active_user_id, None));
+  let grid_types: GridTypeTable = get_grid_type_table(&app_handle);
+  let mut grid_files: Vec<GridFileInfo> = Vec::new();
+
+  for grid_path in steam::list_grid_files(&grids_dir).into_iter() {
+    let filename: &str = grid_path.file_name().and_then(| name | name.to_str()).unwrap_or("");
+    let extension: String = filename.rfind(".").map(| index | filename[index..].to_lowercase()).unwrap_or_default();
+
+    if !GRID_IMAGE_EXTENSIONS.contains(&extension.as_str()) {
+      continue;
+    }
+
+    let metadata = match fs::metadata(&grid_path) {
+      Ok(metadata) => metadata,
+      Err(err) => {
+        logger::log_tagged_to_core_file(&app_handle, "main", format!("Skipping {}, couldn't read its metadata: {}", grid_path.display(), err).as_str(), 1);
+        continue;
+      }
+    };
+
+    let (width, height, format) = match grid_images::read_image_header(&grid_path) {
+      Ok(header) => header,
+      Err(err) => {
+        logger::log_tagged_to_core_file(&app_handle, "main", format!("Skipping {}, couldn't read it as an image: {}", grid_path.display(), err).as_str(), 1);
+        continue;
+      }
+    };
+
+    let mtime: u64 = metadata.modified().ok()
+      .and_then(| modified | modified.duration_since(std::time::UNIX_EPOCH).ok())
+      .map(| since_epoch | since_epoch.as_secs())
+      .unwrap_or(0);
+    let (app_id, grid_type) = match parse_grid_filename(&grid_types, filename) {
+      Some((app_id, grid_type)) => (Some(app_id), Some(grid_type)),
+      None => (None, None)
+    };
+
+    grid_files.push(GridFileInfo {
+      path: steam::path_to_string(&grid_path),
+      appId: app_id,
+      gridType: grid_type,
+      width,
+      height,
+      format,
+      bytes: metadata.len(),
+      mtime
+    });
+  }
+
+  return serde_json::to_string(&grid_files).expect("Should have been able to serialize grid files.");
+}
+
+/// Lists the image files directly in a dir that isn't a grids dir. Returns an empty list if it can't be read.
+fn list_library_images(app_handle: &AppHandle, library_dir: &PathBuf) -> Vec<PathBuf> {
+  let entries = match fs::read_dir(library_dir) {
+    Ok(entries) => entries,
+    Err(err) => {
+      logger::log_tagged_to_core_file(app_handle, "main", format!("Failed to read local art folder {}: {}", library_dir.display(), err).as_str(), 1);
+      return Vec::new();
+    }
+  };
+
+  return entries.filter_map(| entry | entry.ok().map(| entry | entry.path())).filter(| path | path.is_file()).collect();
+}
+
+#[tauri::command]
+/// Lists the images that can be reused as art without downloading anything, from the user's grids dir and the localArtLibraryFolder setting if it's set.
+/// Each image has its dimensions and the grid type its aspect ratio suggests, along with the app and grid type it's for if its name says so.
+async fn list_local_art_library(app_handle: AppHandle, steam_active_user_id: String) -> String {
+  let grids_dir: PathBuf = PathBuf::from(steam::get_grids_directory(app_handle.to_owned(), steam_active_user_id, None));
+  let grid_types: GridTypeTable = get_grid_type_table(&app_handle);
+
+  let mut sources: Vec<(&str, PathBuf)> = steam::list_grid_files(&grids_dir).into_iter().map(| path | ("grids", path)).collect();
+
+  let library_folder: String = settings::get_setting(&app_handle, "localArtLibraryFolder").and_then(| value | value.as_str().map(| folder | folder.trim().to_owned())).unwrap_or_default();
+
+  if !library_folder.is_empty() {
+    let library_dir: PathBuf = PathBuf::from(&library_folder);
+
+    // ? Pointing the setting at the grids dir would list everything twice.
+    if !steam::paths_match(&steam::path_to_string(&library_dir), &steam::path_to_string(&grids_dir)) {
+      sources.extend(list_library_images(&app_handle, &library_dir).into_iter().map(| path | ("library", path)));
+    }
+  }
+
+  let mut art_files: Vec<LocalArtInfo> = Vec::new();
+
+  for (source, art_path) in sources.into_iter() {
+    let filename: &str = art_path.file_name().and_then(| name | name.to_str()).unwrap_or("");
+    let extension: String = filename.rfind(".").map(| index | filename[index..].to_lowercase()).unwrap_or_default();
+
+    if !GRID_IMAGE_EXTENSIONS.contains(&extension.as_str()) {
+      continue;
+    }
+
+    let (width, height, format) = match grid_images::read_image_header(&art_path) {
+      Ok(header) => header,
+      Err(err) => {
+        logger::log_tagged_to_core_file(&app_handle, "main", format!("Skipping {}, couldn't read it as an image: {}", art_path.display(), err).as_str(), 1);
+        continue;
+      }
+    };
+
+    let (app_id, grid_type) = match parse_grid_filename(&grid_types, filename) {
+      Some((app_id, grid_type)) => (Some(app_id), Some(grid_type)),
+      None => (None, None)
+    };
+    let path: String = steam::path_to_string(&art_path);
+
+    art_files.push(LocalArtInfo {
+      guessedGridType: grid_images::guess_grid_type(app_handle.to_owned(), path.to_owned()),
+      path,
+      source: source.to_owned(),
+      appId: app_id,
+      gridType: grid_type,
+      width,
+      height,
+      format
+    });
+  }
+
+  logger::log_tagged_to_core_file(&app_handle, "main", format!("Found {} images in the local art library.", art_files.len()).as_str(), 0);
+  return serde_json::to_string(&art_files).expect("Should have been able to serialize local art library.");
+}
+
+#[tauri::command]
+/// Finds grids in the user's grids dir that are the same image, even if they were saved in different formats. Returns each group of duplicates.
+async fn find_duplicate_grids(app_handle: AppHandle, steam_active_user_id: String) -> Vec<Vec<String>> {
+  let grids_dir: PathBuf = PathBuf::from(steam::get_grids_directory(app_handle.to_owned(), steam_active_user_id, None));
+  let mut grids_by_hash: HashMap<u64, Vec<String>> = HashMap::new();
+
+  for grid_path in steam::list_grid_files(&grids_dir).into_iter() {
+    let filename: &str = grid_path.file_name().and_then(| name | name.to_str()).unwrap_or("");
+    let extension: String = filename.rfind(".").map(| index | filename[index..].to_lowercase()).unwrap_or_default();
+
+    if !GRID_IMAGE_EXTENSIONS.contains(&extension.as_str()) {
+      continue;
+    }
+
+    match grid_images::hash_image_file(&grid_path) {
+      Some(hash) => grids_by_hash.entry(hash).or_default().push(steam::path_to_string(&grid_path)),
+      None => logger::log_tagged_to_core_file(&app_handle, "main", format!("Skipping {}, couldn't read it.", grid_path.display()).as_str(), 1)
+    }
+  }
+
+  let mut duplicates: Vec<Vec<String>> = grids_by_hash.into_values().filter(| group | group.len() > 1).collect();
+
+  for group in duplicates.iter_mut() {
+    group.sort();
+  }
+
+  duplicates.sort();
+
+  logger::log_tagged_to_core_file(&app_handle, "main", format!("Found {} groups of duplicate grids.", duplicates.len()).as_str(), 0);
+  return duplicates;
+}
+
+#[tauri::command]
+/// Exports a JSON inventory of the user's grids, listing each app's name and the path of each grid type.
+async fn export_art_manifest(app_handle: AppHandle, steam_active_user_id: String) -> String {
+  let grids_dir: PathBuf = PathBuf::from(steam::get_grids_directory(app_handle.to_owned(), steam_active_user_id, None));
+  let current_grids: GridImageCache = get_current_grids(&app_handle, &grids_dir);
+  let grid_types: GridTypeTable = get_grid_type_table(&app_handle);
+  let app_names: HashMap<u32, String> = app_cache::get_app_name_map(&app_handle);
+  let mut apps: Map<String, Value> = Map::new();
+
+  for (appid, app_grids) in current_grids.into_iter() {
+    let name: Value = appid.parse::<u32>().ok().and_then(| id | app_names.get(&id)).map(| name | Value::String(name.to_owned())).unwrap_or(Value::Null);
+    let mut grids: Map<String, Value> = Map::new();
+
+    for (grid_type, _) in grid_types.iter() {
+      let grid_path: Value = app_grids.get(grid_type).map(| path | Value::String(path.to_owned())).unwrap_or(Value::Null);
+      grids.insert(grid_type.to_owned(), grid_path);
+    }
+
+    let mut app_entry: Map<String, Value> = Map::new();
+    app_entry.insert(String::from("name"), name);
+    app_entry.insert(String::from("grids"), Value::Object(grids));
+    apps.insert(appid, Value::Object(app_entry));
+  }
+
+  logger::log_tagged_to_core_file(&app_handle, "main", format!("Exported art manifest for {} apps.", apps.len()).as_str(), 0);
+
+  let mut manifest: Map<String, Value> = Map::new();
+  manifest.insert(String::from("version"), Value::from(1));
+  manifest.insert(String::from("apps"), Value::Object(apps));
+
+  return serde_json::to_string_pretty(&manifest).expect("Should have been able to serialize art manifest.");
+}
+
+#[tauri::command]
+/// Applies an art manifest from export_art_manifest, copying in every grid whose source file exists.
+async fn import_art_manifest(app_handle: AppHandle, steam_active_user_id: String, manifest: String) -> ManifestImportResult {
+  let manifest_value: Value = match serde_json::from_str(&manifest) {
+    Ok(value) => value,
+    Err(err) => {
+      logger::log_tagged_to_core_file(&app_handle, "main", format!("Failed to parse art manifest: {}", err).as_str(), 2);
+      return ManifestImportResult { applied: 0, missing: Vec::new(), error: Some(err.to_string()) };
+    }
+  };
+
+  let grids_dir: PathBuf = PathBuf::from(steam::get_grids_directory(app_handle.to_owned(), steam_active_user_id, None));
+  let grid_types: GridTypeTable = get_grid_type_table(&app_handle);
+  let mut manifest_grids: GridImageCache = HashMap::new();
+  let mut missing: Vec<String> = Vec::new();
+
+  if let Some(apps) = manifest_value.get("apps").and_then(| apps | apps.as_object()) {
+    for (appid, app_entry) in apps.iter() {
+      let grids = match app_entry.get("grids").and_then(| grids | grids.as_object()) {
+        Some(grids) => grids,
+        None => continue
+      };
+
+      for (grid_type, grid_path) in grids.iter() {
+        let grid_path: &str = match grid_path.as_str() {
+          Some(path) if is_known_grid_type(&grid_types, grid_type) => path,
+          _ => continue
+        };
+
+        if PathBuf::from(grid_path).is_file() {
+          manifest_grids.entry(appid.to_owned()).or_default().insert(grid_type.to_owned(), steam::normalize_path(grid_path));
+        } else {
+          missing.push(grid_path.to_owned());
+        }
+      }
+    }
+  }
+
+  // ? Reusing the save logic means grids that are already in place are skipped, and replaced grids have their old file removed.
+  let current_grids: GridImageCache = get_current_grids(&app_handle, &grids_dir);
+  let mut changed_paths: Vec<ChangedPath> = filter_paths(&app_handle, &grids_dir, &manifest_grids, &current_grids);
+  for changed_path in changed_paths.iter_mut() {
+    rebase_changed_path(&grid_types, &grids_dir, changed_path);
+  }
+
+  if !changed_paths.is_empty() {
+    backups::create_backup(&app_handle, &grids_dir, &get_changed_grid_files(&changed_paths));
+  }
+
+  let summary: SaveSummary = apply_changed_paths(&app_handle, &changed_paths);
+  let applied: usize = summary.applied + summary.removed;
+  logger::log_tagged_to_core_file(&app_handle, "main", format!("Applied {} grids from art manifest. {} source files were missing.", applied, missing.len()).as_str(), 0);
+
+  return ManifestImportResult { applied, missing, error: summary.errors.first().map(| save_error | save_error.error.to_owned()) };
+}
+
+/// Makes a file dialog that starts in the user's home dir, or wherever the OS defaults to if it can't be found.
+fn new_file_dialog(app_handle: &AppHandle) -> FileDialogBuilder {
+  let file_dialog = FileDialogBuilder::new();
+
+  return match home_dir() {
+    Some(pc_home_dir) => file_dialog.set_directory(pc_home_dir),
+    None => {
+      logger::log_tagged_to_core_file(app_handle, "main", "Couldn't find the user's home dir. Opening the dialog in the default location.", 1);
+      file_dialog
+    }
+  };
+}
+
+#[tauri::command]
+/// Lets the user pick their grids directory, for when it isn't where steam normally puts it. The folder is saved as an override for the provided user.
+async fn pick_grids_directory(app_handle: AppHandle, steam_active_user_id: String) -> Option<String> {
+  let steam_active_user_id: String = steam::resolve_active_user_id(&app_handle, &steam_active_user_id);
+
+  let folder_dialog = new_file_dialog(&app_handle)
+    .set_title("Pick your Grids Folder");
+
+  let folder_path: PathBuf = match folder_dialog.pick_folder() {
+    Some(folder_path) => folder_path,
+    None => {
+      logger::log_tagged_to_core_file(&app_handle, "main", "No grids folder was selected by user.", 0);
+      return None;
+    }
+  };
+
+  let is_named_grid: bool = folder_path.file_name().and_then(| name | name.to_str()).map(| name | name.eq_ignore_ascii_case("grid")).unwrap_or(false);
+  let has_grid_images: bool = steam::list_grid_files(&folder_path).iter().any(| grid_path | {
+    let filename: String = grid_path.file_name().and_then(| name | name.to_str()).unwrap_or("").to_lowercase();
+    return GRID_IMAGE_EXTENSIONS.iter().any(| extension | filename.ends_with(extension));
+  });
+
+  if !is_named_grid && !has_grid_images {
+    logger::log_tagged_to_core_file(&app_handle, "main", format!("{} doesn't look like a grids folder.", folder_path.display()).as_str(), 1);
+    return None;
+  }
+
+  add_directory_to_scope(&app_handle, &folder_path, "grids override");
+
+  let folder_path_str: String = steam::path_to_string(&folder_path);
+  if !steam::set_grids_directory_override(&app_handle, &steam_active_user_id, &folder_path) {
+    logger::log_tagged_to_core_file(&app_handle, "main", "Failed to save grids folder override.", 2);
+  }
+
+  logger::log_tagged_to_core_file(&app_handle, "main", format!("Using {} as the grids folder for user {}.", folder_path_str, steam_active_user_id).as_str(), 0);
+  return Some(folder_path_str);
+}
+
+#[tauri::command]
+/// Exports the users grids to a Grids zip file.
+async fn export_grids_to_zip(app_handle: AppHandle, steam_active_user_id: String, platform_id_map: Map<String, Value>, id_name_map: Map<String, Value>) -> bool {
+  let file_dialog = new_file_dialog(&app_handle)
+    .set_title("Save Grids Zip")
+    .set_file_name("Steam_Grids_Export.zip")
+    .add_filter("zip", &["zip"]);
+
+  let file_path = file_dialog.save_file();
+
+  if file_path.is_some() {
+    let zip_path = file_path.unwrap();
+    logger::log_tagged_to_core_file(&app_handle, "main", format!("Got save path: {}", zip_path.to_str().expect("Should have been able to convert path to string.")).as_str(), 0);
+
+    let grids_dir_path = steam::get_grids_directory(app_handle.to_owned(), steam_active_user_id, None);
+    let succeeded = zip_controller::generate_grids_zip(&app_handle, PathBuf::from(grids_dir_path), zip_path, &platform_id_map, &id_name_map);
+
+    if succeeded {
+      logger::log_tagged_to_core_file(&app_handle, "main", "Successfully saved the user's grids.", 0);
+      return true;
+    } else {
+      logger::log_tagged_to_core_file(&app_handle, "main", "Failed to save the user's grids.", 0);
+      return false;
+    }
+  } else {
+    logger::log_tagged_to_core_file(&app_handle, "main", "No save location was chosen.", 0);
+    return false;
+  }
+}
+
+#[tauri::command]
+/// Copies the user's grids into a plain folder, named exactly as steam names them so it can be dropped into another grids dir. Logo position configs are copied too.
+/// Grids in appid subfolders are flattened to the top level, where steam reads them. If appids is provided, only those apps' grids are copied.
+/// Returns the paths of the copied files.
+async fn export_grids_to_folder(app_handle: AppHandle, steam_active_user_id: String, dest_dir: String, appids: Option<Vec<String>>) -> Vec<String> {
+  let dest_dir_path: PathBuf = PathBuf::from(&dest_dir);
+
+  if !steam::ensure_directory_exists(&app_handle, &dest_dir_path) {
+    logger::log_tagged_to_core_file(&app_handle, "main", format!("Export directory {} couldn't be created.", dest_dir).as_str(), 2);
+    return Vec::new();
+  }
+
+  let grids_dir: PathBuf = PathBuf::from(steam::get_grids_directory(app_handle.to_owned(), steam_active_user_id, None));
+  let grid_types: GridTypeTable = get_grid_type_table(&app_handle);
+  let appid_filter: Option<&[String]> = appids.as_deref();
+  let mut copied_names: Vec<String> = Vec::new();
+  let mut copied_paths: Vec<String> = Vec::new();
+
+  for grid_path in steam::list_grid_files(&grids_dir).into_iter() {
+    let filename: &str = grid_path.file_name().and_then(| name | name.to_str()).unwrap_or("");
+    let extension: String = filename.rfind(".").map(| index | filename[index..].to_lowercase()).unwrap_or_default();
+
+    let (appid, export_name) = match parse_grid_filename(&grid_types, filename) {
+      Some((appid, grid_type)) => match get_grid_filename(&grid_types, &appid, &grid_type, &extension) {
+        Ok(export_name) => (appid, export_name),
+        Err(_) => continue
+      },
+      None if extension == ".json" => {
+        let appid: &str = &filename[..filename.len() - extension.len()];
+
+        if appid.is_empty() || !appid.chars().all(| c | c.is_ascii_digit()) {
+          continue;
+        }
+
+        (appid.to_owned(), format!("{}.json", appid))
+      },
+      None => continue
+    };
+
+    if appid_filter.is_some_and(| filter | !filter.contains(&appid)) {
+      continue;
+    }
+
+    // ? Grids in appid subfolders can share a name with top-level ones, which were listed first and take priority.
+    if copied_names.contains(&export_name) {
+      logger::log_tagged_to_core_file(&app_handle, "main", format!("Already exported a grid named {}, skipping {}...", export_name, grid_path.display()).as_str(), 1);
+      continue;
+    }
+
+    let dest_path: PathBuf = dest_dir_path.join(&export_name);
+
+    match fs::copy(&grid_path, &dest_path) {
+      Ok(_) => {
+        copied_paths.push(steam::path_to_string(&dest_path));
+        copied_names.push(export_name);
+      },
+      Err(err) => logger::log_tagged_to_core_file(&app_handle, "main", format!("Failed to copy {} to {}: {}", grid_path.display(), dest_path.display(), err).as_str(), 2)
+    }
+  }
+
+  logger::log_tagged_to_core_file(&app_handle, "main", format!("Exported {} grids to {}.", copied_paths.len(), dest_dir).as_str(), 0);
+  return copied_paths;
+}
+
+#[tauri::command]
+/// Exports a single app's grids to a zip file in the provided directory, named after the app when possible.
+async fn export_app_art(app_handle: AppHandle, steam_active_user_id: String, appid: String, app_name: Option<String>, dest_path: String) -> bool {
+  let dest_dir: PathBuf = PathBuf::from(&dest_path);
+
+  if !dest_dir.is_dir() {
+    logger::log_tagged_to_core_file(&app_handle, "main", format!("Export directory {} does not exist.", dest_path).as_str(), 2);
+    return false;
+  }
+
+  let mut zip_name: String = appid.to_owned();
+
+  if app_name.is_some() {
+    let sanitized_name: String = app_name.unwrap().chars().filter(| c | c.is_alphanumeric() || *c == ' ' || *c == '-' || *c == '_').collect();
+
+    if !sanitized_name.trim().is_empty() {
+      zip_name = sanitized_name.trim().replace(" ", "_");
+    }
+  }
+
+  let zip_path: PathBuf = dest_dir.join(format!("{}_Grids.zip", zip_name));
+  logger::log_tagged_to_core_file(&app_handle, "main", format!("Exporting grids for {} to {}", appid, zip_path.to_str().expect("Should have been able to convert path to string.")).as_str(), 0);
+
+  let grids_dir_path = steam::get_grids_directory(app_handle.to_owned(), steam_active_user_id, None);
+  return match zip_controller::generate_app_grids_zip(&app_handle, PathBuf::from(grids_dir_path), zip_path, appid.as_str()) {
+    Ok(()) => {
+      logger::log_tagged_to_core_file(&app_handle, "main", format!("Successfully exported the grids for {}.", appid).as_str(), 0);
+      true
+    },
+    Err(err) => {
+      logger::log_tagged_to_core_file(&app_handle, "main", format!("Failed to export the grids for {}: {}", appid, err).as_str(), 2);
+      false
+    }
+  };
+}
+
+#[tauri::command]
+/// Sets the users grids from a Grids zip file. The user picks the zip unless zip_path is provided, like when they've already reviewed an art pack's info.
+/// Grids for fuzzy matched shortcuts are only written once the user has confirmed them, by running the import again with them in confirmed_matches.
+async fn import_grids_from_zip(app_handle: AppHandle, steam_active_user_id: String, name_id_map: Map<String, Value>, confirmed_matches: Option<Map<String, Value>>, fuzzy_threshold: Option<f64>, zip_path: Option<String>) -> (bool, Map<String, Value>, zip_controller::ImportMatches, zip_controller::ZipExtractionReport) {
+  let file_path: Option<PathBuf> = match zip_path {
+    Some(zip_path) => Some(PathBuf::from(zip_path)),
+    None => new_file_dialog(&app_handle)
+      .set_title("Pick a Grids Zip")
+      .add_filter("zip", &["zip"])
+      .pick_file()
+  };
+
+  if file_path.is_some() {
+    let zip_path = file_path.unwrap();
+    logger::log_tagged_to_core_file(&app_handle, "main", format!("Got file path: {}", zip_path.to_str().expect("Should have been able to convert path to string.")).as_str(), 0);
+
+    let grids_dir_path = steam::get_grids_directory(app_handle.to_owned(), steam_active_user_id, None);
+    let (success, icon_map, import_matches, report) = zip_controller::set_grids_from_zip(&app_handle, PathBuf::from(grids_dir_path), zip_path, &name_id_map, &confirmed_matches.unwrap_or_default(), fuzzy_threshold.unwrap_or(zip_controller::DEFAULT_FUZZY_THRESHOLD));
+
+    if success {
+      logger::log_tagged_to_core_file(&app_handle, "main", "Successfully set the user's grids.", 0);
+      return (success, icon_map, import_matches, report);
+    } else {
+      logger::log_tagged_to_core_file(&app_handle, "main", "Failed to set the user's grids.", 0);
+      return (success, icon_map, import_matches, report);
+    }
+  } else {
+    logger::log_tagged_to_core_file(&app_handle, "main", "No zip file was selected by user.", 0);
+    return (false, Map::new(), zip_controller::ImportMatches::default(), zip_controller::ZipExtractionReport::default());
+  }
+}
+
+#[tauri::command]
+/// Previews importing a Grids zip without extracting it, listing the app, grid type, and action for each entry along with how shortcuts were matched.
+/// Uses the same matching as import_grids_from_zip, so the frontend can confirm the import before running it.
+async fn preview_import(app_handle: AppHandle, steam_active_user_id: String, zip_path: String, name_id_map: Map<String, Value>, confirmed_matches: Option<Map<String, Value>>, fuzzy_threshold: Option<f64>) -> String {
+  let grids_dir_path: PathBuf = PathBuf::from(steam::get_grids_directory(app_handle.to_owned(), steam_active_user_id, None));
+
+  let preview: zip_controller::ImportPreview = match zip_controller::preview_grids_from_zip(&app_handle, &grids_dir_path, &PathBuf::from(&zip_path), &name_id_map, &confirmed_matches.unwrap_or_default(), fuzzy_threshold.unwrap_or(zip_controller::DEFAULT_FUZZY_THRESHOLD)) {
+    Ok(preview) => preview,
+    Err(err) => {
+      logger::log_tagged_to_core_file(&app_handle, "main", format!("Failed to preview import of {}: {}", zip_path, err).as_str(), 2);
+      zip_controller::ImportPreview::default()
+    }
+  };
+
+  return serde_json::to_string(&preview).expect("Should have been able to serialize import preview.");
+}
+
+#[tauri::command]
+/// Exports the grids of the provided apps as an art pack for sharing, with a pack.json holding its name, author, description, version, and the games it covers.
+/// Game names are taken from the user's apps and shortcuts. Returns an error if the pack couldn't be written.
+async fn create_art_pack(app_handle: AppHandle, steam_active_user_id: String, appids: Vec<String>, metadata: zip_controller::ArtPackMetadata, dest_path: String) -> Result<usize, String> {
+  let pack_path: PathBuf = PathBuf::from(&dest_path);
+
+  if !pack_path.parent().is_some_and(| parent_dir | parent_dir.is_dir()) {
+    return Err(format!("The directory for {} doesn't exist.", dest_path));
+  }
+
+  let mut id_name_map: Map<String, Value> = app_cache::get_name_id_maps(&app_handle).idNameMap;
+
+  let shortcuts_vdf_path: PathBuf = PathBuf::from(steam::get_shortcuts_path(app_handle.to_owned(), steam_active_user_id.clone(), None));
+
+  if shortcuts_vdf_path.is_file() {
+    let shortcuts_data: Value = read_shortcuts_data(&shortcuts_vdf_path);
+
+    for shortcut in shortcuts_data.get("shortcuts").and_then(| shortcuts | shortcuts.as_object()).into_iter().flat_map(| shortcuts | shortcuts.values()) {
+      if let Some(appid) = shortcut.get("appid").and_then(normalize_shortcut_appid) {
+        id_name_map.insert(appid, Value::String(get_shortcut_app_name(shortcut).to_owned()));
+      }
+    }
+  }
+
+  let grids_dir: PathBuf = PathBuf::from(steam::get_grids_directory(app_handle.to_owned(), steam_active_user_id, None));
+  let pack_res: Result<usize, String> = zip_controller::generate_art_pack_zip(&app_handle, grids_dir, pack_path, &appids, &metadata, &id_name_map);
+
+  if let Err(err) = &pack_res {
+    logger::log_tagged_to_core_file(&app_handle, "main", format!("Failed to create art pack {}: {}", dest_path, err).as_str(), 2);
+  }
+
+  return pack_res;
+}
+
+#[tauri::command]
+/// Reads an art pack's info so it can be shown before the pack is imported. Returns None if the zip isn't an art pack.
+async fn read_art_pack_info(app_handle: AppHandle, pack_path: String) -> Option<zip_controller::ArtPackInfo> {
+  return zip_controller::read_art_pack_info(&app_handle, &PathBuf::from(pack_path));
+}
+
+#[tauri::command]
+/// Sets the users grids from a folder of grids. Like import_grids_from_zip, grids for fuzzy matched shortcuts are only written once they're in confirmed_matches.
+async fn import_grids_from_folder(app_handle: AppHandle, steam_active_user_id: String, folder_path: String, name_id_map: Map<String, Value>, confirmed_matches: Option<Map<String, Value>>, fuzzy_threshold: Option<f64>) -> (bool, Map<String, Value>, zip_controller::ImportMatches) {
+  let grids_folder: PathBuf = PathBuf::from(&folder_path);
+
+  if !grids_folder.is_dir() {
+    logger::log_tagged_to_core_file(&app_handle, "main", format!("Grids folder {} does not exist.", folder_path).as_str(), 2);
+    return (false, Map::new(), zip_controller::ImportMatches::default());
+  }
+
+  let grids_dir_path = steam::get_grids_directory(app_handle.to_owned(), steam_active_user_id, None);
+  let (success, icon_map, import_matches) = zip_controller::set_grids_from_folder(&app_handle, PathBuf::from(grids_dir_path), grids_folder, &name_id_map, &confirmed_matches.unwrap_or_default(), fuzzy_threshold.unwrap_or(zip_controller::DEFAULT_FUZZY_THRESHOLD));
+
+  if success {
+    logger::log_tagged_to_core_file(&app_handle, "main", "Successfully set the user's grids from folder.", 0);
+  } else {
+    logger::log_tagged_to_core_file(&app_handle, "main", "Failed to set the user's grids from folder.", 0);
+  }
+
+  return (success, icon_map, import_matches);
+}
+
+#[tauri::command]
+/// Reads the user's appinfo.vdf file.
+async fn read_appinfo_vdf(app_handle: AppHandle) -> String {
+  let appinfo_path: PathBuf = PathBuf::from(steam::get_appinfo_path(app_handle.to_owned(), None));
+  let appinfo_vdf: Map<String, Value> = match open_appinfo_vdf(&appinfo_path) {
+    Ok(appinfo_vdf) => appinfo_vdf,
+    Err(err) => {
+      logger::log_tagged_to_core_file(&app_handle, "main", format!("Failed to read appinfo.vdf: {}", err).as_str(), 2);
+      return "{}".to_owned();
+    }
+  };
+
+  app_cache::set_cached_app_names(&app_handle, &appinfo_vdf);
+  return serde_json::to_string(&appinfo_vdf).expect("Should have been able to serialize AppInfo vdf to string.");
+}
+
+/// Reads a shortcuts.vdf file in the shape write_shortcuts_vdf expects. open_shortcuts_vdf leaves out the root shortcuts key.
+fn read_shortcuts_data(shortcuts_vdf_path: &PathBuf) -> Value {
+  let mut shortcuts_data: Map<String, Value> = Map::new();
+  shortcuts_data.insert(String::from("shortcuts"), open_shortcuts_vdf(shortcuts_vdf_path));
+
+  return Value::Object(shortcuts_data);
+}
+
+#[tauri::command]
+/// Reads a single app's entry from the user's appinfo.vdf file, without parsing any other apps.
+async fn get_app_info(app_handle: AppHandle, appid: u32) -> String {
+  let appinfo_path: PathBuf = PathBuf::from(steam::get_appinfo_path(app_handle.to_owned(), None));
+
+  return match find_app_info(&appinfo_path, appid) {
+    Some(app_info) => serde_json::to_string(&app_info).expect("Should have been able to serialize app info to string."),
+    None => {
+      logger::log_tagged_to_core_file(&app_handle, "main", format!("App {} wasn't in appinfo.vdf, or it couldn't be read.", appid).as_str(), 1);
+      "{}".to_owned()
+    }
+  };
+}
+
+#[tauri::command]
+/// Reads the user's shortcuts.vdf file.
+async fn read_shortcuts_vdf(app_handle: AppHandle, steam_active_user_id: String) -> String {
+  let shortcuts_path = PathBuf::from(steam::get_shortcuts_path(app_handle.to_owned(), steam_active_user_id, None));
+    
+  if shortcuts_path.as_path().exists() {
+    logger::log_tagged_to_core_file(&app_handle, "main", "shortcuts.vdf exists, reading...", 0);
+    let shortcuts_array = open_shortcuts_vdf(&shortcuts_path);
+    return serde_json::to_string(&shortcuts_array).expect("Should have been able to serialize Shortcuts vdf to string.");
+  } else {
+    logger::log_tagged_to_core_file(&app_handle, "main", "shortcuts.vdf does not exist.", 0);
+    return "{}".to_owned();
+  }
+}
+
+#[tauri::command]
+/// Checks if the user's shortcuts.vdf file exists, and how many shortcuts it has.
+async fn shortcuts_status(app_handle: AppHandle, steam_active_user_id: String) -> ShortcutsStatus {
+  let shortcuts_path = PathBuf::from(steam::get_shortcuts_path(app_handle.to_owned(), steam_active_user_id, None));
+  let path: String = steam::path_to_string(&shortcuts_path);
+
+  if !shortcuts_path.as_path().exists() {
+    return ShortcutsStatus { exists: false, path, shortcutCount: 0 };
+  }
+
+  let shortcuts_data: Value = read_shortcuts_data(&shortcuts_path);
+  let shortcut_count: usize = shortcuts_data.get("shortcuts").and_then(| shortcuts | shortcuts.as_object()).map(| shortcuts | shortcuts.len()).unwrap_or(0);
+
+  return ShortcutsStatus { exists: true, path, shortcutCount: shortcut_count };
+}
+
+/// Checks if files can be written to a dir by creating and deleting a probe file in it.
+fn probe_dir_writable(dir: &PathBuf) -> Result<(), String> {
+  if !dir.is_dir() {
+    fs::create_dir_all(dir).map_err(| err | format!("Couldn't create {}: {}", dir.display(), err))?;
+  }
+
+  let probe_path: PathBuf = dir.join(format!(".sarm_write_probe_{}", std::process::id()));
+  let write_res = fs::write(&probe_path, b"probe");
+
+  // ? The probe is removed even if writing failed, since the file may have been created before the write errored.
+  let remove_res = if probe_path.exists() { fs::remove_file(&probe_path) } else { Ok(()) };
+
+  write_res.map_err(| err | format!("Couldn't write to {}: {}", dir.display(), err))?;
+  remove_res.map_err(| err | format!("Couldn't delete files in {}: {}", dir.display(), err))?;
+
+  return Ok(());
+}
+
+#[tauri::command]
+/// Checks if the user's grids dir can be written to, so problems can be caught before saving.
+async fn check_grids_writable(app_handle: AppHandle, steam_active_user_id: String) -> GridsWritableStatus {
+  let grids_dir: PathBuf = PathBuf::from(steam::get_grids_directory(app_handle.to_owned(), steam_active_user_id, None));
+
+  return match probe_dir_writable(&grids_dir) {
+    Ok(()) => GridsWritableStatus { writable: true, reason: None },
+    Err(reason) => {
+      logger::log_tagged_to_core_file(&app_handle, "main", format!("Grids dir isn't writable. {}", reason).as_str(), 1);
+      GridsWritableStatus { writable: false, reason: Some(reason) }
+    }
+  };
+}
+
+#[tauri::command]
+/// Reads the user's localconfig.vdf file.
+async fn read_localconfig_vdf(app_handle: AppHandle, steam_active_user_id: String) -> String {
+  let localconfig_path = PathBuf::from(steam::get_localconfig_path(app_handle.to_owned(), steam_active_user_id, None));
+    
+  if localconfig_path.as_path().exists() {
+    logger::log_tagged_to_core_file(&app_handle, "main", "localconfig.vdf exists, reading...", 0);
+    let localconfig_contents: String = fs::read_to_string(localconfig_path).expect("localconfig.vdf should have existed.").parse().expect("File should have been a text file.");
+    let vdf = Vdf::parse(&localconfig_contents).unwrap();
+    let software = vdf.value.get_obj().unwrap().get_key_value("Software").unwrap();
+    let valve = software.1[0].get_obj().unwrap().get_key_value("Valve").unwrap();
+    let steam = valve.1[0].get_obj().unwrap().get_key_value("Steam").unwrap();
+    let apps = steam.1[0].get_obj().unwrap().get_key_value("apps").unwrap();
+
+    let app_entries = &apps.1[0];
+    let mut appids: Vec<String> = Vec::new();
+
+    for (_, appid) in app_entries.get_obj().unwrap().keys().enumerate() {
+      appids.push(appid.to_string());
+    }
+
+    return serde_json::to_string(&appids).expect("Should have been able to serialize localconfig vdf to string.");
+  } else {
+    logger::log_tagged_to_core_file(&app_handle, "main", "localconfig.vdf does not exist.", 0);
+    return "{}".to_owned();
+  }
+}
+
+/// Merges a JSON object into a VDF object. Objects are merged recursively, null removes a key, and anything else replaces the key's value.
+fn merge_json_into_vdf_obj(vdf_obj: &mut Obj, data: &Map<String, Value>) {
+  for (key, val) in data.iter() {
+    let vdf_key = Cow::from(key.to_owned());
+
+    if val.is_null() {
+      vdf_obj.remove(&vdf_key);
+    } else if val.is_object() {
+      let entries: &mut Vec<VdfValue> = vdf_obj.entry(vdf_key).or_insert(Vec::new());
+
+      if entries.is_empty() || entries[0].get_obj().is_none() {
+        *entries = vec![VdfValue::Obj(Obj::new())];
+      }
+
+      let child_obj: &mut Obj = entries[0].get_mut_obj().expect("Entry should have been an object.");
+      merge_json_into_vdf_obj(child_obj, val.as_object().expect("Should have been able to convert value to object."));
+    } else {
+      let val_str: String = if val.is_string() { val.as_str().unwrap().to_owned() } else { val.to_string() };
+      vdf_obj.insert(vdf_key, vec![VdfValue::Str(Cow::from(val_str))]);
+    }
+  }
+}
+
+#[tauri::command]
+/// Writes changes to the user's localconfig.vdf file, leaving everything not in the provided data untouched.
+async fn write_localconfig_vdf(app_handle: AppHandle, steam_active_user_id: String, data: Map<String, Value>) -> bool {
+  let localconfig_path = PathBuf::from(steam::get_localconfig_path(app_handle.to_owned(), steam_active_user_id, None));
+
+  if !localconfig_path.as_path().exists() {
+    logger::log_tagged_to_core_file(&app_handle, "main", "localconfig.vdf does not exist.", 2);
+    return false;
+  }
+
+  let localconfig_contents: String = fs::read_to_string(&localconfig_path).expect("localconfig.vdf should have existed.");
+  let vdf_res = Vdf::parse(&localconfig_contents);
+
+  if vdf_res.is_err() {
+    let err = vdf_res.err().unwrap();
+    logger::log_tagged_to_core_file(&app_handle, "main", format!("Failed to parse localconfig.vdf: {}", err.to_string()).as_str(), 2);
+    return false;
+  }
+
+  let mut vdf = vdf_res.unwrap();
+  let root_obj: &mut Obj = vdf.value.get_mut_obj().expect("localconfig.vdf's root should have been an object.");
+  merge_json_into_vdf_obj(root_obj, &data);
+
+  let backup_path: PathBuf = localconfig_path.with_extension("vdf.bak");
+  let backup_res = fs::copy(&localconfig_path, &backup_path);
+
+  if backup_res.is_err() {
+    let err = backup_res.err().unwrap();
+    logger::log_tagged_to_core_file(&app_handle, "main", format!("Failed to back up localconfig.vdf: {}", err.to_string()).as_str(), 2);
+    return false;
+  }
+
+  let write_res = fs::write(&localconfig_path, vdf.to_string());
+
+  if write_res.is_ok() {
+    logger::log_tagged_to_core_file(&app_handle, "main", "Changes to localconfig.vdf saved.", 0);
+    return true;
+  } else {
+    let err = write_res.err().unwrap();
+    logger::log_tagged_to_core_file(&app_handle, "main", format!("Failed to write localconfig.vdf: {}", err.to_string()).as_str(), 2);
+    return false;
+  }
+}
+
+/// Gets the grid files a set of changes will overwrite or remove, so only those need backing up.
+fn get_changed_grid_files(changed_paths: &[ChangedPath]) -> Vec<PathBuf> {
+  return changed_paths.iter()
+    .flat_map(| changed_path | [changed_path.oldPath.to_owned(), changed_path.targetPath.to_owned()])
+    .filter(| path | !path.is_empty() && path != "REMOVE")
+    .map(PathBuf::from)
+    .collect();
+}
+
+/// Removes a changed path's old grid. It already being gone counts as removed, so retrying a change that failed partway through doesn't fail again.
+fn remove_old_grid(old_path: &str) -> io::Result<()> {
+  return match fs::remove_file(old_path) {
+    Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(()),
+    remove_res => remove_res
+  };
+}
+
+/// Copies or removes a single changed grid, updating its target path if optimizing converted it. Returns true if the grid was removed rather than replaced.
+/// The new grid is copied into place before the old one is removed, so a failed copy leaves the old grid where it was.
+fn apply_changed_path(app_handle: &AppHandle, changed_path: &mut ChangedPath) -> Result<bool, String> {
+  let source = changed_path.sourcePath.to_owned();
+  let target = changed_path.targetPath.to_owned();
+  let old_path = changed_path.oldPath.to_owned();
+
+  if target == String::from("REMOVE") {
+    if old_path.contains("grid") {
+      remove_old_grid(&old_path).map_err(| err | err.to_string())?;
+    }
+
+    logger::log_tagged_to_core_file(app_handle, "main", format!("Removed grid {}.", old_path).as_str(), 0);
+    return Ok(true);
+  }
+
+  let target_path: PathBuf = PathBuf::from(&target);
+  if let Some(target_dir) = target_path.parent() {
+    if !steam::ensure_directory_exists(app_handle, target_dir) {
+      return Err(format!("Failed to create {}", steam::path_to_string(&target_dir)));
+    }
+  }
+
+  let copy_res = fs::copy(source.clone(), target.clone());
+
+  if copy_res.is_err() {
+    logger::log_tagged_to_core_file(app_handle, "main", format!("Failed to copy {} to {}.", source, target).as_str(), 2);
+    let err = copy_res.err().unwrap();
+    return Err(err.to_string());
+  }
+
+  logger::log_tagged_to_core_file(app_handle, "main", format!("Copied {} to {}.", source, target).as_str(), 0);
+  let optimized_path: PathBuf = grid_images::optimize_grid_if_enabled(app_handle, &target_path);
+  changed_path.targetPath = steam::path_to_string(&optimized_path);
+
+  // ? The old grid was already overwritten if it had the same name as the new one.
+  if old_path.contains("grid") && !steam::paths_match(&old_path, &target) && !steam::paths_match(&old_path, &changed_path.targetPath) {
+    remove_old_grid(&old_path).map_err(| err | format!("Saved {}, but failed to remove the old grid {}: {}", changed_path.targetPath, old_path, err))?;
+  }
+
+  return Ok(false);
+}
+
+/// Copies or removes the changed grids, emitting save-progress for each one. Failures are recorded and don't stop the rest from being applied.
+fn apply_changed_paths(app_handle: &AppHandle, changed_paths: &Vec<ChangedPath>) -> SaveSummary {
+  let mut summary: SaveSummary = SaveSummary::default();
+  let total: usize = changed_paths.len();
+
+  for (index, changed_path) in changed_paths.iter().enumerate() {
+    if let Err(err) = app_handle.emit_all("save-progress", SaveProgress { current: index + 1, total, appId: changed_path.appId.to_owned(), gridType: changed_path.gridType.to_owned() }) {
+      logger::log_tagged_to_core_file(app_handle, "main", format!("Failed to emit save-progress: {}", err).as_str(), 2);
+    }
+
+    let mut applied_path: ChangedPath = changed_path.to_owned();
+
+    match apply_changed_path(app_handle, &mut applied_path) {
+      Ok(was_removed) => {
+        if was_removed {
+          summary.removed += 1;
+        } else {
+          summary.applied += 1;
+        }
+
+        summary.changedPaths.push(applied_path);
+      },
+      Err(err) => {
+        logger::log_tagged_to_core_file(app_handle, "main", format!("Failed to save {} for {}: {}", changed_path.gridType, changed_path.appId, err).as_str(), 2);
+        summary.failed += 1;
+        summary.errors.push(SaveError { appId: changed_path.appId.to_owned(), gridType: changed_path.gridType.to_owned(), error: err });
+        summary.failedPaths.push(changed_path.to_owned());
+      }
+    }
+  }
+
+  return summary;
+}
+
+/// Writes or removes the changed logo position configs in a grids dir. Returns the first error encountered.
+fn apply_logo_positions(app_handle: &AppHandle, grids_directory: &PathBuf, changed_logo_positions: &Map<String, Value>) -> Result<(), String> {
+  for (appid, steam_logo_str_val) in changed_logo_positions.iter() {
+    let steam_logo_str: &str = steam_logo_str_val.as_str().expect("Should have been able to convert steamLogo pos into str.");
+    let logo_config_path: PathBuf = grids_directory.join(format!("{}.json", appid));
+
+    if steam_logo_str == "REMOVE" {
+      let remove_res = fs::remove_file(logo_config_path);
+      if remove_res.is_err() {
+        let err = remove_res.err().unwrap();
+        return Err(err.to_string());
+      }
+      logger::log_tagged_to_core_file(app_handle, "main", format!("Removed logo position config for {}.", appid).as_str(), 0);
+    } else {
+      let write_res = fs::write(&logo_config_path, steam_logo_str);
+  
+      if write_res.is_ok() {
+        logger::log_tagged_to_core_file(app_handle, "main", format!("Wrote logo pos to config for {}.", appid).as_str(), 0);
+      } else {
+        logger::log_tagged_to_core_file(app_handle, "main", format!("Failed to write logo pos to config for {}.", appid).as_str(), 2);
+        let err = write_res.err().unwrap();
+        return Err(err.to_string());
+      }
+    }
+  }
+
+  return Ok(());
+}
+
+/// Points a changed path's old grid at the one in another user's grids dir, since their existing art can differ from the active user's.
+fn rebase_changed_path(grid_types: &GridTypeTable, grids_dir: &PathBuf, changed_path: &mut ChangedPath) {
+  changed_path.oldPath = String::from("");
+
+  for grid_path in steam::list_grid_files(grids_dir).into_iter() {
+    let filename_str: &str = grid_path.file_name().and_then(| name | name.to_str()).unwrap_or("");
+
+    if let Some((appid, grid_type)) = parse_grid_filename(grid_types, filename_str) {
+      if appid == changed_path.appId && grid_type == changed_path.gridType {
+        changed_path.oldPath = steam::path_to_string(&grid_path);
+        return;
+      }
+    }
+  }
+}
+
+/// Applies grid and logo position changes to a single user's grids dir.
+fn save_user_grid_changes(app_handle: &AppHandle, steam_user_id: &str, current_art_dict: &GridImageCache, original_art_dict: &GridImageCache, changed_logo_positions: &Map<String, Value>) -> Result<usize, String> {
+  let grids_dir: PathBuf = steam::get_user_grids_dir_path(app_handle, steam_user_id);
+
+  if !steam::ensure_directory_exists(app_handle, &grids_dir) {
+    return Err(format!("Failed to create {}", steam::path_to_string(&grids_dir)));
+  }
+
+  let grid_types: GridTypeTable = get_grid_type_table(app_handle);
+  let mut changed_paths: Vec<ChangedPath> = filter_paths(app_handle, &grids_dir, current_art_dict, original_art_dict);
+  for changed_path in changed_paths.iter_mut() {
+    rebase_changed_path(&grid_types, &grids_dir, changed_path);
+  }
+
+  if !changed_paths.is_empty() {
+    backups::create_backup(app_handle, &grids_dir, &get_changed_grid_files(&changed_paths));
+  }
+
+  let summary: SaveSummary = apply_changed_paths(app_handle, &changed_paths);
+  let logo_positions_res: Result<(), String> = apply_logo_positions(app_handle, &grids_dir, changed_logo_positions);
+
+  if let Some(first_error) = summary.errors.first() {
+    return Err(format!("{} grids failed to save. {}", summary.failed, first_error.error));
+  }
+  logo_positions_res?;
+
+  return Ok(changed_paths.len());
+}
+
+#[tauri::command]
+/// Applies the user's grid changes to several steam users. Shortcuts aren't changed since each user has their own.
+async fn save_changes_multi_user(app_handle: AppHandle, user_ids: Vec<String>, current_art: String, original_art: String, changed_logo_positions: Map<String, Value>) -> HashMap<String, UserSaveResult> {
+  let current_art_dict: GridImageCache = serde_json::from_str(current_art.as_str()).unwrap();
+  let original_art_dict: GridImageCache = serde_json::from_str(original_art.as_str()).unwrap();
+  let mut results: HashMap<String, UserSaveResult> = HashMap::new();
+
+  for user_id in user_ids.into_iter() {
+    logger::log_tagged_to_core_file(&app_handle, "main", format!("Saving changes for user {}...", user_id).as_str(), 0);
+
+    let result: UserSaveResult = match save_user_grid_changes(&app_handle, &user_id, &current_art_dict, &original_art_dict, &changed_logo_positions) {
+      Ok(changed_count) => UserSaveResult { success: true, changedCount: changed_count, error: None },
+      Err(err) => {
+        logger::log_tagged_to_core_file(&app_handle, "main", format!("Failed to save changes for user {}: {}", user_id, err).as_str(), 2);
+        UserSaveResult { success: false, changedCount: 0, error: Some(err) }
+      }
+    };
+
+    results.insert(user_id, result);
+  }
+
+  return results;
+}
+
+#[tauri::command]
+/// Counts the grid changes that would be applied by save_changes, including removals.
+async fn count_pending_changes(app_handle: AppHandle, steam_active_user_id: String, current_art: String, original_art: String) -> usize {
+  return get_changed_paths(&app_handle, steam_active_user_id, &current_art, &original_art).len();
+}
+
+/// Checks if the images being saved have the right aspect ratio for their grid types, so mixups like a hero used as a capsule can be flagged.
+fn get_dimension_warnings(app_handle: &AppHandle, changed_paths: &Vec<ChangedPath>) -> Vec<SaveWarning> {
+  let mut warnings: Vec<SaveWarning> = Vec::new();
+
+  for changed_path in changed_paths.iter().filter(| changed_path | changed_path.targetPath != "REMOVE") {
+    let expected = match grid_images::get_expected_dimensions(&changed_path.gridType) {
+      Some(expected) => expected,
+      None => continue
+    };
+
+    let (width, height) = match grid_images::read_image_dimensions(app_handle, &PathBuf::from(&changed_path.sourcePath)) {
+      Some(dimensions) => dimensions,
+      None => continue
+    };
+
+    if grid_images::dimensions_match(&changed_path.gridType, width, height, &expected) {
+      continue;
+    }
+
+    // ? Naming the type the image does fit makes the likely mixup obvious.
+    let fitting_type: Option<&str> = DEFAULT_GRID_TYPE_SUFFIXES.iter()
+      .map(| (grid_type, _) | *grid_type)
+      .filter(| grid_type | *grid_type != "Logo" && *grid_type != changed_path.gridType)
+      .find(| grid_type | grid_images::get_expected_dimensions(grid_type).is_some_and(| other | grid_images::dimensions_match(grid_type, width, height, &other)));
+
+    let warning: String = match fitting_type {
+      Some(fitting_type) => format!("This {}x{} image looks like a {}, but is being used as a {}.", width, height, fitting_type, changed_path.gridType),
+      None => format!("This {}x{} image doesn't fit the aspect ratio of a {}.", width, height, changed_path.gridType)
+    };
+
+    warnings.push(SaveWarning {
+      appId: changed_path.appId.to_owned(),
+      gridType: changed_path.gridType.to_owned(),
+      sourcePath: changed_path.sourcePath.to_owned(),
+      warning
+    });
+  }
+
+  return warnings;
+}
+
+/// Builds the shortcuts to save from the frontend's copy, pointing each shortcut's icon at its newly saved icon grid.
+/// Only grids that were actually saved should be passed, so shortcuts never reference an icon that failed to save.
+fn set_saved_shortcut_icons(app_handle: &AppHandle, shortcuts_str: &str, saved_paths: &Vec<ChangedPath>) -> Value {
+  let paths_id_map: HashMap<String, ChangedPath> = saved_paths.iter().map(| entry | (format!("{}_{}", entry.appId.to_owned(), entry.gridType.to_owned()).to_string(), entry.to_owned())).collect();
+  let mut shortcuts_data: Value = serde_json::from_str(shortcuts_str).expect("Should have been able to parse json string.");
+
+  let shortcuts_obj_map: &mut Value = shortcuts_data.get_mut("shortcuts").expect("key: shortcuts should have existed.");
+  let shortcuts_map: &mut Map<String, Value> = shortcuts_obj_map.as_object_mut().expect("Should have been able to convert shortcuts to map");
+
+  for (_, shortcut) in shortcuts_map.into_iter() {
+    let shortcut_map: &mut Map<String, Value> = shortcut.as_object_mut().expect("should have been able to convert shortcut to map.");
+    let shortcut_appid_val: &Value = shortcut_map.get("appid").expect("shortcut should have had an appid");
+    let shortcut_appid: String = match normalize_shortcut_appid(shortcut_appid_val) {
+      Some(shortcut_appid) => shortcut_appid,
+      None => {
+        logger::log_tagged_to_core_file(app_handle, "main", format!("Skipping shortcut with invalid appid {}.", shortcut_appid_val).as_str(), 1);
+        continue;
+      }
+    };
+
+    // ? Keys match paths_id_map, which uses the grid type the icon was saved as.
+    let path_key: String = format!("{}_Icon", shortcut_appid.to_owned()).to_string();
+
+    if paths_id_map.contains_key(&path_key) {
+      let changed_path: &ChangedPath = paths_id_map.get(&path_key).expect("entry should have existed.");
+      shortcut_map.insert(String::from("icon"), Value::String(changed_path.targetPath.to_owned()));
+    }
+  }
+
+  let mut modified_shortcuts_data: Map<String, Value> = Map::new();
+  modified_shortcuts_data.insert(String::from("shortcuts"), shortcuts_obj_map.to_owned());
+
+  return Value::Object(modified_shortcuts_data);
+}
+
+/// Splits out the changes whose source images can't be fully decoded, so corrupt files aren't saved as grids.
+/// Returns the changes that can be applied, and a summary of the ones that were skipped.
+fn skip_undecodable_paths(app_handle: &AppHandle, changed_paths: Vec<ChangedPath>) -> (Vec<ChangedPath>, SaveSummary) {
+  let mut decodable_paths: Vec<ChangedPath> = Vec::new();
+  let mut skipped_summary: SaveSummary = SaveSummary::default();
+
+  for changed_path in changed_paths.into_iter() {
+    if changed_path.targetPath == "REMOVE" {
+      decodable_paths.push(changed_path);
+      continue;
+    }
+
+    match grid_images::decode_image_fully(&PathBuf::from(&changed_path.sourcePath)) {
+      Ok(()) => decodable_paths.push(changed_path),
+      Err(err) => {
+        logger::log_tagged_to_core_file(app_handle, "main", format!("Skipping {} for {}, {} couldn't be decoded: {}", changed_path.gridType, changed_path.appId, changed_path.sourcePath, err).as_str(), 1);
+        skipped_summary.failed += 1;
+        skipped_summary.errors.push(SaveError { appId: changed_path.appId.to_owned(), gridType: changed_path.gridType.to_owned(), error: format!("The image couldn't be decoded: {}", err) });
+        skipped_summary.failedPaths.push(changed_path);
+      }
+    }
+  }
+
+  return (decodable_paths, skipped_summary);
+}
+
+#[tauri::command]
+/// Applies the changes the user has made. Shortcut icons are only rewritten if manage_shortcut_icons isn't false.
+/// If validate_dimensions is true, images that don't fit their grid type's aspect ratio are reported as warnings without blocking the save.
+/// If verify_decode is true, every image is fully decoded first, and ones that are corrupt are skipped and reported as failures.
+async fn save_changes(app_handle: AppHandle, steam_active_user_id: String, current_art: String, original_art: String, shortcuts_str: String, shortcut_icons: Map<String, Value>, original_shortcut_icons: Map<String, Value>, changed_logo_positions: Map<String, Value>, manage_shortcut_icons: Option<bool>, validate_dimensions: Option<bool>, verify_decode: Option<bool>) -> String {
+  logger::log_tagged_to_core_file(&app_handle, "main", "Converting current path entries to grid paths...", 0);
+  let paths_to_set: Vec<ChangedPath> = get_changed_paths(&app_handle, steam_active_user_id.clone(), &current_art, &original_art);
+  logger::log_tagged_to_core_file(&app_handle, "main", "Current path entries converted to grid paths.", 0);
+
+  // ? Decoding every image is slow for big saves, so it's only done when asked for.
+  let (paths_to_set, skipped_summary) = if verify_decode.unwrap_or(false) {
+    skip_undecodable_paths(&app_handle, paths_to_set)
+  } else {
+    (paths_to_set, SaveSummary::default())
+  };
+
+  if !paths_to_set.is_empty() {
+    let grids_dir: PathBuf = PathBuf::from(steam::get_grids_directory(app_handle.to_owned(), steam_active_user_id.clone(), None));
+    backups::create_backup(&app_handle, &grids_dir, &get_changed_grid_files(&paths_to_set));
+  }
+
+  // ? Reading every image's header is only worth it when the frontend wants to show the warnings.
+  let warnings: Vec<SaveWarning> = if validate_dimensions.unwrap_or(false) {
+    get_dimension_warnings(&app_handle, &paths_to_set)
+  } else {
+    Vec::new()
+  };
+
+  let mut summary: SaveSummary = apply_changed_paths(&app_handle, &paths_to_set);
+  summary.warnings = warnings;
+  summary.failed += skipped_summary.failed;
+  summary.errors.extend(skipped_summary.errors);
+  summary.failedPaths.extend(skipped_summary.failedPaths);
+
+  let grids_directory: PathBuf = PathBuf::from(steam::get_grids_directory(app_handle.to_owned(), steam_active_user_id.clone(), None));
+  if let Err(err) = apply_logo_positions(&app_handle, &grids_directory, &changed_logo_positions) {
+    summary.failed += 1;
+    summary.errors.push(SaveError { appId: String::from(""), gridType: String::from("Logo Position"), error: err });
+  }
+
+  let should_change_shortcuts: bool = check_for_shortcut_changes(&shortcut_icons, &original_shortcut_icons);
+  
+  if !manage_shortcut_icons.unwrap_or(true) {
+    logger::log_tagged_to_core_file(&app_handle, "main", "Shortcut icon management is disabled. Skipping shortcuts...", 0);
+  } else if should_change_shortcuts {
+    logger::log_tagged_to_core_file(&app_handle, "main", "Changes to shortcuts detected. Writing shortcuts.vdf...", 0);
+    let shortcuts_data: Value = set_saved_shortcut_icons(&app_handle, &shortcuts_str, &summary.changedPaths);
+
+    let shortcuts_vdf_path: PathBuf = PathBuf::from(steam::get_shortcuts_path(app_handle.to_owned(), steam_active_user_id, None));
+    match try_write_shortcuts_vdf(&shortcuts_vdf_path, shortcuts_data) {
+      Ok(()) => logger::log_tagged_to_core_file(&app_handle, "main", "Changes to shortcuts saved.", 0),
+      Err(err) => logger::log_tagged_to_core_file(&app_handle, "main", format!("Failed to save changes to shortcuts: {}", err).as_str(), 2)
+    }
+  } else {
+    logger::log_tagged_to_core_file(&app_handle, "main", "No changes to shortcuts detected. Skipping...", 0);
+  }
+
+  logger::log_tagged_to_core_file(&app_handle, "main", format!("Saved changes. {} applied, {} removed, {} failed.", summary.applied, summary.removed, summary.failed).as_str(), 0);
+
+  return serde_json::to_string(&summary).expect("Should have been able to serialize save summary.");
+}
+
+/// The dir in the grids dir that grids are staged in during a transactional save. Being in the grids dir keeps moving them into place a rename on the same drive.
+const SAVE_STAGING_DIR: &str = ".sarm-staging";
+
+/// Copies the changed grids into the staging dir, optimizing them like a normal save would.
+/// Returns each change with its final target path, and where its grid was staged. Removals have nothing staged.
+fn stage_changed_paths(app_handle: &AppHandle, staging_dir: &PathBuf, changed_paths: &Vec<ChangedPath>) -> Result<Vec<(ChangedPath, Option<PathBuf>)>, SaveError> {
+  let mut staged: Vec<(ChangedPath, Option<PathBuf>)> = Vec::new();
+
+  for changed_path in changed_paths.iter() {
+    if changed_path.targetPath == "REMOVE" {
+      staged.push((changed_path.to_owned(), None));
+      continue;
+    }
+
+    let to_save_error = | err: String | SaveError { appId: changed_path.appId.to_owned(), gridType: changed_path.gridType.to_owned(), error: err };
+
+    let target_path: PathBuf = PathBuf::from(&changed_path.targetPath);
+    let filename = target_path.file_name().ok_or_else(|| to_save_error(format!("{} isn't a file path.", changed_path.targetPath)))?;
+    let staged_path: PathBuf = staging_dir.join(filename);
+
+    fs::copy(&changed_path.sourcePath, &staged_path).map_err(| err | to_save_error(format!("Failed to stage {}: {}", changed_path.sourcePath, err)))?;
+
+    // ? Optimizing can convert the grid to another format, which changes the name it's saved with.
+    let optimized_path: PathBuf = grid_images::optimize_grid_if_enabled(app_handle, &staged_path);
+    let mut staged_change: ChangedPath = changed_path.to_owned();
+    staged_change.targetPath = steam::path_to_string(&target_path.with_file_name(optimized_path.file_name().unwrap_or(filename)));
+
+    staged.push((staged_change, Some(optimized_path)));
+  }
+
+  return Ok(staged);
+}
+
+/// Renames a file, recording the move so it can be undone.
+fn journaled_rename(from: &PathBuf, to: &PathBuf, journal: &mut Vec<(PathBuf, PathBuf)>) -> Result<(), String> {
+  fs::rename(from, to).map_err(| err | format!("Failed to move {} to {}: {}", from.display(), to.display(), err))?;
+  journal.push((from.to_owned(), to.to_owned()));
+
+  return Ok(());
+}
+
+/// Moves the staged shortcuts.vdf and grids into place, moving the files they replace into the rollback dir.
+/// The moves are recorded in the journal, so they can all be undone if one fails.
+fn commit_staged_save(rollback_dir: &PathBuf, staged_grids: &Vec<(ChangedPath, Option<PathBuf>)>, staged_shortcuts: Option<(&PathBuf, &PathBuf)>, journal: &mut Vec<(PathBuf, PathBuf)>) -> Result<(), String> {
+  if let Some((staged_shortcuts_path, shortcuts_vdf_path)) = staged_shortcuts {
+    if shortcuts_vdf_path.exists() {
+      journaled_rename(shortcuts_vdf_path, &shortcuts_vdf_path.with_extension("vdf.rollback"), journal)?;
+    }
+
+    journaled_rename(staged_shortcuts_path, shortcuts_vdf_path, journal)?;
+  }
+
+  for (index, (changed_path, staged_path)) in staged_grids.iter().enumerate() {
+    let old_path: PathBuf = PathBuf::from(&changed_path.oldPath);
+
+    if !changed_path.oldPath.is_empty() && old_path.is_file() {
+      let old_filename: String = old_path.file_name().map(| name | name.to_string_lossy().into_owned()).unwrap_or_default();
+      // ? The index keeps grids with the same name from different changes apart.
+      journaled_rename(&old_path, &rollback_dir.join(format!("{}_{}", index, old_filename)), journal)?;
+    }
+
+    if let Some(staged_path) = staged_path {
+      journaled_rename(staged_path, &PathBuf::from(&changed_path.targetPath), journal)?;
+    }
+  }
+
+  return Ok(());
+}
+
+#[tauri::command]
+/// Applies the changes the user has made like save_changes, but as a single transaction across the grids and shortcuts.vdf.
+/// Every grid and the new shortcuts.vdf are staged first, and only moved into place once they all staged successfully.
+/// If anything fails, every grid and shortcuts.vdf are put back how they were, so shortcut icons never reference grids that weren't saved.
+async fn save_changes_transactional(app_handle: AppHandle, steam_active_user_id: String, current_art: String, original_art: String, shortcuts_str: String, shortcut_icons: Map<String, Value>, original_shortcut_icons: Map<String, Value>, changed_logo_positions: Map<String, Value>, manage_shortcut_icons: Option<bool>, validate_dimensions: Option<bool>) -> String {
+  let paths_to_set: Vec<ChangedPath> = get_changed_paths(&app_handle, steam_active_user_id.clone(), &current_art, &original_art);
+  let grids_dir: PathBuf = PathBuf::from(steam::get_grids_directory(app_handle.to_owned(), steam_active_user_id.clone(), None));
+  let shortcuts_vdf_path: PathBuf = PathBuf::from(steam::get_shortcuts_path(app_handle.to_owned(), steam_active_user_id.clone(), None));
+
+  let mut summary: SaveSummary = SaveSummary::default();
+
+  // ? Reading every image's header is only worth it when the frontend wants to show the warnings.
+  if validate_dimensions.unwrap_or(false) {
+    summary.warnings = get_dimension_warnings(&app_handle, &paths_to_set);
+  }
+
+  let should_change_shortcuts: bool = manage_shortcut_icons.unwrap_or(true) && check_for_shortcut_changes(&shortcut_icons, &original_shortcut_icons);
+
+  if !paths_to_set.is_empty() || should_change_shortcuts {
+    if !paths_to_set.is_empty() {
+      backups::create_backup(&app_handle, &grids_dir, &get_changed_grid_files(&paths_to_set));
+    }
+
+    let staging_dir: PathBuf = grids_dir.join(SAVE_STAGING_DIR);
+    let rollback_dir: PathBuf = staging_dir.join("rollback");
+    let staged_shortcuts_path: PathBuf = shortcuts_vdf_path.with_extension("vdf.staged");
+
+    // ? A leftover staging dir is from a save that was interrupted, and may hold originals that were never restored, so it's moved aside rather than deleted.
+    if staging_dir.exists() {
+      let interrupted_secs: u64 = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).map(| since_epoch | since_epoch.as_secs()).unwrap_or(0);
+      let interrupted_dir: PathBuf = grids_dir.join(format!("{}-{}", SAVE_STAGING_DIR, interrupted_secs));
+
+      logger::log_tagged_to_core_file(&app_handle, "main", format!("Found a staging dir left by an interrupted save. Moving it to {}.", interrupted_dir.display()).as_str(), 1);
+      let _ = fs::rename(&staging_dir, &interrupted_dir);
+    }
+
+    let stage_res: Result<Vec<(ChangedPath, Option<PathBuf>)>, SaveError> = fs::create_dir_all(&rollback_dir)
+      .map_err(| err | SaveError { appId: String::from(""), gridType: String::from(""), error: format!("Failed to create {}: {}", rollback_dir.display(), err) })
+      .and_then(| _ | stage_changed_paths(&app_handle, &staging_dir, &paths_to_set))
+      .and_then(| staged_grids | {
+        if should_change_shortcuts {
+          let saved_paths: Vec<ChangedPath> = staged_grids.iter().map(| (changed_path, _) | changed_path.to_owned()).collect();
+          let shortcuts_data: Value = set_saved_shortcut_icons(&app_handle, &shortcuts_str, &saved_paths);
+
+          if let Err(err) = try_write_shortcuts_vdf(&staged_shortcuts_path, shortcuts_data) {
+            return Err(SaveError { appId: String::from(""), gridType: String::from("Shortcuts"), error: format!("Failed to stage shortcuts.vdf: {}", err) });
+          }
+        }
+
+        return Ok(staged_grids);
+      });
+
+    let mut rollback_failed: bool = false;
+    let commit_res: Result<Vec<(ChangedPath, Option<PathBuf>)>, SaveError> = stage_res.and_then(| staged_grids | {
+      let mut journal: Vec<(PathBuf, PathBuf)> = Vec::new();
+      let staged_shortcuts: Option<(&PathBuf, &PathBuf)> = if should_change_shortcuts { Some((&staged_shortcuts_path, &shortcuts_vdf_path)) } else { None };
+
+      if let Err(err) = commit_staged_save(&rollback_dir, &staged_grids, staged_shortcuts, &mut journal) {
+        logger::log_tagged_to_core_file(&app_handle, "main", format!("{} Rolling back the {} moves already made...", err, journal.len()).as_str(), 2);
+
+        for (from, to) in journal.iter().rev() {
+          if let Err(rollback_err) = fs::rename(to, from) {
+            logger::log_tagged_to_core_file(&app_handle, "main", format!("Failed to move {} back to {}: {}", to.display(), from.display(), rollback_err).as_str(), 2);
+            rollback_failed = true;
+          }
+        }
+
+        return Err(SaveError { appId: String::from(""), gridType: String::from(""), error: err });
+      }
+
+      return Ok(staged_grids);
+    });
+
+    // ? If rolling back failed, the originals are still in the staging dir and need to be kept so they can be restored by hand.
+    if rollback_failed {
+      logger::log_tagged_to_core_file(&app_handle, "main", format!("Some files couldn't be rolled back. The originals were left in {}.", staging_dir.display()).as_str(), 2);
+    } else {
+      let _ = fs::remove_dir_all(&staging_dir);
+      let _ = fs::remove_file(&staged_shortcuts_path);
+      let _ = fs::remove_file(shortcuts_vdf_path.with_extension("vdf.rollback"));
+    }
+
+    match commit_res {
+      Ok(staged_grids) => {
+        for (changed_path, staged_path) in staged_grids.into_iter() {
+          if staged_path.is_some() {
+            summary.applied += 1;
+          } else {
+            summary.removed += 1;
+          }
+
+          summary.changedPaths.push(changed_path);
+        }
+      },
+      Err(save_error) => {
+        logger::log_tagged_to_core_file(&app_handle, "main", format!("Save failed, so nothing was changed: {}", save_error.error).as_str(), 2);
+
+        summary.failed = paths_to_set.len();
+        summary.errors.push(save_error);
+        summary.failedPaths = paths_to_set;
+      }
+    }
+  }
+
+  if let Err(err) = apply_logo_positions(&app_handle, &grids_dir, &changed_logo_positions) {
+    summary.failed += 1;
+    summary.errors.push(SaveError { appId: String::from(""), gridType: String::from("Logo Position"), error: err });
+  }
+
+  logger::log_tagged_to_core_file(&app_handle, "main", format!("Saved changes transactionally. {} applied, {} removed, {} failed.", summary.applied, summary.removed, summary.failed).as_str(), 0);
+
+  return serde_json::to_string(&summary).expect("Should have been able to serialize save summary.");
+}
+
+/// Checks that a provided change only touches the user's grids dir, and that its target is named for its app and grid type.
+fn validate_changed_path(grid_types: &GridTypeTable, grids_dir: &PathBuf, changed_path: &ChangedPath) -> Result<(), String> {
+  if !is_known_grid_type(grid_types, &changed_path.gridType) {
+    return Err(format!("Unknown grid type {}.", changed_path.gridType));
+  }
+
+  let is_in_grids_dir = | path_str: &str | {
+    let path: PathBuf = PathBuf::from(path_str);
+    return path.starts_with(grids_dir) && !path.components().any(| component | component == std::path::Component::ParentDir);
+  };
+
+  if !changed_path.oldPath.is_empty() && !is_in_grids_dir(&changed_path.oldPath) {
+    return Err(format!("Old path {} isn't in the grids dir.", changed_path.oldPath));
+  }
+
+  if changed_path.targetPath == "REMOVE" {
+    if changed_path.oldPath.is_empty() {
+      return Err(String::from("Nothing to remove."));
+    }
+
+    return Ok(());
+  }
+
+  let target_path: PathBuf = PathBuf::from(&changed_path.targetPath);
+  if target_path.parent() != Some(grids_dir.as_path()) || !is_in_grids_dir(&changed_path.targetPath) {
+    return Err(format!("Target path {} isn't in the grids dir.", changed_path.targetPath));
+  }
+
+  let target_filename: &str = target_path.file_name().and_then(| name | name.to_str()).unwrap_or("");
+  if parse_grid_filename(grid_types, target_filename) != Some((changed_path.appId.to_owned(), changed_path.gridType.to_owned())) {
+    return Err(format!("Target path {} isn't named for {} {}.", changed_path.targetPath, changed_path.appId, changed_path.gridType));
+  }
+
+  if !PathBuf::from(&changed_path.sourcePath).is_file() {
+    return Err(format!("Source path {} doesn't exist.", changed_path.sourcePath));
+  }
+
+  return Ok(());
+}
+
+/// Points the icons of shortcuts with a newly saved icon grid at it. Returns true if shortcuts.vdf was updated.
+fn update_changed_shortcut_icons(app_handle: &AppHandle, steam_active_user_id: String, changed_paths: &Vec<ChangedPath>) -> bool {
+  let shortcuts_vdf_path: PathBuf = PathBuf::from(steam::get_shortcuts_path(app_handle.to_owned(), steam_active_user_id, None));
+  let icon_paths: HashMap<String, String> = changed_paths.iter()
+    .filter(| changed_path | changed_path.gridType == "Icon" && changed_path.targetPath != "REMOVE")
+    .map(| changed_path | (changed_path.appId.to_owned(), changed_path.targetPath.to_owned()))
+    .collect();
+
+  if icon_paths.is_empty() || !shortcuts_vdf_path.exists() {
+    return false;
+  }
+
+  let mut shortcuts_data: Value = read_shortcuts_data(&shortcuts_vdf_path);
+  let mut updated_icons: bool = false;
+
+  if let Some(shortcuts_map) = shortcuts_data.get_mut("shortcuts").and_then(| shortcuts | shortcuts.as_object_mut()) {
+    for (_, shortcut) in shortcuts_map.iter_mut() {
+      let shortcut_appid: String = shortcut.get("appid").and_then(normalize_shortcut_appid).unwrap_or_default();
+
+      if let (Some(icon_path), Some(shortcut_map)) = (icon_paths.get(&shortcut_appid), shortcut.as_object_mut()) {
+        shortcut_map.insert(String::from("icon"), Value::String(icon_path.to_owned()));
+        updated_icons = true;
+      }
+    }
+  }
+
+  if updated_icons {
+    if let Err(err) = try_write_shortcuts_vdf(&shortcuts_vdf_path, shortcuts_data) {
+      logger::log_tagged_to_core_file(app_handle, "main", format!("Failed to update shortcut icons pointing at changed grids: {}", err).as_str(), 2);
+      return false;
+    }
+
+    logger::log_tagged_to_core_file(app_handle, "main", "Updated shortcut icons pointing at changed grids.", 0);
+  }
+
+  return updated_icons;
+}
+
+#[tauri::command]
+/// Applies an explicit list of grid changes, skipping the current/original art diff. Invalid changes are reported as failures without being applied.
+async fn apply_changes(app_handle: AppHandle, steam_active_user_id: String, changes: Vec<ChangedPath>) -> String {
+  let grids_dir: PathBuf = PathBuf::from(steam::get_grids_directory(app_handle.to_owned(), steam_active_user_id.clone(), None));
+  let grid_types: GridTypeTable = get_grid_type_table(&app_handle);
+  let mut valid_changes: Vec<ChangedPath> = Vec::new();
+  let mut invalid_summary: SaveSummary = SaveSummary::default();
+
+  for change in changes.into_iter() {
+    let mut change: ChangedPath = change;
+    change.oldPath = steam::normalize_path(&change.oldPath);
+    change.targetPath = steam::normalize_path(&change.targetPath);
+    change.sourcePath = steam::normalize_path(&change.sourcePath);
+
+    match validate_changed_path(&grid_types, &grids_dir, &change) {
+      Ok(()) => valid_changes.push(change),
+      Err(err) => {
+        logger::log_tagged_to_core_file(&app_handle, "main", format!("Skipping invalid change to {} for {}: {}", change.gridType, change.appId, err).as_str(), 1);
+        invalid_summary.failed += 1;
+        invalid_summary.errors.push(SaveError { appId: change.appId.to_owned(), gridType: change.gridType.to_owned(), error: err });
+        invalid_summary.failedPaths.push(change);
+      }
+    }
+  }
+
+  if !valid_changes.is_empty() {
+    if !steam::ensure_directory_exists(&app_handle, &grids_dir) {
+      logger::log_tagged_to_core_file(&app_handle, "main", format!("Failed to create {}.", grids_dir.display()).as_str(), 2);
+    }
+    backups::create_backup(&app_handle, &grids_dir, &get_changed_grid_files(&valid_changes));
+  }
+
+  let mut summary: SaveSummary = apply_changed_paths(&app_handle, &valid_changes);
+  summary.failed += invalid_summary.failed;
+  summary.errors.extend(invalid_summary.errors);
+  summary.failedPaths.extend(invalid_summary.failedPaths);
+
+  update_changed_shortcut_icons(&app_handle, steam_active_user_id, &summary.changedPaths);
+
+  logger::log_tagged_to_core_file(&app_handle, "main", format!("Applied changes. {} applied, {} removed, {} failed.", summary.applied, summary.removed, summary.failed).as_str(), 0);
+
+  return serde_json::to_string(&summary).expect("Should have been able to serialize save summary.");
+}
+
+#[tauri::command]
+/// Writes the user's shortcuts.vdf file.
+async fn write_shortcuts(app_handle: AppHandle, steam_active_user_id: String, shortcuts_str: String) -> bool {
+  logger::log_tagged_to_core_file(&app_handle, "main", "Writing shortcuts.vdf...", 0);
+  let shortcuts_vdf_path: PathBuf = PathBuf::from(steam::get_shortcuts_path(app_handle.to_owned(), steam_active_user_id, None));
+  let shortcuts_data: Value = serde_json::from_str(shortcuts_str.as_str()).expect("Should have been able to parse json string.");
+
+  let success: bool = write_shortcuts_vdf(&shortcuts_vdf_path, shortcuts_data);
+
+  if success {
+    logger::log_tagged_to_core_file(&app_handle, "main", "Changes to shortcuts saved.", 0);
+    return true;
+  } else {
+    logger::log_tagged_to_core_file(&app_handle, "main", "Changes to shortcuts failed.", 0);
+    return false;
+  }
+}
+
+#[tauri::command]
+/// Writes an empty shortcuts.vdf file for the user if they don't have one yet. Returns true if a new file was created.
+async fn ensure_shortcuts_vdf(app_handle: AppHandle, steam_active_user_id: String) -> bool {
+  let shortcuts_vdf_path: PathBuf = PathBuf::from(steam::get_shortcuts_path(app_handle.to_owned(), steam_active_user_id, None));
+
+  if shortcuts_vdf_path.exists() {
+    return false;
+  }
+
+  if let Some(config_dir) = shortcuts_vdf_path.parent() {
+    if !steam::ensure_directory_exists(&app_handle, config_dir) {
+      return false;
+    }
+  }
+
+  let mut empty_shortcuts: Map<String, Value> = Map::new();
+  empty_shortcuts.insert(String::from("shortcuts"), Value::Object(Map::new()));
+
+  let created: bool = write_shortcuts_vdf(&shortcuts_vdf_path, Value::Object(empty_shortcuts));
+
+  if created {
+    logger::log_tagged_to_core_file(&app_handle, "main", format!("Created empty shortcuts.vdf at {}.", shortcuts_vdf_path.display()).as_str(), 0);
+  }
+
+  return created;
+}
+
+/// Re-keys shortcuts to sequential indexes, as steam expects. Shortcuts can be provided as an array or an object,
+/// either under a root shortcuts key or directly, the way read_shortcuts_vdf returns them.
+fn reindex_shortcuts(shortcuts_data: &Value) -> Option<Value> {
+  let shortcuts: Vec<Value> = match shortcuts_data.get("shortcuts").unwrap_or(shortcuts_data) {
+    Value::Array(shortcuts) => shortcuts.to_owned(),
+    Value::Object(shortcuts) => {
+      let mut entries: Vec<(&String, &Value)> = shortcuts.iter().collect();
+      // ? Keys are sorted as numbers so "10" doesn't come before "2".
+      entries.sort_by_key(| (key, _) | key.parse::<u64>().unwrap_or(u64::MAX));
+      entries.into_iter().map(| (_, shortcut) | shortcut.to_owned()).collect()
+    },
+    _ => return None
+  };
+
+  let mut reindexed: Map<String, Value> = Map::new();
+
+  for (index, shortcut) in shortcuts.into_iter().enumerate() {
+    if !shortcut.is_object() {
+      return None;
+    }
+
+    reindexed.insert(index.to_string(), shortcut);
+  }
+
+  let mut reindexed_data: Map<String, Value> = Map::new();
+  reindexed_data.insert(String::from("shortcuts"), Value::Object(reindexed));
+
+  return Some(Value::Object(reindexed_data));
+}
+
+#[tauri::command]
+/// Exports the user's shortcuts.vdf file as pretty printed json.
+async fn export_shortcuts_json(app_handle: AppHandle, steam_active_user_id: String, dest_path: String) -> bool {
+  let shortcuts_vdf_path: PathBuf = PathBuf::from(steam::get_shortcuts_path(app_handle.to_owned(), steam_active_user_id, None));
+
+  if !shortcuts_vdf_path.exists() {
+    logger::log_tagged_to_core_file(&app_handle, "main", "Can't export shortcuts, shortcuts.vdf does not exist.", 1);
+    return false;
+  }
+
+  let shortcuts_data: Value = read_shortcuts_data(&shortcuts_vdf_path);
+  let shortcuts_json: String = serde_json::to_string_pretty(&shortcuts_data).expect("Should have been able to serialize shortcuts.");
+
+  if let Err(err) = fs::write(&dest_path, shortcuts_json) {
+    logger::log_tagged_to_core_file(&app_handle, "main", format!("Failed to export shortcuts to {}: {}", dest_path, err).as_str(), 2);
+    return false;
+  }
+
+  logger::log_tagged_to_core_file(&app_handle, "main", format!("Exported shortcuts to {}.", dest_path).as_str(), 0);
+  return true;
+}
+
+#[tauri::command]
+/// Imports shortcuts from a json file, replacing the user's shortcuts.vdf file.
+async fn import_shortcuts_json(app_handle: AppHandle, steam_active_user_id: String, src_path: String) -> bool {
+  let shortcuts_vdf_path: PathBuf = PathBuf::from(steam::get_shortcuts_path(app_handle.to_owned(), steam_active_user_id, None));
+
+  let shortcuts_json: String = match fs::read_to_string(&src_path) {
+    Ok(contents) => contents,
+    Err(err) => {
+      logger::log_tagged_to_core_file(&app_handle, "main", format!("Failed to read shortcuts from {}: {}", src_path, err).as_str(), 2);
+      return false;
+    }
+  };
+
+  let shortcuts_data: Option<Value> = serde_json::from_str::<Value>(&shortcuts_json).ok().and_then(| data | reindex_shortcuts(&data));
+
+  let shortcuts_data: Value = match shortcuts_data {
+    Some(shortcuts_data) => shortcuts_data,
+    None => {
+      logger::log_tagged_to_core_file(&app_handle, "main", format!("{} isn't a valid shortcuts export.", src_path).as_str(), 2);
+      return false;
+    }
+  };
+
+  if let Some(config_dir) = shortcuts_vdf_path.parent() {
+    if !steam::ensure_directory_exists(&app_handle, config_dir) {
+      return false;
+    }
+  }
+
+  let success: bool = write_shortcuts_vdf(&shortcuts_vdf_path, shortcuts_data);
+
+  if success {
+    logger::log_tagged_to_core_file(&app_handle, "main", format!("Imported shortcuts from {}.", src_path).as_str(), 0);
+  } else {
+    logger::log_tagged_to_core_file(&app_handle, "main", format!("Failed to import shortcuts from {}.", src_path).as_str(), 2);
+  }
+
+  return success;
+}
+
+/// Gets a string field from a shortcut. Steam has changed the casing of shortcut keys over time, so they're matched case insensitively.
+fn get_shortcut_str<'a>(shortcut: &'a Value, key: &str) -> &'a str {
+  return shortcut.as_object()
+    .and_then(| shortcut_map | shortcut_map.iter().find(| (field, _) | field.eq_ignore_ascii_case(key)))
+    .and_then(| (_, value) | value.as_str())
+    .unwrap_or("");
+}
+
+/// Gets a shortcut's name.
+fn get_shortcut_app_name(shortcut: &Value) -> &str {
+  return get_shortcut_str(shortcut, "appname");
+}
+
+/// Gets the path of a shortcut's exe. Steam usually quotes it.
+fn get_shortcut_exe(shortcut: &Value) -> &str {
+  return get_shortcut_str(shortcut, "exe");
+}
+
+/// Gets the path of a shortcut's icon, or an empty string if it uses the exe's icon.
+fn get_shortcut_icon(shortcut: &Value) -> &str {
+  return get_shortcut_str(shortcut, "icon");
+}
+
+/// Checks if two shortcuts are the same game, based on their exe and name.
+fn is_same_shortcut(shortcut_a: &Value, shortcut_b: &Value) -> bool {
+  return get_shortcut_exe(shortcut_a) == get_shortcut_exe(shortcut_b) && get_shortcut_app_name(shortcut_a) == get_shortcut_app_name(shortcut_b);
+}
+
+#[tauri::command]
+/// Merges another set of shortcuts into the user's shortcuts.vdf file, skipping any that already exist. Returns the number of shortcuts added.
+async fn merge_shortcuts(app_handle: AppHandle, steam_active_user_id: String, other_shortcuts_json: String) -> usize {
+  let shortcuts_vdf_path: PathBuf = PathBuf::from(steam::get_shortcuts_path(app_handle.to_owned(), steam_active_user_id, None));
+
+  let other_shortcuts_data: Option<Value> = serde_json::from_str::<Value>(&other_shortcuts_json).ok().and_then(| data | reindex_shortcuts(&data));
+
+  let other_shortcuts: Vec<Value> = match other_shortcuts_data.as_ref().and_then(| data | data.get("shortcuts")).and_then(| shortcuts | shortcuts.as_object()) {
+    Some(other_shortcuts) => other_shortcuts.values().cloned().collect(),
+    None => {
+      logger::log_tagged_to_core_file(&app_handle, "main", "Can't merge shortcuts, the provided shortcuts weren't valid.", 2);
+      return 0;
+    }
+  };
+
+  let local_shortcuts_data: Value = if shortcuts_vdf_path.exists() {
+    read_shortcuts_data(&shortcuts_vdf_path)
+  } else {
+    Value::Null
+  };
+
+  let mut merged_shortcuts: Vec<Value> = match reindex_shortcuts(&local_shortcuts_data) {
+    Some(local_shortcuts_data) => local_shortcuts_data["shortcuts"].as_object().expect("Reindexed shortcuts should have been an object.").values().cloned().collect(),
+    None => Vec::new()
+  };
+  let mut added: usize = 0;
+
+  for other_shortcut in other_shortcuts.into_iter() {
+    let existing_shortcut: Option<&mut Value> = merged_shortcuts.iter_mut().find(| shortcut | is_same_shortcut(shortcut, &other_shortcut));
+
+    match existing_shortcut {
+      Some(existing_shortcut) => {
+        // ? The local icon is kept if there is one, but a missing one is filled in from the other set.
+        let other_icon: &str = get_shortcut_icon(&other_shortcut);
+
+        if get_shortcut_icon(existing_shortcut).is_empty() && !other_icon.is_empty() {
+          existing_shortcut.as_object_mut().unwrap().insert(String::from("icon"), Value::String(other_icon.to_owned()));
+        }
+      },
+      None => {
+        merged_shortcuts.push(other_shortcut);
+        added += 1;
+      }
+    }
+  }
+
+  let mut merged_shortcuts_data: Map<String, Value> = Map::new();
+  merged_shortcuts_data.insert(String::from("shortcuts"), Value::Array(merged_shortcuts));
+  let merged_shortcuts_data: Value = reindex_shortcuts(&Value::Object(merged_shortcuts_data)).expect("Merged shortcuts should have been valid.");
+
+  if let Some(config_dir) = shortcuts_vdf_path.parent() {
+    if !steam::ensure_directory_exists(&app_handle, config_dir) {
+      return 0;
+    }
+  }
+
+  if !write_shortcuts_vdf(&shortcuts_vdf_path, merged_shortcuts_data) {
+    logger::log_tagged_to_core_file(&app_handle, "main", "Failed to write merged shortcuts.", 2);
+    return 0;
+  }
+
+  logger::log_tagged_to_core_file(&app_handle, "main", format!("Merged {} new shortcuts into shortcuts.vdf.", added).as_str(), 0);
+  return added;
+}
+
+/// Checks a shortcut for the fields steam needs, returning each problem found.
+fn check_shortcut_fields(shortcut: &Value) -> Vec<String> {
+  let mut problems: Vec<String> = Vec::new();
+
+  if !shortcut.is_object() {
+    problems.push(String::from("Shortcut isn't a map."));
+    return problems;
+  }
+
+  match shortcut.get("appid") {
+    Some(appid) if appid.is_u64() || appid.is_i64() => {},
+    Some(appid) => problems.push(format!("Appid {} isn't an integer.", appid)),
+    None => problems.push(String::from("Missing appid."))
+  }
+
+  if get_shortcut_app_name(shortcut).is_empty() {
+    problems.push(String::from("Missing app name."));
+  }
+
+  if get_shortcut_exe(shortcut).is_empty() {
+    problems.push(String::from("Missing exe."));
+  }
+
+  if let Some(icon) = get_broken_shortcut_icon(shortcut) {
+    problems.push(format!("Icon {} doesn't exist.", icon));
+  }
+
+  return problems;
+}
+
+/// Gets a shortcut's icon path if it's set but the file doesn't exist anymore.
+fn get_broken_shortcut_icon(shortcut: &Value) -> Option<&str> {
+  // ? Steam quotes some paths, so the quotes need to be stripped before checking them.
+  let icon: &str = get_shortcut_icon(shortcut).trim_matches('"');
+
+  if icon.is_empty() || PathBuf::from(icon).is_file() {
+    return None;
+  }
+
+  return Some(icon);
+}
+
+#[tauri::command]
+/// Checks the user's shortcuts.vdf file for corrupt entries and shortcuts missing required fields, without failing on malformed files.
+async fn validate_shortcuts(app_handle: AppHandle, steam_active_user_id: String) -> ShortcutsValidation {
+  let shortcuts_vdf_path: PathBuf = PathBuf::from(steam::get_shortcuts_path(app_handle.to_owned(), steam_active_user_id, None));
+
+  if !shortcuts_vdf_path.exists() {
+    return ShortcutsValidation { valid: true, issues: Vec::new() };
+  }
+
+  let checked: CheckedShortcuts = match try_open_shortcuts_vdf(&shortcuts_vdf_path) {
+    Ok(checked) => checked,
+    Err(err) => {
+      logger::log_tagged_to_core_file(&app_handle, "main", format!("shortcuts.vdf couldn't be read: {}", err).as_str(), 2);
+      return ShortcutsValidation { valid: false, issues: vec![ShortcutIssue { shortcutId: None, issue: err }] };
+    }
+  };
+
+  let mut issues: Vec<ShortcutIssue> = checked.issues.into_iter().map(| (shortcut_id, issue) | ShortcutIssue { shortcutId: shortcut_id, issue }).collect();
+
+  for (shortcut_id, shortcut) in checked.shortcuts.iter() {
+    for problem in check_shortcut_fields(shortcut).into_iter() {
+      issues.push(ShortcutIssue { shortcutId: Some(shortcut_id.to_owned()), issue: problem });
+    }
+  }
+
+  logger::log_tagged_to_core_file(&app_handle, "main", format!("Validated shortcuts.vdf. Found {} issues.", issues.len()).as_str(), 0);
+  return ShortcutsValidation { valid: issues.is_empty(), issues };
+}
+
+#[tauri::command]
+/// Rewrites the user's shortcuts.vdf file without the entries that couldn't be read, backing up the original first. Returns the number of shortcuts kept.
+async fn repair_shortcuts(app_handle: AppHandle, steam_active_user_id: String) -> Result<usize, String> {
+  let shortcuts_vdf_path: PathBuf = PathBuf::from(steam::get_shortcuts_path(app_handle.to_owned(), steam_active_user_id, None));
+
+  if !shortcuts_vdf_path.exists() {
+    return Err(String::from("shortcuts.vdf does not exist."));
+  }
+
+  let checked: CheckedShortcuts = try_open_shortcuts_vdf(&shortcuts_vdf_path).map_err(| err | format!("shortcuts.vdf couldn't be read: {}", err))?;
+
+  let mut shortcuts_data: Map<String, Value> = Map::new();
+  shortcuts_data.insert(String::from("shortcuts"), Value::Object(checked.shortcuts));
+  let shortcuts_data: Value = reindex_shortcuts(&Value::Object(shortcuts_data)).ok_or(String::from("Some readable shortcuts weren't maps."))?;
+  let kept: usize = shortcuts_data["shortcuts"].as_object().map(| shortcuts | shortcuts.len()).unwrap_or(0);
+
+  let backup_path: PathBuf = shortcuts_vdf_path.with_extension("vdf.bak");
+  fs::copy(&shortcuts_vdf_path, &backup_path).map_err(| err | format!("Failed to back up shortcuts.vdf: {}", err))?;
+
+  if !write_shortcuts_vdf(&shortcuts_vdf_path, shortcuts_data) {
+    return Err(String::from("Failed to write repaired shortcuts.vdf."));
+  }
+
+  logger::log_tagged_to_core_file(&app_handle, "main", format!("Repaired shortcuts.vdf, keeping {} shortcuts and dropping {} corrupt entries. The original was backed up to {}.", kept, checked.issues.len(), backup_path.display()).as_str(), 0);
+  return Ok(kept);
+}
+
+#[tauri::command]
+/// Finds the user's shortcuts whose icon points to a file that doesn't exist anymore, which steam shows as a blank icon.
+async fn find_broken_shortcut_icons(app_handle: AppHandle, steam_active_user_id: String) -> Vec<BrokenShortcutIcon> {
+  let shortcuts_vdf_path: PathBuf = PathBuf::from(steam::get_shortcuts_path(app_handle.to_owned(), steam_active_user_id, None));
+
+  if !shortcuts_vdf_path.exists() {
+    return Vec::new();
+  }
+
+  let shortcuts_data: Value = read_shortcuts_data(&shortcuts_vdf_path);
+
+  let broken_icons: Vec<BrokenShortcutIcon> = shortcuts_data.get("shortcuts").and_then(| shortcuts | shortcuts.as_object()).into_iter().flat_map(| shortcuts | shortcuts.values()).filter_map(| shortcut | {
+    let icon: &str = get_broken_shortcut_icon(shortcut)?;
+
+    return Some(BrokenShortcutIcon {
+      appId: shortcut.get("appid").and_then(normalize_shortcut_appid).unwrap_or_default(),
+      appName: get_shortcut_app_name(shortcut).to_owned(),
+      icon: icon.to_owned()
+    });
+  }).collect();
+
+  logger::log_tagged_to_core_file(&app_handle, "main", format!("Found {} shortcuts with missing icons.", broken_icons.len()).as_str(), 0);
+  return broken_icons;
+}
+
+#[tauri::command]
+/// Blanks the icons of the user's shortcuts that point to files that don't exist anymore, so steam falls back to the exe's icon. Backs up shortcuts.vdf first.
+/// Returns the number of shortcuts that were cleared.
+async fn clear_broken_shortcut_icons(app_handle: AppHandle, steam_active_user_id: String) -> Result<usize, String> {
+  let shortcuts_vdf_path: PathBuf = PathBuf::from(steam::get_shortcuts_path(app_handle.to_owned(), steam_active_user_id, None));
+
+  if !shortcuts_vdf_path.exists() {
+    return Err(String::from("shortcuts.vdf does not exist."));
+  }
+
+  let mut shortcuts_data: Value = read_shortcuts_data(&shortcuts_vdf_path);
+  let mut cleared: usize = 0;
+
+  if let Some(shortcuts_map) = shortcuts_data.get_mut("shortcuts").and_then(| shortcuts | shortcuts.as_object_mut()) {
+    for (_, shortcut) in shortcuts_map.iter_mut() {
+      if get_broken_shortcut_icon(shortcut).is_none() {
+        continue;
+      }
+
+      if let Some(shortcut_map) = shortcut.as_object_mut() {
+        shortcut_map.insert(String::from("icon"), Value::String(String::new()));
+        cleared += 1;
+      }
+    }
+  }
+
+  if cleared == 0 {
+    logger::log_tagged_to_core_file(&app_handle, "main", "No shortcuts had missing icons.", 0);
+    return Ok(0);
+  }
+
+  let backup_path: PathBuf = shortcuts_vdf_path.with_extension("vdf.bak");
+  fs::copy(&shortcuts_vdf_path, &backup_path).map_err(| err | format!("Failed to back up shortcuts.vdf: {}", err))?;
+
+  if !write_shortcuts_vdf(&shortcuts_vdf_path, shortcuts_data) {
+    return Err(String::from("Failed to write shortcuts.vdf with cleared icons."));
+  }
+
+  logger::log_tagged_to_core_file(&app_handle, "main", format!("Cleared {} missing shortcut icons. The original was backed up to {}.", cleared, backup_path.display()).as_str(), 0);
+  return Ok(cleared);
+}
+
+/// Gets the part of a path after a base dir, if it's inside it. Separators are ignored, and so is case on windows.
+fn strip_base_path<'a>(path: &'a str, base: &str) -> Option<&'a str> {
+  let normalized_path: String = steam::normalize_path(path);
+  let normalized_base: String = steam::normalize_path(base).trim_end_matches('/').to_owned();
+
+  if normalized_base.is_empty() || normalized_path.len() <= normalized_base.len() || !normalized_path.is_char_boundary(normalized_base.len()) {
+    return None;
+  }
+
+  let (path_base, rest) = normalized_path.split_at(normalized_base.len());
+
+  if !steam::paths_match(path_base, &normalized_base) || !rest.starts_with('/') {
+    return None;
+  }
+
+  // ? Normalizing only swaps one byte separators, so the offset is the same in the original path.
+  return Some(&path[normalized_base.len() + 1..]);
+}
+
+#[tauri::command]
+/// Repoints shortcut icons inside old_base to the same files under new_base, for when the user moved their grids dir. Backs up shortcuts.vdf first.
+/// Returns the number of shortcuts that were updated.
+async fn rebase_shortcut_icons(app_handle: AppHandle, steam_active_user_id: String, old_base: String, new_base: String) -> usize {
+  let shortcuts_vdf_path: PathBuf = PathBuf::from(steam::get_shortcuts_path(app_handle.to_owned(), steam_active_user_id, None));
+
+  if !shortcuts_vdf_path.exists() {
+    logger::log_tagged_to_core_file(&app_handle, "main", "No shortcuts.vdf to rebase icons in.", 0);
+    return 0;
+  }
+
+  let new_base: String = steam::normalize_path(&new_base).trim_end_matches('/').to_owned();
+  let mut shortcuts_data: Value = read_shortcuts_data(&shortcuts_vdf_path);
+  let mut updated: usize = 0;
+
+  if let Some(shortcuts_map) = shortcuts_data.get_mut("shortcuts").and_then(| shortcuts | shortcuts.as_object_mut()) {
+    for (_, shortcut) in shortcuts_map.iter_mut() {
+      let icon: &str = get_shortcut_icon(shortcut);
+      // ? Steam quotes some paths, so the quotes are kept around the rebased one.
+      let is_quoted: bool = icon.len() > 1 && icon.starts_with('"') && icon.ends_with('"');
+
+      let rebased_icon: String = match strip_base_path(icon.trim_matches('"'), &old_base) {
+        Some(relative_path) if is_quoted => format!("\"{}/{}\"", new_base, steam::normalize_path(relative_path)),
+        Some(relative_path) => format!("{}/{}", new_base, steam::normalize_path(relative_path)),
+        None => continue
+      };
+
+      if let Some(shortcut_map) = shortcut.as_object_mut() {
+        shortcut_map.insert(String::from("icon"), Value::String(rebased_icon));
+        updated += 1;
+      }
+    }
+  }
+
+  if updated == 0 {
+    logger::log_tagged_to_core_file(&app_handle, "main", format!("No shortcut icons were in {}.", old_base).as_str(), 0);
+    return 0;
+  }
+
+  let backup_path: PathBuf = shortcuts_vdf_path.with_extension("vdf.bak");
+
+  if let Err(err) = fs::copy(&shortcuts_vdf_path, &backup_path) {
+    logger::log_tagged_to_core_file(&app_handle, "main", format!("Failed to back up shortcuts.vdf, so icons weren't rebased: {}", err).as_str(), 2);
+    return 0;
+  }
+
+  if !write_shortcuts_vdf(&shortcuts_vdf_path, shortcuts_data) {
+    logger::log_tagged_to_core_file(&app_handle, "main", "Failed to write shortcuts.vdf with rebased icons.", 2);
+    return 0;
+  }
+
+  logger::log_tagged_to_core_file(&app_handle, "main", format!("Rebased {} shortcut icons from {} to {}. The original was backed up to {}.", updated, old_base, new_base, backup_path.display()).as_str(), 0);
+  return updated;
+}
+
+#[tauri::command]
+/// Renames a grid to the file name of another grid type, for art that was saved with the wrong suffix. The appid and extension stay the same.
+/// Shortcut icons pointing at the grid are updated to the new path, backing up shortcuts.vdf first. Returns the new path, or an empty string if it couldn't be renamed.
+async fn retag_grid(app_handle: AppHandle, steam_active_user_id: String, path: String, new_grid_type: String) -> String {
+  let grid_path: PathBuf = PathBuf::from(&path);
+  let grid_types: GridTypeTable = get_grid_type_table(&app_handle);
+
+  if !is_known_grid_type(&grid_types, &new_grid_type) {
+    logger::log_tagged_to_core_file(&app_handle, "main", format!("Can't retag {} as unknown grid type {}.", path, new_grid_type).as_str(), 2);
+    return String::new();
+  }
+
+  let filename: &str = grid_path.file_name().and_then(| name | name.to_str()).unwrap_or("");
+
+  let (appid, grid_type) = match parse_grid_filename(&grid_types, filename) {
+    Some(parsed) => parsed,
+    None => {
+      logger::log_tagged_to_core_file(&app_handle, "main", format!("Can't retag {}, its name isn't a grid for a known appid.", path).as_str(), 2);
+      return String::new();
+    }
+  };
+
+  if grid_type == new_grid_type {
+    logger::log_tagged_to_core_file(&app_handle, "main", format!("{} is already a {}.", path, new_grid_type).as_str(), 0);
+    return path;
+  }
+
+  let extension: &str = &filename[filename.rfind(".").expect("Grid should have had a file extension.")..];
+  let new_filename: String = get_grid_filename(&grid_types, &appid, &new_grid_type, extension).expect("Grid type should have been in the grid type table.");
+  let new_path: PathBuf = grid_path.with_file_name(&new_filename);
+
+  if new_path.exists() {
+    logger::log_tagged_to_core_file(&app_handle, "main", format!("Can't retag {}, {} already exists.", path, new_path.display()).as_str(), 2);
+    return String::new();
+  }
+
+  if let Err(err) = fs::rename(&grid_path, &new_path) {
+    logger::log_tagged_to_core_file(&app_handle, "main", format!("Failed to rename {} to {}: {}", path, new_path.display(), err).as_str(), 2);
+    return String::new();
+  }
+
+  let new_path_str: String = steam::path_to_string(&new_path);
+  logger::log_tagged_to_core_file(&app_handle, "main", format!("Retagged {} from {} to {} as {}.", appid, grid_type, new_grid_type, new_path_str).as_str(), 0);
+
+  let shortcuts_vdf_path: PathBuf = PathBuf::from(steam::get_shortcuts_path(app_handle.to_owned(), steam_active_user_id, None));
+
+  if !shortcuts_vdf_path.exists() {
+    return new_path_str;
+  }
+
+  let mut shortcuts_data: Value = read_shortcuts_data(&shortcuts_vdf_path);
+  let mut updated: usize = 0;
+
+  if let Some(shortcuts_map) = shortcuts_data.get_mut("shortcuts").and_then(| shortcuts | shortcuts.as_object_mut()) {
+    for (_, shortcut) in shortcuts_map.iter_mut() {
+      let icon: &str = get_shortcut_icon(shortcut);
+
+      if icon.is_empty() || !steam::paths_match(icon.trim_matches('"'), &path) {
+        continue;
+      }
+
+      // ? Steam quotes some paths, so the quotes are kept around the new one.
+      let is_quoted: bool = icon.len() > 1 && icon.starts_with('"') && icon.ends_with('"');
+      let new_icon: String = if is_quoted { format!("\"{}\"", new_path_str) } else { new_path_str.to_owned() };
+
+      if let Some(shortcut_map) = shortcut.as_object_mut() {
+        shortcut_map.insert(String::from("icon"), Value::String(new_icon));
+        updated += 1;
+      }
+    }
+  }
+
+  if updated == 0 {
+    return new_path_str;
+  }
+
+  if let Err(err) = fs::copy(&shortcuts_vdf_path, shortcuts_vdf_path.with_extension("vdf.bak")) {
+    logger::log_tagged_to_core_file(&app_handle, "main", format!("Failed to back up shortcuts.vdf, so icons still point to {}: {}", path, err).as_str(), 2);
+    return new_path_str;
+  }
+
+  if write_shortcuts_vdf(&shortcuts_vdf_path, shortcuts_data) {
+    logger::log_tagged_to_core_file(&app_handle, "main", format!("Updated {} shortcut icons to {}.", updated, new_path_str).as_str(), 0);
+  } else {
+    logger::log_tagged_to_core_file(&app_handle, "main", format!("Failed to write shortcuts.vdf, so icons still point to {}.", path).as_str(), 2);
+  }
+
+  return new_path_str;
+}
+
+/// The largest download allowed when the user hasn't configured one, in megabytes.
+const DEFAULT_MAX_DOWNLOAD_SIZE_MB: u64 = 50;
+
+/// Gets the largest download allowed, in bytes.
+fn get_max_download_size(app_handle: &AppHandle) -> u64 {
+  let max_size_mb: u64 = settings::get_setting(app_handle, "maxDownloadSizeMB")
+    .and_then(| value | value.as_u64())
+    .filter(| value | *value > 0)
+    .unwrap_or(DEFAULT_MAX_DOWNLOAD_SIZE_MB);
+
+  return max_size_mb.saturating_mul(1024 * 1024);
+}
+
+/// Gets the temporary path a grid is downloaded to before being moved into place.
+fn get_partial_download_path(dest_path: &str) -> String {
+  return format!("{}.part", dest_path);
+}
+
+/// Downloads a grid to a temporary file, moving it to the destination once complete.
+async fn fetch_grid_to_file(app_handle: &AppHandle, http_client: &Client, grid_url: &str, dest_path: &str) -> String {
+  let cached_download: Option<download_cache::CachedDownload> = download_cache::get_cached_download(app_handle, grid_url, dest_path);
+  let mut request = http_client.get(grid_url);
+
+  if let Some(cached_download) = cached_download {
+    if let Some(etag) = cached_download.etag {
+      request = request.header(reqwest::header::IF_NONE_MATCH, etag);
+    }
+    if let Some(last_modified) = cached_download.lastModified {
+      request = request.header(reqwest::header::IF_MODIFIED_SINCE, last_modified);
+    }
+  }
+
+  let mut response = match request.send().await {
+    Ok(response) => response,
+    Err(err) => {
+      logger::log_tagged_to_core_file(app_handle, "main", format!("Request for {} failed with {}.", grid_url, err).as_str(), 2);
+      return String::from("failed");
+    }
+  };
+
+  if response.status() == reqwest::StatusCode::NOT_MODIFIED {
+    logger::log_tagged_to_core_file(app_handle, "main", format!("{} wasn't modified. Reusing cached copy.", grid_url).as_str(), 0);
+    return String::from("cached");
+  }
+
+  let max_download_size: u64 = get_max_download_size(app_handle);
+
+  if response.content_length().is_some_and(| content_length | content_length > max_download_size) {
+    logger::log_tagged_to_core_file(app_handle, "main", format!("{} is larger than the {} byte download limit. Skipping...", grid_url, max_download_size).as_str(), 1);
+    return String::from("tooLarge");
+  }
+
+  let get_header = | name: reqwest::header::HeaderName | response.headers().get(name).and_then(| value | value.to_str().ok()).map(| value | value.to_owned());
+  let etag: Option<String> = get_header(reqwest::header::ETAG);
+  let last_modified: Option<String> = get_header(reqwest::header::LAST_MODIFIED);
+
+  let partial_path: String = get_partial_download_path(dest_path);
+  let mut partial_file: File = File::create(&partial_path).expect("Dest path should have existed.");
+  let mut write_res: Result<(), String> = Ok(());
+  let mut downloaded_bytes: u64 = 0;
+
+  // ? Writing chunk by chunk lets a cancelled download stop partway through instead of after the whole body arrives.
+  loop {
+    match response.chunk().await {
+      Ok(Some(chunk)) => {
+        // ? Content-Length can be missing or wrong, so the limit is enforced on what actually arrives too.
+        downloaded_bytes += chunk.len() as u64;
+        if downloaded_bytes > max_download_size {
+          drop(partial_file);
+          let _ = fs::remove_file(&partial_path);
+          logger::log_tagged_to_core_file(app_handle, "main", format!("{} exceeded the {} byte download limit. Aborted.", grid_url, max_download_size).as_str(), 1);
+          return String::from("tooLarge");
+        }
+
+        if let Err(err) = partial_file.write_all(&chunk) {
+          write_res = Err(err.to_string());
+          break;
+        }
+      },
+      Ok(None) => break,
+      Err(err) => {
+        write_res = Err(err.to_string());
+        break;
+      }
+    }
+  }
+
+  drop(partial_file);
+
+  if write_res.is_ok() {
+    write_res = fs::rename(&partial_path, dest_path).map_err(| err | err.to_string());
+  }
+
+  if write_res.is_ok() {
+    logger::log_tagged_to_core_file(app_handle, "main", format!("Download of {} finished.", grid_url).as_str(), 0);
+
+    if etag.is_some() || last_modified.is_some() {
+      download_cache::set_cached_download(app_handle, grid_url, download_cache::CachedDownload { etag, lastModified: last_modified, destPath: dest_path.to_owned() });
+    }
+
+    return String::from("success");
+  } else {
+    let err = write_res.err().expect("Request failed, error should have existed.");
+    let _ = fs::remove_file(&partial_path);
+    logger::log_tagged_to_core_file(app_handle, "main", format!("Download of {} failed with {}.", grid_url, err).as_str(), 0);
+    return String::from("failed");
+  }
+}
+
+#[tauri::command]
+/// Requests a url once and reports what came back, without saving anything. Bodies are still capped at the max download size.
+async fn test_download(app_handle: AppHandle, url: String, timeout: u64) -> DownloadTestResult {
+  let http_client: Client = reqwest::Client::builder().timeout(Duration::from_secs(timeout)).build().expect("Should have been able to successfully make the reqwest client.");
+  let mut result: DownloadTestResult = DownloadTestResult { status: None, contentType: None, bytes: 0, isImage: false, detectedFormat: None, error: None };
+
+  let mut response = match http_client.get(&url).send().await {
+    Ok(response) => response,
+    Err(err) => {
+      logger::log_tagged_to_core_file(&app_handle, "main", format!("Test request for {} failed with {}.", url, err).as_str(), 1);
+      result.error = Some(err.to_string());
+      return result;
+    }
+  };
+
+  result.status = Some(response.status().as_u16());
+  result.contentType = response.headers().get(reqwest::header::CONTENT_TYPE).and_then(| value | value.to_str().ok()).map(| value | value.to_owned());
+
+  let max_download_size: u64 = get_max_download_size(&app_handle);
+  let mut body: Vec<u8> = Vec::new();
+
+  loop {
+    match response.chunk().await {
+      Ok(Some(chunk)) => {
+        result.bytes += chunk.len() as u64;
+
+        if result.bytes > max_download_size {
+          result.error = Some(format!("The body is larger than the {} byte download limit.", max_download_size));
+          break;
+        }
+
+        body.extend_from_slice(&chunk);
+      },
+      Ok(None) => break,
+      Err(err) => {
+        result.error = Some(err.to_string());
+        break;
+      }
+    }
+  }
+
+  if result.error.is_none() {
+    result.detectedFormat = grid_images::sniff_image_bytes(&body).map(| extension | extension.trim_start_matches('.').to_owned());
+    // ? Matching magic bytes doesn't mean the rest of the image is intact, so it also has to decode.
+    result.isImage = result.detectedFormat.is_some() && image::load_from_memory(&body).is_ok();
+  }
+
+  logger::log_tagged_to_core_file(&app_handle, "main", format!("Tested {}: status {:?}, {} bytes, image: {}.", url, result.status, result.bytes, result.isImage).as_str(), 0);
+  return result;
+}
+
+#[tauri::command]
+/// Downloads a file from a url. Downloads with a job id can be cancelled with cancel_download.
+async fn download_grid(app_handle: AppHandle, grid_url: String, dest_path: String, timeout: u64, job_id: Option<String>) -> String {
+  logger::log_tagged_to_core_file(&app_handle, "main", format!("Downloading grid from {} to {}", grid_url, dest_path).as_str(), 0);
+  
+  let http_client_res = reqwest::Client::builder().timeout(Duration::from_secs(timeout)).build();
+  let http_client: Client = http_client_res.expect("Should have been able to successfully make the reqwest client.");
+
+  if job_id.is_none() {
+    return fetch_grid_to_file(&app_handle, &http_client, &grid_url, &dest_path).await;
+  }
+
+  let job_id: String = job_id.unwrap();
+  let cancel_notify = download_jobs::register_download_job(&app_handle, &job_id);
+
+  let status: String = tokio::select! {
+    status = fetch_grid_to_file(&app_handle, &http_client, &grid_url, &dest_path) => status,
+    _ = cancel_notify.notified() => {
+      let _ = fs::remove_file(get_partial_download_path(&dest_path));
+      logger::log_tagged_to_core_file(&app_handle, "main", format!("Download of {} was cancelled.", grid_url).as_str(), 0);
+      String::from("cancelled")
+    }
+  };
+
+  download_jobs::finish_download_job(&app_handle, &job_id);
+
+  return status;
+}
+
+/// Downloads an image for a url import to the staging dir, naming it with the extension matching its contents.
+async fn stage_url_import(app_handle: &AppHandle, http_client: &Client, staging_dir: &PathBuf, index: usize, job: &UrlImportJob) -> Result<String, String> {
+  if !is_known_grid_type(&get_grid_type_table(app_handle), &job.gridType) {
+    return Err(format!("Unknown grid type {}.", job.gridType));
+  }
+  if job.appId.is_empty() || !job.appId.chars().all(| c | c.is_ascii_digit()) {
+    return Err(format!("Invalid appid {}.", job.appId));
+  }
+
+  let download_path: PathBuf = staging_dir.join(format!("{}.download", index));
+  let download_path_str: String = download_path.to_str().expect("Should have been able to convert path to string.").to_owned();
+  let status: String = fetch_grid_to_file(app_handle, http_client, &job.url, &download_path_str).await;
+
+  if status != "success" && status != "cached" {
+    return Err(format!("Failed to download {}.", job.url));
+  }
+
+  let extension: &str = match grid_images::sniff_image_extension(&download_path) {
+    Some(extension) => extension,
+    None => {
+      let _ = fs::remove_file(&download_path);
+      return Err(format!("{} isn't a supported image.", job.url));
+    }
+  };
+
+  let staged_path: PathBuf = staging_dir.join(format!("{}{}", index, extension));
+  fs::rename(&download_path, &staged_path).map_err(| err | err.to_string())?;
+
+  return Ok(steam::path_to_string(&staged_path));
+}
+
+/// Gets the name steam gives a grid type's art in its library cache.
+fn get_library_cache_art_name(grid_type: &str) -> Option<&'static str> {
+  return match grid_type {
+    "Capsule" => Some("library_600x900"),
+    "Wide Capsule" => Some("header"),
+    "Hero" => Some("library_hero"),
+    "Logo" => Some("logo"),
+    "Icon" => Some("icon"),
+    _ => None
+  };
+}
+
+/// Finds the first image in a dir whose name matches, ignoring its extension.
+fn find_image_named(dir: &PathBuf, name: &str) -> Option<PathBuf> {
+  let mut dir_contents: Vec<PathBuf> = fs::read_dir(dir).ok()?.flatten().map(| entry | entry.path()).collect();
+  dir_contents.sort();
+
+  return dir_contents.into_iter().find(| path | {
+    let filename: &str = path.file_name().and_then(| name | name.to_str()).unwrap_or("");
+    let extension: String = filename.rfind(".").map(| index | filename[index..].to_lowercase()).unwrap_or_default();
+
+    return path.is_file() && path.file_stem().and_then(| stem | stem.to_str()) == Some(name) && GRID_IMAGE_EXTENSIONS.contains(&extension.as_str());
+  });
+}
+
+/// Finds an app's art in steam's library caches, each paired with its layout from steam::library_cache_layout. Flat caches store it as appid_name.ext at the top level,
+/// while nested ones put it in an appid folder, sometimes nested one level further in a hashed folder.
+fn find_library_cache_art(cache_dirs: &Vec<(PathBuf, &str)>, appid: &str, art_name: &str) -> Option<PathBuf> {
+  for (cache_dir, layout) in cache_dirs.iter() {
+    if *layout == "flat" || *layout == "mixed" {
+      if let Some(flat_path) = find_image_named(cache_dir, &format!("{}_{}", appid, art_name)) {
+        return Some(flat_path);
+      }
+    }
+
+    let app_dir: PathBuf = cache_dir.join(appid);
+
+    if (*layout != "nested" && *layout != "mixed") || !app_dir.is_dir() {
+      continue;
+    }
+
+    if let Some(nested_path) = find_image_named(&app_dir, art_name) {
+      return Some(nested_path);
+    }
+
+    let mut sub_dirs: Vec<PathBuf> = fs::read_dir(&app_dir).into_iter().flatten().flatten().map(| entry | entry.path()).filter(| path | path.is_dir()).collect();
+    sub_dirs.sort();
+
+    if let Some(sub_dir_path) = sub_dirs.iter().find_map(| sub_dir | find_image_named(sub_dir, art_name)) {
+      return Some(sub_dir_path);
+    }
+  }
+
+  return None;
+}
+
+#[tauri::command]
+/// Copies an app's art from steam's library cache into the user's grids dir, so steam's own art becomes a managed grid. Returns the grid types that were applied.
+async fn apply_default_art(app_handle: AppHandle, steam_active_user_id: String, appid: String, grid_types: Vec<String>) -> Vec<String> {
+  let grids_dir: PathBuf = PathBuf::from(steam::get_grids_directory(app_handle.to_owned(), steam_active_user_id, None));
+  let cache_dirs: Vec<(PathBuf, &str)> = steam::get_library_cache_dirs(None).into_iter()
+    .map(| cache_dir | {
+      let layout: &str = steam::library_cache_layout(std::slice::from_ref(&cache_dir));
+      return (cache_dir, layout);
+    })
+    .collect();
+  let mut default_art: HashMap<String, String> = HashMap::new();
+
+  for grid_type in grid_types.iter() {
+    let cached_art: Option<PathBuf> = get_library_cache_art_name(grid_type).and_then(| art_name | find_library_cache_art(&cache_dirs, &appid, art_name));
+
+    match cached_art {
+      Some(cached_art) => {
+        default_art.insert(grid_type.to_owned(), steam::path_to_string(&cached_art));
+      },
+      None => logger::log_tagged_to_core_file(&app_handle, "main", format!("No {} art for {} in the library cache.", grid_type, appid).as_str(), 1)
+    }
+  }
+
+  let mut new_grids: GridImageCache = HashMap::new();
+  new_grids.insert(appid.to_owned(), default_art);
+
+  let current_grids: GridImageCache = get_current_grids(&app_handle, &grids_dir);
+  let changed_paths: Vec<ChangedPath> = filter_paths(&app_handle, &grids_dir, &new_grids, &current_grids);
+
+  if !changed_paths.is_empty() {
+    backups::create_backup(&app_handle, &grids_dir, &get_changed_grid_files(&changed_paths));
+  }
+
+  let summary: SaveSummary = apply_changed_paths(&app_handle, &changed_paths);
+  let applied_types: Vec<String> = summary.changedPaths.iter().map(| changed_path | changed_path.gridType.to_owned()).collect();
+
+  logger::log_tagged_to_core_file(&app_handle, "main", format!("Applied default {} art for {}.", applied_types.join(", "), appid).as_str(), 0);
+  return applied_types;
 }
 
 #[tauri::command]
-/// Exports the users grids to a Grids zip file.
-async fn export_grids_to_zip(app_handle: AppHandle, steam_active_user_id: String, platform_id_map: Map<String, Value>, id_name_map: Map<String, Value>) -> bool {
-  let file_dialog = FileDialogBuilder::new()
-    .set_title("Save Grids Zip")
-    .set_file_name("Steam_Grids_Export.zip")
-    .add_filter("zip", &["zip"])
-    .set_directory(home_dir().expect("Should have been able to get home dir for zip."));
-
-  let file_path = file_dialog.save_file();
+/// Downloads grids from a list of urls and saves them for their apps, optionally pointing shortcut icons at them. Returns the result of each job.
+async fn import_grids_from_urls(app_handle: AppHandle, steam_active_user_id: String, jobs: Vec<UrlImportJob>, timeout: u64, update_shortcut_icons: Option<bool>) -> Vec<UrlImportResult> {
+  logger::log_tagged_to_core_file(&app_handle, "main", format!("Importing {} grids from urls...", jobs.len()).as_str(), 0);
 
-  if file_path.is_some() {
-    let zip_path = file_path.unwrap();
-    logger::log_to_core_file(app_handle.to_owned(), format!("Got save path: {}", zip_path.to_str().expect("Should have been able to convert path to string.")).as_str(), 0);
+  let grids_dir: PathBuf = PathBuf::from(steam::get_grids_directory(app_handle.to_owned(), steam_active_user_id.clone(), None));
+  let staging_dir: PathBuf = download_cache::get_url_import_staging_dir(&app_handle);
+  let http_client: Client = reqwest::Client::builder().timeout(Duration::from_secs(timeout)).build().expect("Should have been able to successfully make the reqwest client.");
 
-    let grids_dir_path = steam::get_grids_directory(app_handle.to_owned(), steam_active_user_id);
-    let succeeded = zip_controller::generate_grids_zip(&app_handle, PathBuf::from(grids_dir_path), zip_path, &platform_id_map, &id_name_map);
+  let mut results: Vec<UrlImportResult> = jobs.iter().map(| job | UrlImportResult { appId: job.appId.to_owned(), gridType: job.gridType.to_owned(), success: false, path: None, error: None }).collect();
 
-    if succeeded {
-      logger::log_to_core_file(app_handle.to_owned(), "Successfully saved the user's grids.", 0);
-      return true;
-    } else {
-      logger::log_to_core_file(app_handle.to_owned(), "Failed to save the user's grids.", 0);
-      return false;
+  if !steam::ensure_directory_exists(&app_handle, &staging_dir) {
+    for result in results.iter_mut() {
+      result.error = Some(format!("Failed to create {}", staging_dir.display()));
     }
-  } else {
-    logger::log_to_core_file(app_handle.to_owned(), "No save location was chosen.", 0);
-    return false;
+    return results;
   }
-}
 
-#[tauri::command]
-/// Sets the users grids from a Grids zip file.
-async fn import_grids_from_zip(app_handle: AppHandle, steam_active_user_id: String, name_id_map: Map<String, Value>) -> (bool, Map<String, Value>) {
-  let file_dialog = FileDialogBuilder::new()
-    .set_title("Pick a Grids Zip")
-    .add_filter("zip", &["zip"])
-    .set_directory(home_dir().expect("Should have been able to get home dir for zip."));
+  let mut staged_grids: GridImageCache = HashMap::new();
+  let max_download_size: u64 = get_max_download_size(&app_handle);
 
-  let file_path = file_dialog.pick_file();
+  for (index, job) in jobs.iter().enumerate() {
+    // ? Each download can be up to the max size, and needs room both while staged and once copied to the grids dir.
+    let space_res: Result<(), String> = disk_space::ensure_free_space(&staging_dir, max_download_size).and_then(| _ | disk_space::ensure_free_space(&grids_dir, max_download_size));
 
-  if file_path.is_some() {
-    let zip_path = file_path.unwrap();
-    logger::log_to_core_file(app_handle.to_owned(), format!("Got file path: {}", zip_path.to_str().expect("Should have been able to convert path to string.")).as_str(), 0);
+    if let Err(err) = space_res {
+      logger::log_tagged_to_core_file(&app_handle, "main", format!("Stopping url import: {}", err).as_str(), 2);
 
-    let grids_dir_path = steam::get_grids_directory(app_handle.to_owned(), steam_active_user_id);
-    let (success, icon_map) = zip_controller::set_grids_from_zip(&app_handle, PathBuf::from(grids_dir_path), zip_path, &name_id_map);
+      for result in results.iter_mut().skip(index) {
+        result.error = Some(err.to_owned());
+      }
+      break;
+    }
 
-    if success {
-      logger::log_to_core_file(app_handle.to_owned(), "Successfully set the user's grids.", 0);
-      return (success, icon_map);
-    } else {
-      logger::log_to_core_file(app_handle.to_owned(), "Failed to set the user's grids.", 0);
-      return (success, icon_map);
+    match stage_url_import(&app_handle, &http_client, &staging_dir, index, job).await {
+      Ok(staged_path) => {
+        staged_grids.entry(job.appId.to_owned()).or_default().insert(job.gridType.to_owned(), staged_path);
+      },
+      Err(err) => {
+        logger::log_tagged_to_core_file(&app_handle, "main", format!("Failed to import {} for {}: {}", job.gridType, job.appId, err).as_str(), 2);
+        results[index].error = Some(err);
+      }
     }
-  } else {
-    logger::log_to_core_file(app_handle.to_owned(), "No zip file was selected by user.", 0);
-    return (false, Map::new());
   }
-}
-
-#[tauri::command]
-/// Reads the user's appinfo.vdf file.
-async fn read_appinfo_vdf(app_handle: AppHandle) -> String {
-  let appinfo_path: PathBuf = PathBuf::from(steam::get_appinfo_path(app_handle.to_owned()));
-  let appinfo_vdf: Map<String, Value> = open_appinfo_vdf(&appinfo_path);
-  return serde_json::to_string(&appinfo_vdf).expect("Should have been able to serialize AppInfo vdf to string.");
-}
 
-#[tauri::command]
-/// Reads the user's shortcuts.vdf file.
-async fn read_shortcuts_vdf(app_handle: AppHandle, steam_active_user_id: String) -> String {
-  let shortcuts_path = PathBuf::from(steam::get_shortcuts_path(app_handle.to_owned(), steam_active_user_id));
-    
-  if shortcuts_path.as_path().exists() {
-    logger::log_to_core_file(app_handle.to_owned(), "shortcuts.vdf exists, reading...", 0);
-    let shortcuts_array = open_shortcuts_vdf(&shortcuts_path);
-    return serde_json::to_string(&shortcuts_array).expect("Should have been able to serialize Shortcuts vdf to string.");
-  } else {
-    logger::log_to_core_file(app_handle.to_owned(), "shortcuts.vdf does not exist.", 0);
-    return "{}".to_owned();
+  // ? Staged grids go through the same path as saving, so old grids are removed and the new ones optimized.
+  let changed_paths: Vec<ChangedPath> = filter_paths(&app_handle, &grids_dir, &staged_grids, &get_current_grids(&app_handle, &grids_dir));
+  if !changed_paths.is_empty() {
+    backups::create_backup(&app_handle, &grids_dir, &get_changed_grid_files(&changed_paths));
   }
-}
 
-#[tauri::command]
-/// Reads the user's localconfig.vdf file.
-async fn read_localconfig_vdf(app_handle: AppHandle, steam_active_user_id: String) -> String {
-  let localconfig_path = PathBuf::from(steam::get_localconfig_path(app_handle.to_owned(), steam_active_user_id));
-    
-  if localconfig_path.as_path().exists() {
-    logger::log_to_core_file(app_handle.to_owned(), "localconfig.vdf exists, reading...", 0);
-    let localconfig_contents: String = fs::read_to_string(localconfig_path).expect("localconfig.vdf should have existed.").parse().expect("File should have been a text file.");
-    let vdf = Vdf::parse(&localconfig_contents).unwrap();
-    let software = vdf.value.get_obj().unwrap().get_key_value("Software").unwrap();
-    let valve = software.1[0].get_obj().unwrap().get_key_value("Valve").unwrap();
-    let steam = valve.1[0].get_obj().unwrap().get_key_value("Steam").unwrap();
-    let apps = steam.1[0].get_obj().unwrap().get_key_value("apps").unwrap();
+  let summary: SaveSummary = apply_changed_paths(&app_handle, &changed_paths);
 
-    let app_entries = &apps.1[0];
-    let mut appids: Vec<String> = Vec::new();
+  if update_shortcut_icons.unwrap_or(true) {
+    update_changed_shortcut_icons(&app_handle, steam_active_user_id, &summary.changedPaths);
+  }
 
-    for (_, appid) in app_entries.get_obj().unwrap().keys().enumerate() {
-      appids.push(appid.to_string());
-    }
+  for result in results.iter_mut().filter(| result | result.error.is_none()) {
+    let staged_path: Option<&String> = get_cached_grid_path(&staged_grids, &result.appId, &result.gridType);
+    let changed_path: Option<&ChangedPath> = summary.changedPaths.iter().find(| changed_path | staged_path.is_some_and(| staged_path | steam::paths_match(&changed_path.sourcePath, staged_path)));
 
-    return serde_json::to_string(&appids).expect("Should have been able to serialize localconfig vdf to string.");
-  } else {
-    logger::log_to_core_file(app_handle.to_owned(), "localconfig.vdf does not exist.", 0);
-    return "{}".to_owned();
+    if let Some(changed_path) = changed_path {
+      result.success = true;
+      result.path = Some(changed_path.targetPath.to_owned());
+    } else if let Some(save_error) = summary.errors.iter().find(| save_error | save_error.appId == result.appId && save_error.gridType == result.gridType) {
+      result.error = Some(save_error.error.to_owned());
+    } else {
+      result.error = Some(String::from("Replaced by a later job for the same grid."));
+    }
   }
+
+  let _ = fs::remove_dir_all(&staging_dir);
+
+  logger::log_tagged_to_core_file(&app_handle, "main", format!("Imported {} of {} grids from urls.", results.iter().filter(| result | result.success).count(), results.len()).as_str(), 0);
+  return results;
 }
 
 #[tauri::command]
-/// Applies the changes the user has made.
-async fn save_changes(app_handle: AppHandle, steam_active_user_id: String, current_art: String, original_art: String, shortcuts_str: String, shortcut_icons: Map<String, Value>, original_shortcut_icons: Map<String, Value>, changed_logo_positions: Map<String, Value>) -> String {
-  let current_art_dict: GridImageCache = serde_json::from_str(current_art.as_str()).unwrap();
-  let original_art_dict: GridImageCache = serde_json::from_str(original_art.as_str()).unwrap();
+/// Fills an app's empty grid slots with the top art from a source, either "steamgriddb" or one with url templates like "steam".
+/// Slots that already have custom art are skipped unless force is true. Returns the result of each slot that was filled or attempted.
+async fn autofill_game_art(app_handle: AppHandle, steam_active_user_id: String, appid: String, source: String, timeout: u64, force: Option<bool>) -> Vec<UrlImportResult> {
+  let grids_dir: PathBuf = PathBuf::from(steam::get_grids_directory(app_handle.to_owned(), steam_active_user_id.clone(), None));
+  let current_grids: GridImageCache = get_current_grids(&app_handle, &grids_dir);
+  let force: bool = force.unwrap_or(false);
+
+  let grid_types: Vec<&str> = DEFAULT_GRID_TYPE_SUFFIXES.iter()
+    .map(| (grid_type, _) | *grid_type)
+    .filter(| grid_type | force || get_cached_grid_path(&current_grids, &appid, grid_type).is_none())
+    .collect();
+
+  if grid_types.is_empty() {
+    logger::log_tagged_to_core_file(&app_handle, "main", format!("{} already has art in every slot. Nothing to autofill.", appid).as_str(), 0);
+    return Vec::new();
+  }
 
-  logger::log_to_core_file(app_handle.to_owned(), "Converting current path entries to grid paths...", 0);
-  let paths_to_set: Vec<ChangedPath> = filter_paths(&app_handle, steam_active_user_id.clone(), &current_art_dict, &original_art_dict);
-  let paths_id_map: HashMap<String, ChangedPath> = paths_to_set.clone().iter().map(| entry | (format!("{}_{}", entry.appId.to_owned(), entry.gridType.to_owned()).to_string(), entry.to_owned())).collect();
-  logger::log_to_core_file(app_handle.to_owned(), "Current path entries converted to grid paths.", 0);
+  let steamgriddb_key: String = settings::get_setting(&app_handle, "steamGridDbApiKey").and_then(| key | key.as_str().map(| key | key.to_owned())).unwrap_or_default();
+  let steamgriddb_client: Client = steamgriddb::build_client();
 
-  for changed_path in (&paths_to_set).into_iter() {
-    let source = changed_path.sourcePath.to_owned();
-    let target = changed_path.targetPath.to_owned();
+  let mut jobs: Vec<UrlImportJob> = Vec::new();
+  let mut unavailable: Vec<UrlImportResult> = Vec::new();
 
-    if target == String::from("REMOVE") {
-      if changed_path.oldPath.contains("grid") {
-        let remove_res = fs::remove_file(changed_path.oldPath.to_owned());
-        if remove_res.is_err() {
-          let err = remove_res.err().unwrap();
-          return format!("{{ \"error\": \"{}\"}}", err.to_string());
-        }
-        logger::log_to_core_file(app_handle.to_owned(), format!("Removed grid {}.", changed_path.oldPath.to_owned()).as_str(), 0);
+  for grid_type in grid_types.into_iter() {
+    let url_res: Result<String, String> = if source == "steamgriddb" {
+      if steamgriddb_key.trim().is_empty() {
+        Err(String::from("No SteamGridDB API key is set."))
+      } else {
+        steamgriddb::find_top_steam_art_url(&steamgriddb_client, &steamgriddb_key, &appid, grid_type).await
       }
     } else {
-      if changed_path.oldPath.contains("grid") {
-        let remove_res = fs::remove_file(changed_path.oldPath.to_owned());
-        if remove_res.is_err() {
-          let err = remove_res.err().unwrap();
-          return format!("{{ \"error\": \"{}\"}}", err.to_string());
-        }
-      }
-  
-      fs::File::create(target.clone()).unwrap();
-      
-      let copy_res = fs::copy(source.clone(), target.clone());
-  
-      if copy_res.is_ok() {
-        logger::log_to_core_file(app_handle.to_owned(), format!("Copied {} to {}.", source, target).as_str(), 0);
-      } else {
-        logger::log_to_core_file(app_handle.to_owned(), format!("Failed to copy {} to {}.", source, target).as_str(), 2);
-        let err = copy_res.err().unwrap();
-        return format!("{{ \"error\": \"{}\"}}", err.to_string());
+      store_art::get_art_url(&app_handle, &source, &appid, grid_type)
+    };
+
+    match url_res {
+      Ok(url) => jobs.push(UrlImportJob { appId: appid.to_owned(), gridType: grid_type.to_owned(), url }),
+      Err(err) => {
+        logger::log_tagged_to_core_file(&app_handle, "main", format!("Can't autofill {} for {}: {}", grid_type, appid, err).as_str(), 1);
+        unavailable.push(UrlImportResult { appId: appid.to_owned(), gridType: grid_type.to_owned(), success: false, path: None, error: Some(err) });
       }
     }
   }
 
-  let grids_directory: PathBuf = PathBuf::from(steam::get_grids_directory(app_handle.to_owned(), steam_active_user_id.clone()));
-  for (appid, steam_logo_str_val) in changed_logo_positions.into_iter() {
-    let steam_logo_str: &str = steam_logo_str_val.as_str().expect("Should have been able to convert steamLogo pos into str.");
-    let logo_config_path: PathBuf = grids_directory.join(format!("{}.json", appid));
+  let mut results: Vec<UrlImportResult> = if jobs.is_empty() { Vec::new() } else { import_grids_from_urls(app_handle.to_owned(), steam_active_user_id, jobs, timeout, None).await };
+  results.append(&mut unavailable);
 
-    if steam_logo_str == "REMOVE" {
-      let remove_res = fs::remove_file(logo_config_path);
-      if remove_res.is_err() {
-        let err = remove_res.err().unwrap();
-        return format!("{{ \"error\": \"{}\"}}", err.to_string());
-      }
-      logger::log_to_core_file(app_handle.to_owned(), format!("Removed logo position config for {}.", appid).as_str(), 0);
-    } else {
-      let write_res = fs::write(&logo_config_path, steam_logo_str);
-  
-      if write_res.is_ok() {
-        logger::log_to_core_file(app_handle.to_owned(), format!("Wrote logo pos to config for {}.", appid).as_str(), 0);
-      } else {
-        logger::log_to_core_file(app_handle.to_owned(), format!("Failed to write logo pos to config for {}.", appid).as_str(), 2);
-        let err = write_res.err().unwrap();
-        return format!("{{ \"error\": \"{}\"}}", err.to_string());
+  logger::log_tagged_to_core_file(&app_handle, "main", format!("Autofilled {} slots for {} from {}.", results.iter().filter(| result | result.success).count(), appid, source).as_str(), 0);
+  return results;
+}
+
+/// Points any shortcut icons at renamed grids. Returns true if shortcuts.vdf was updated.
+fn update_renamed_shortcut_icons(app_handle: &AppHandle, steam_active_user_id: String, renames: &Vec<GridRename>) -> bool {
+  let shortcuts_vdf_path: PathBuf = PathBuf::from(steam::get_shortcuts_path(app_handle.to_owned(), steam_active_user_id, None));
+
+  if renames.is_empty() || !shortcuts_vdf_path.exists() {
+    return false;
+  }
+
+  let mut shortcuts_data: Value = read_shortcuts_data(&shortcuts_vdf_path);
+  let mut updated_icons: bool = false;
+
+  if let Some(shortcuts_map) = shortcuts_data.get_mut("shortcuts").and_then(| shortcuts | shortcuts.as_object_mut()) {
+    for (_, shortcut) in shortcuts_map.iter_mut() {
+      let icon: &str = shortcut.get("icon").and_then(| icon | icon.as_str()).unwrap_or("");
+      let rename = renames.iter().find(| rename | steam::paths_match(&rename.from, icon));
+
+      if rename.is_some() && shortcut.is_object() {
+        shortcut.as_object_mut().unwrap().insert(String::from("icon"), Value::String(rename.unwrap().to.to_owned()));
+        updated_icons = true;
       }
     }
   }
 
-  let should_change_shortcuts: bool = check_for_shortcut_changes(&shortcut_icons, &original_shortcut_icons);
-  
-  if should_change_shortcuts {
-    logger::log_to_core_file(app_handle.to_owned(), "Changes to shortcuts detected. Writing shortcuts.vdf...", 0);
-    let mut shortcuts_data: Value = serde_json::from_str(shortcuts_str.as_str()).expect("Should have been able to parse json string.");
+  if updated_icons {
+    if let Err(err) = try_write_shortcuts_vdf(&shortcuts_vdf_path, shortcuts_data) {
+      logger::log_tagged_to_core_file(app_handle, "main", format!("Failed to update shortcut icons pointing at renamed grids: {}", err).as_str(), 2);
+      return false;
+    }
 
-    let shortcuts_obj_map: &mut Value = shortcuts_data.get_mut("shortcuts").expect("key: shortcuts should have existed.");
-    let shortcuts_map: &mut Map<String, Value> = shortcuts_obj_map.as_object_mut().expect("Should have been able to convert shortcuts to map");
+    logger::log_tagged_to_core_file(app_handle, "main", "Updated shortcut icons pointing at renamed grids.", 0);
+  }
 
-    for (_, shortcut) in shortcuts_map.into_iter() {
-      let shortcut_map: &mut Map<String, Value> = shortcut.as_object_mut().expect("should have been able to convert shortcut to map.");
-      let shortcut_appid_val: &Value = shortcut_map.get("appid").expect("shortcut should have had an appid");
-      let shortcut_appid_num: i64 = shortcut_appid_val.as_i64().expect("should have been able to convert shortcut appid to str.");
-      let shortcut_appid: String = shortcut_appid_num.to_string();
+  return updated_icons;
+}
 
-      let path_key: String = format!("{}_icon", shortcut_appid.to_owned()).to_string();
+/// Clears any shortcut icons pointing at removed grids, so steam falls back to the exe's icon. Returns true if shortcuts.vdf was updated.
+fn clear_removed_shortcut_icons(app_handle: &AppHandle, steam_active_user_id: String, removed_paths: &Vec<String>) -> bool {
+  let shortcuts_vdf_path: PathBuf = PathBuf::from(steam::get_shortcuts_path(app_handle.to_owned(), steam_active_user_id, None));
 
-      if paths_id_map.contains_key(&path_key) {
-        let changed_path: &ChangedPath = paths_id_map.get(&path_key).expect("entry should have existed.");
-        shortcut_map.insert(String::from("icon"), Value::String(changed_path.targetPath.to_owned()));
-      }
-    }
+  if removed_paths.is_empty() || !shortcuts_vdf_path.exists() {
+    return false;
+  }
 
-    let mut modified_shortcuts_data: Map<String, Value> = Map::new();
-    modified_shortcuts_data.insert(String::from("shortcuts"), shortcuts_obj_map.to_owned());
-    shortcuts_data = Value::Object(modified_shortcuts_data);
+  let mut shortcuts_data: Value = read_shortcuts_data(&shortcuts_vdf_path);
+  let mut updated_icons: bool = false;
 
-    let shortcuts_vdf_path: PathBuf = PathBuf::from(steam::get_shortcuts_path(app_handle.to_owned(), steam_active_user_id));
-    write_shortcuts_vdf(&shortcuts_vdf_path, shortcuts_data);
-    logger::log_to_core_file(app_handle.to_owned(), "Changes to shortcuts saved.", 0);
-  } else {
-    logger::log_to_core_file(app_handle.to_owned(), "No changes to shortcuts detected. Skipping...", 0);
+  if let Some(shortcuts_map) = shortcuts_data.get_mut("shortcuts").and_then(| shortcuts | shortcuts.as_object_mut()) {
+    for (_, shortcut) in shortcuts_map.iter_mut() {
+      let icon: &str = shortcut.get("icon").and_then(| icon | icon.as_str()).unwrap_or("");
+      let was_removed: bool = !icon.is_empty() && removed_paths.iter().any(| removed_path | steam::paths_match(removed_path, icon));
+
+      if was_removed && shortcut.is_object() {
+        shortcut.as_object_mut().unwrap().insert(String::from("icon"), Value::String(String::new()));
+        updated_icons = true;
+      }
+    }
   }
 
-  let changed_res = serde_json::to_string::<Vec<ChangedPath>>(paths_to_set.as_ref());
+  if updated_icons {
+    if let Err(err) = try_write_shortcuts_vdf(&shortcuts_vdf_path, shortcuts_data) {
+      logger::log_tagged_to_core_file(app_handle, "main", format!("Failed to clear shortcut icons pointing at removed grids: {}", err).as_str(), 2);
+      return false;
+    }
 
-  if changed_res.is_ok() {
-    return changed_res.unwrap();
-  } else {
-    let err = changed_res.err().unwrap();
-    logger::log_to_core_file(app_handle, format!("{}", err.to_string()).as_str(), 2);
-    return String::from("[]");
+    logger::log_tagged_to_core_file(app_handle, "main", "Cleared shortcut icons pointing at removed grids.", 0);
   }
+
+  return updated_icons;
 }
 
 #[tauri::command]
-/// Writes the user's shortcuts.vdf file.
-async fn write_shortcuts(app_handle: AppHandle, steam_active_user_id: String, shortcuts_str: String) -> bool {
-  logger::log_to_core_file(app_handle.to_owned(), "Writing shortcuts.vdf...", 0);
-  let shortcuts_vdf_path: PathBuf = PathBuf::from(steam::get_shortcuts_path(app_handle.to_owned(), steam_active_user_id));
-  let shortcuts_data: Value = serde_json::from_str(shortcuts_str.as_str()).expect("Should have been able to parse json string.");
+/// Removes the grids of the provided types for each of the provided apps. An empty list of grid types removes all of them.
+/// Failures are reported and don't stop the rest from being removed.
+async fn remove_grids(app_handle: AppHandle, steam_active_user_id: String, appids: Vec<String>, grid_types: Vec<String>) -> GridRemoveResult {
+  let grids_dir: PathBuf = PathBuf::from(steam::get_grids_directory(app_handle.to_owned(), steam_active_user_id.clone(), None));
+  let current_grids: GridImageCache = get_current_grids(&app_handle, &grids_dir);
+
+  let grid_paths: Vec<String> = appids.iter()
+    .filter_map(| appid | current_grids.get(appid))
+    .flat_map(| app_grids | app_grids.iter())
+    .filter(| (grid_type, _) | grid_types.is_empty() || grid_types.contains(grid_type))
+    .map(| (_, grid_path) | grid_path.to_owned())
+    .collect();
+
+  let mut result: GridRemoveResult = GridRemoveResult { removed: Vec::new(), failed: Vec::new() };
+
+  if grid_paths.is_empty() {
+    return result;
+  }
 
-  let success: bool = write_shortcuts_vdf(&shortcuts_vdf_path, shortcuts_data);
+  backups::create_backup(&app_handle, &grids_dir, &grid_paths.iter().map(PathBuf::from).collect::<Vec<PathBuf>>());
 
-  if success {
-    logger::log_to_core_file(app_handle.to_owned(), "Changes to shortcuts saved.", 0);
-    return true;
-  } else {
-    logger::log_to_core_file(app_handle.to_owned(), "Changes to shortcuts failed.", 0);
-    return false;
+  for grid_path in grid_paths.into_iter() {
+    match fs::remove_file(&grid_path) {
+      Ok(()) => result.removed.push(grid_path),
+      Err(err) => {
+        logger::log_tagged_to_core_file(&app_handle, "main", format!("Failed to remove {}: {}", grid_path, err).as_str(), 2);
+        result.failed.push(GridRemoveFailure { path: grid_path, error: err.to_string() });
+      }
+    }
   }
+
+  clear_removed_shortcut_icons(&app_handle, steam_active_user_id, &result.removed);
+
+  logger::log_tagged_to_core_file(&app_handle, "main", format!("Removed {} grids. {} failed.", result.removed.len(), result.failed.len()).as_str(), 0);
+  return result;
 }
 
 #[tauri::command]
-/// Downloads a file from a url.
-async fn download_grid(app_handle: AppHandle, grid_url: String, dest_path: String, timeout: u64) -> String {
-  logger::log_to_core_file(app_handle.to_owned(), format!("Downloading grid from {} to {}", grid_url, dest_path).as_str(), 0);
-  
-  let http_client_res = reqwest::Client::builder().timeout(Duration::from_secs(timeout)).build();
-  let http_client: Client = http_client_res.expect("Should have been able to successfully make the reqwest client.");
+/// Renames grids whose extension doesn't match their actual image format, like PNGs saved as .jpg.
+/// Returns every rename, so the frontend can point its cached grid paths at the renamed files. Otherwise the next save would see the old paths as changes.
+async fn repair_grid_extensions(app_handle: AppHandle, steam_active_user_id: String) -> Vec<GridRename> {
+  let grids_dir: PathBuf = PathBuf::from(steam::get_grids_directory(app_handle.to_owned(), steam_active_user_id.clone(), None));
+  let mut renames: Vec<GridRename> = Vec::new();
+
+  for path in steam::list_grid_files(&grids_dir).into_iter() {
+    let filename: String = path.file_name().and_then(| name | name.to_str()).unwrap_or("").to_owned();
+    let format_start_index = match filename.rfind(".") {
+      Some(index) => index,
+      None => continue
+    };
+
+    let current_extension: String = filename[format_start_index..].to_lowercase();
+    if !path.is_file() || !GRID_IMAGE_EXTENSIONS.contains(&current_extension.as_str()) {
+      continue;
+    }
 
-  let response = http_client.get(grid_url.clone()).send().await.expect("Should have been able to await request.");
-  let response_bytes = response.bytes().await.expect("Should have been able to await getting response bytes.");
+    let true_extension: &str = match grid_images::sniff_image_extension(&path) {
+      Some(extension) => extension,
+      None => {
+        logger::log_tagged_to_core_file(&app_handle, "main", format!("Couldn't identify the format of {}. Skipping...", filename).as_str(), 1);
+        continue;
+      }
+    };
 
-  let mut dest_file: File = File::create(&dest_path).expect("Dest path should have existed.");
-  let write_res = dest_file.write_all(&response_bytes);
+    if current_extension == true_extension || (current_extension == ".jpeg" && true_extension == ".jpg") {
+      continue;
+    }
 
-  if write_res.is_ok() {
-    logger::log_to_core_file(app_handle.to_owned(), format!("Download of {} finished.", grid_url.clone()).as_str(), 0);
-    return String::from("success");
-  } else {
-    let err = write_res.err().expect("Request failed, error should have existed.");
-    logger::log_to_core_file(app_handle.to_owned(), format!("Download of {} failed with {}.", grid_url.clone(), err.to_string()).as_str(), 0);
-    return String::from("failed");
+    let target_path: PathBuf = path.with_file_name(format!("{}{}", &filename[..format_start_index], true_extension));
+
+    if target_path.exists() {
+      logger::log_tagged_to_core_file(&app_handle, "main", format!("Can't rename {} since {} already exists.", filename, target_path.display()).as_str(), 1);
+      continue;
+    }
+
+    if let Err(err) = fs::rename(&path, &target_path) {
+      logger::log_tagged_to_core_file(&app_handle, "main", format!("Failed to rename {}: {}", filename, err).as_str(), 2);
+      continue;
+    }
+
+    let rename = GridRename {
+      from: steam::path_to_string(&path),
+      to: steam::path_to_string(&target_path)
+    };
+    logger::log_tagged_to_core_file(&app_handle, "main", format!("Renamed {} to {}.", rename.from, rename.to).as_str(), 0);
+    renames.push(rename);
   }
+
+  update_renamed_shortcut_icons(&app_handle, steam_active_user_id, &renames);
+
+  return renames;
 }
 
 #[tauri::command]
 /// Downloads a file from a url.
 async fn clean_grids(app_handle: AppHandle, steam_active_user_id: String, preset: String, all_appids: String, selected_game_ids: String) -> String {
-  logger::log_to_core_file(app_handle.to_owned(), format!("Starting {} grid cleaning.", preset).as_str(), 0);
+  logger::log_tagged_to_core_file(&app_handle, "main", format!("Starting {} grid cleaning.", preset).as_str(), 0);
   
   let appids_arr: Vec<String> = serde_json::from_str(all_appids.as_str()).expect("Should have been able to deserialize appids array.");
   
-  let grids_dir_path: String = steam::get_grids_directory(app_handle.to_owned(), steam_active_user_id);
-  let grids_dir_contents = fs::read_dir(grids_dir_path).unwrap();
+  let grids_dir_path: String = steam::get_grids_directory(app_handle.to_owned(), steam_active_user_id, None);
+  let grid_files: Vec<PathBuf> = steam::list_grid_files(&PathBuf::from(grids_dir_path));
 
   let mut found_apps: HashMap<String, (String, String)> = HashMap::new();
   let mut conflicts: Vec<CleanConflicts> = Vec::new();
   
   
   if preset == String::from("clean") {
-    for dir_entry in grids_dir_contents {
-      let entry = dir_entry.expect("Should have been able to get directory entry.");
-  
-      if entry.file_type().unwrap().is_file() {
-        let full_file_path: PathBuf = entry.path();
-        let full_file_path_str: &str = full_file_path.to_str().unwrap();
-        let filename = entry.file_name();
-        let filename_str: &str = filename.to_str().unwrap();
-        
-        let (id, grid_type) = zip_controller::get_id_from_grid_name(filename_str);
-        let id_type_str: String = format!("{}_{}", id, grid_type);
-
-        if appids_arr.contains(&id) {
-          if found_apps.contains_key(&id_type_str) {
-            // ? There's a conflict
-            let (other_filename, other_full_path) = found_apps.get(&id_type_str).expect("Map should have contained the id_type_str.");
-
-            conflicts.push(CleanConflicts { fileAPath: other_full_path.to_owned(), fileAName: other_filename.to_owned(), fileBPath: String::from(full_file_path_str), fileBName: String::from(filename_str), appid: id.clone(), gridType: grid_type.clone() });
-            
-            logger::log_to_core_file(app_handle.to_owned(), format!("Detected conflict between {} and {}.", filename_str, other_filename).as_str(), 0);
-          } else {
-            found_apps.insert(id_type_str, (String::from(filename_str), String::from(full_file_path_str)));
-          }
-        } else {
-          let remove_res = fs::remove_file(full_file_path);
-          if remove_res.is_err() {
-            let err = remove_res.err().unwrap();
-            return format!("{{ \"error\": \"{}\"}}", err.to_string());
-          }
+    for full_file_path in grid_files.iter() {
+      let full_file_path_str: &str = full_file_path.to_str().unwrap();
+      let filename_str: &str = full_file_path.file_name().unwrap().to_str().unwrap();
+      
+      let (id, grid_type) = zip_controller::get_id_from_grid_name(filename_str);
+      let id_type_str: String = format!("{}_{}", id, grid_type);
+
+      if appids_arr.contains(&id) {
+        if found_apps.contains_key(&id_type_str) {
+          // ? There's a conflict
+          let (other_filename, other_full_path) = found_apps.get(&id_type_str).expect("Map should have contained the id_type_str.");
 
-          logger::log_to_core_file(app_handle.to_owned(), format!("Deleted {}.", filename_str).as_str(), 0);
+          conflicts.push(CleanConflicts { fileAPath: other_full_path.to_owned(), fileAName: other_filename.to_owned(), fileBPath: String::from(full_file_path_str), fileBName: String::from(filename_str), appid: id.clone(), gridType: grid_type.clone() });
+          
+          logger::log_tagged_to_core_file(&app_handle, "main", format!("Detected conflict between {} and {}.", filename_str, other_filename).as_str(), 0);
+        } else {
+          found_apps.insert(id_type_str, (String::from(filename_str), String::from(full_file_path_str)));
+        }
+      } else {
+        let remove_res = fs::remove_file(full_file_path);
+        if remove_res.is_err() {
+          let err = remove_res.err().unwrap();
+          return format!("{{ \"error\": \"{}\"}}", err.to_string());
         }
+
+        logger::log_tagged_to_core_file(&app_handle, "main", format!("Deleted {}.", filename_str).as_str(), 0);
       }
     }
   } else {
     let game_ids_arr: Vec<String> = serde_json::from_str(selected_game_ids.as_str()).expect("Should have been able to deserialize selected appids array.");
 
-    for dir_entry in grids_dir_contents {
-      let entry = dir_entry.expect("Should have been able to get directory entry.");
-  
-      if entry.file_type().unwrap().is_file() {
-        let full_file_path: PathBuf = entry.path();
-        let filename = entry.file_name();
-        let filename_str: &str = filename.to_str().unwrap();
-        
-        let (id, _) = zip_controller::get_id_from_grid_name(filename_str);
-
-        if game_ids_arr.contains(&id) {
-          let remove_res = fs::remove_file(full_file_path);
-          if remove_res.is_err() {
-            let err = remove_res.err().unwrap();
-            return format!("{{ \"error\": \"{}\"}}", err.to_string());
-          }
+    for full_file_path in grid_files.iter() {
+      let filename_str: &str = full_file_path.file_name().unwrap().to_str().unwrap();
+      
+      let (id, _) = zip_controller::get_id_from_grid_name(filename_str);
 
-          logger::log_to_core_file(app_handle.to_owned(), format!("Deleted {}.", filename_str).as_str(), 0);
+      if game_ids_arr.contains(&id) {
+        let remove_res = fs::remove_file(full_file_path);
+        if remove_res.is_err() {
+          let err = remove_res.err().unwrap();
+          return format!("{{ \"error\": \"{}\"}}", err.to_string());
         }
+
+        logger::log_tagged_to_core_file(&app_handle, "main", format!("Deleted {}.", filename_str).as_str(), 0);
       }
     }
   }
 
 
-  logger::log_to_core_file(app_handle.to_owned(), format!("{} grid cleaning complete.", preset).as_str(), 0);
+  logger::log_tagged_to_core_file(&app_handle, "main", format!("{} grid cleaning complete.", preset).as_str(), 0);
 
   return serde_json::to_string(&conflicts).expect("Should have been able to serialize conflict array.");
 }
 
 
+/// Adds a directory to Tauri FS and Asset scope. Returns true if it was added to both.
+fn add_directory_to_scope(app_handle: &AppHandle, dir_path: &PathBuf, dir_name: &str) -> bool {
+  let fs_scope = app_handle.fs_scope();
+  let asset_scope = app_handle.asset_protocol_scope();
+
+  let fs_res = FsScope::allow_directory(&fs_scope, dir_path, true);
+  let asset_res = FsScope::allow_directory(&asset_scope, dir_path, true);
+
+  if fs_res.is_ok() && asset_res.is_ok() {
+    logger::log_tagged_to_core_file(app_handle, "main", format!("Added {} directory to scope.", dir_name).as_str(), 0);
+    return true;
+  } else if fs_res.is_err() {
+    let err = fs_res.err().unwrap();
+    logger::log_tagged_to_core_file(app_handle, "main", format!("Error adding {} directory to scope. FS Scope Error: {}", dir_name, err.to_string()).as_str(), 0);
+  } else if asset_res.is_err() {
+    let err = asset_res.err().unwrap();
+    logger::log_tagged_to_core_file(app_handle, "main", format!("Error adding {} directory to scope. Asset Scope Error: {}", dir_name, err.to_string()).as_str(), 0);
+  } else {
+    let fs_err = fs_res.err().unwrap();
+    let asset_err = asset_res.err().unwrap();
+    logger::log_tagged_to_core_file(app_handle, "main", format!("Error adding {} directory to scope. FS Scope Error: {}. Asset Scope Error: {}", dir_name, fs_err.to_string(), asset_err.to_string()).as_str(), 0);
+  }
+
+  return false;
+}
+
+#[tauri::command]
+/// Adds a directory chosen at runtime, like a secondary steam library, to Tauri FS and Asset scope so its images can be loaded.
+fn add_path_to_scope(app_handle: AppHandle, path: String) -> bool {
+  let dir_path: PathBuf = PathBuf::from(&path);
+
+  if !dir_path.is_dir() {
+    logger::log_tagged_to_core_file(&app_handle, "main", format!("Can't add {} to scope since it isn't a directory.", path).as_str(), 1);
+    return false;
+  }
+
+  return add_directory_to_scope(&app_handle, &dir_path, &path);
+}
+
 /// Adds the user's steam directory to Tauri FS and Asset scope.
 fn add_steam_to_scope(app_handle: &AppHandle) {
   let steam_path_res = get_steam_root_dir();
 
   if steam_path_res.is_ok() {
     let steam_path: PathBuf = steam_path_res.ok().expect("Should have been able to get Steam install path result.");
-
-    let fs_scope = app_handle.fs_scope();
-    let asset_scope = app_handle.asset_protocol_scope();
-
-    let fs_res = FsScope::allow_directory(&fs_scope, &steam_path, true);
-    let asset_res = FsScope::allow_directory(&asset_scope, &steam_path, true);
-
-    if fs_res.is_ok() && asset_res.is_ok() {
-      logger::log_to_core_file(app_handle.to_owned(), "Added Steam directory to scope.", 0);
-    } else if fs_res.is_err() {
-      let err = fs_res.err().unwrap();
-      logger::log_to_core_file(app_handle.to_owned(), format!("Error adding Steam directory to scope. FS Scope Error: {}", err.to_string()).as_str(), 0);
-    } else if asset_res.is_err() {
-      let err = asset_res.err().unwrap();
-      logger::log_to_core_file(app_handle.to_owned(), format!("Error adding Steam directory to scope. Asset Scope Error: {}", err.to_string()).as_str(), 0);
-    } else {
-      let fs_err = fs_res.err().unwrap();
-      let asset_err = asset_res.err().unwrap();
-      logger::log_to_core_file(app_handle.to_owned(), format!("Error adding Steam directory to scope. FS Scope Error: {}. Asset Scope Error: {}", fs_err.to_string(), asset_err.to_string()).as_str(), 0);
-    }
+    add_directory_to_scope(app_handle, &steam_path, "Steam");
   } else {
     let err_message = steam_path_res.err().expect("Should have been able to get Steam install path error.");
-    logger::log_to_core_file(app_handle.to_owned(), &err_message, 2);
+    logger::log_tagged_to_core_file(app_handle, "main", &err_message, 2);
 
-    let hit_ok = MessageDialogBuilder::new("SARM Initialization Error", "Steam was not found on your PC. Steam needs to be installed for SARM to work.")
+    let hit_ok = MessageDialogBuilder::new("SARM Initialization Error", format!("Steam was not found on your PC. Steam needs to be installed for SARM to work.\n\n{}", err_message))
       .buttons(MessageDialogButtons::Ok)
       .show();
 
@@ -534,6 +3294,15 @@ fn add_steam_to_scope(app_handle: &AppHandle) {
       exit(1);
     }
   }
+
+  for grids_dir_override in steam::get_grids_directory_overrides(app_handle).values().filter_map(| grids_dir | grids_dir.as_str()) {
+    add_directory_to_scope(app_handle, &PathBuf::from(grids_dir_override), "grids override");
+  }
+
+  // ? The install's own cache is already covered by scoping steam.
+  for cache_dir in steam::get_library_cache_dirs(None).into_iter().skip(1) {
+    add_directory_to_scope(app_handle, &cache_dir, "library cache");
+  }
 }
 
 /// This app's main function.
@@ -544,21 +3313,115 @@ fn main() {
       logger::log_to_core_file,
       logger::log_to_batch_apply_file,
       steam::get_steam_users,
+      steam::read_loginusers,
+      steam::read_config_vdf,
+      steam::get_all_steam_installs,
+      steam::get_steam_apps,
+      steam::get_steam_language,
+      app_dirs::get_app_data_dir,
+      app_dirs::get_app_cache_dir,
+      steam::set_active_user_override,
       steam::get_grids_directory,
       steam::get_library_cache_directory,
+      steam::get_library_cache_directories,
+      steam::get_library_cache_art,
+      steam::get_art_locations,
+      steam::get_app_library,
       steam::get_appinfo_path,
       steam::get_shortcuts_path,
       steam::get_localconfig_path,
+      list_grid_files,
+      get_existing_art,
+      pick_grids_directory,
+      add_path_to_scope,
+      list_grids_detailed,
+      list_local_art_library,
+      find_duplicate_grids,
+      get_customized_appids,
+      get_custom_grid_counts,
+      get_art_coverage,
+      export_art_manifest,
+      import_art_manifest,
       export_grids_to_zip,
+      export_app_art,
+      export_grids_to_folder,
       import_grids_from_zip,
+      preview_import,
+      create_art_pack,
+      read_art_pack_info,
+      import_grids_from_folder,
       read_appinfo_vdf,
+      get_app_info,
       read_shortcuts_vdf,
+      shortcuts_status,
+      check_grids_writable,
       read_localconfig_vdf,
+      write_localconfig_vdf,
       save_changes,
+      save_changes_transactional,
+      apply_changes,
+      diff_art_profiles,
+      art_profiles::save_art_profile,
+      art_profiles::list_art_profiles,
+      art_profiles::apply_art_profile,
+      caches::clear_caches,
+      controller_configs::read_controller_config,
+      controller_configs::write_controller_config,
+      disk_space::get_free_space,
+      grids_snapshot::snapshot_grids,
+      grids_snapshot::diff_grids_snapshot,
+      proton::get_proton_shortcut_info,
+      proton::get_compat_tools,
+      count_pending_changes,
+      save_changes_multi_user,
       write_shortcuts,
+      ensure_shortcuts_vdf,
+      export_shortcuts_json,
+      import_shortcuts_json,
+      merge_shortcuts,
+      validate_shortcuts,
+      repair_shortcuts,
+      find_broken_shortcut_icons,
+      clear_broken_shortcut_icons,
+      rebase_shortcut_icons,
+      retag_grid,
       download_grid,
-      clean_grids
+      test_download,
+      import_grids_from_urls,
+      autofill_game_art,
+      apply_default_art,
+      clean_grids,
+      remove_grids,
+      repair_grid_extensions,
+      backups::list_backups,
+      backups::delete_backup,
+      heroic::get_heroic_games,
+      lutris::get_lutris_games,
+      app_cache::search_apps,
+      app_cache::build_name_id_maps,
+      grid_images::check_grid_dimensions,
+      grid_images::guess_grid_type,
+      grid_images::can_decode_image,
+      grid_images::optimize_grid,
+      grid_images::read_image_as_base64,
+      steam::get_user_avatar_path,
+      grids_watcher::watch_grids_directory,
+      grids_watcher::unwatch_grids_directory,
+      diagnostics::export_diagnostics,
+      download_jobs::cancel_download,
+      steamgriddb::validate_steamgriddb_key,
+      steamgriddb::search_grids_paged,
+      log_stream::start_log_stream,
+      log_stream::stop_log_stream,
+      store_art::get_store_header_url,
+      store_art::build_art_url,
+      store_art::download_store_art
     ])
+    .manage(app_cache::AppInfoCache::default())
+    .manage(grids_watcher::GridsWatcher::default())
+    .manage(download_jobs::DownloadJobs::default())
+    .manage(log_stream::LogStream::default())
+    .manage(steam::ActiveUserOverride::default())
     .plugin(tauri_plugin_single_instance::init(|app, argv, cwd| {
       println!("{}, {argv:?}, {cwd}", app.package_info().name);
 
@@ -593,8 +3456,8 @@ fn main() {
           log_message = format!("PANIC: File 'UNKOWN' at line UNKOWN: {}", message).to_string();
         }
 
-        logger::log_to_file(&log_file_path_buf, &log_message, 2);
-        logger::log_to_file(&log_file_path_buf, "Please open an issue at https://github.com/Tormak9970/Steam-Art-Manager/issues", 2);
+        logger::log_tagged_to_file(&log_file_path_buf, Some("panic"), &log_message, 2);
+        logger::log_tagged_to_file(&log_file_path_buf, Some("panic"), "Please open an issue at https://github.com/Tormak9970/Steam-Art-Manager/issues", 2);
 
         let hit_ok = MessageDialogBuilder::new("SARM Panic!", "Check your log file for more information, and please open an issue at https://github.com/Tormak9970/Steam-Art-Manager/issues")
           .buttons(MessageDialogButtons::Ok)
@@ -609,4 +3472,180 @@ fn main() {
     })
     .run(tauri::generate_context!())
     .expect("error while running tauri application");
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  /// Gets the grid type table without any custom types.
+  fn default_grid_types() -> GridTypeTable {
+    return DEFAULT_GRID_TYPE_SUFFIXES.iter().map(| (grid_type, suffix) | (grid_type.to_string(), suffix.to_string())).collect();
+  }
+
+  #[test]
+  fn parse_grid_filename_inverts_get_grid_filename() {
+    let grid_types: GridTypeTable = default_grid_types();
+
+    for (grid_type, _) in grid_types.iter() {
+      for extension in GRID_IMAGE_EXTENSIONS.iter() {
+        let filename: String = get_grid_filename(&grid_types, "1234", grid_type, extension).unwrap();
+        assert_eq!(parse_grid_filename(&grid_types, &filename), Some((String::from("1234"), grid_type.to_owned())), "{}", filename);
+      }
+    }
+  }
+
+  #[test]
+  fn parse_grid_filename_matches_suffixes_before_capsules() {
+    let grid_types: GridTypeTable = default_grid_types();
+    let expected: [(&str, &str); 5] = [("1234p.png", "Capsule"), ("1234.png", "Wide Capsule"), ("1234_hero.jpg", "Hero"), ("1234_logo.webp", "Logo"), ("1234_icon.ico", "Icon")];
+
+    for (filename, grid_type) in expected.iter() {
+      assert_eq!(parse_grid_filename(&grid_types, filename), Some((String::from("1234"), grid_type.to_string())), "{}", filename);
+    }
+  }
+
+  #[test]
+  fn parse_grid_filename_rejects_other_files() {
+    let grid_types: GridTypeTable = default_grid_types();
+
+    for filename in ["1234.json", "1234", "notes.txt", "abc.png", "p.png", "_hero.png", "1234_banner.png", "12a4p.png", "1234_hero_logo.png"].iter() {
+      assert_eq!(parse_grid_filename(&grid_types, filename), None, "{}", filename);
+    }
+  }
+
+  /// A trimmed down localconfig.vdf, with the deep Software/Valve/Steam nesting and a section outside it.
+  const LOCALCONFIG_VDF: &str = r#""UserLocalConfigStore"
+{
+	"Software"
+	{
+		"Valve"
+		{
+			"Steam"
+			{
+				"apps"
+				{
+					"1234"
+					{
+						"LastPlayed"		"1700000000"
+						"cloud"
+						{
+							"last_sync_state"		"synchronized"
+						}
+					}
+				}
+			}
+		}
+	}
+	"friends"
+	{
+		"PersonaName"		"Some \"Quoted\" Name"
+	}
+}"#;
+
+  /// Gets the object at a path of keys in a vdf object.
+  fn get_vdf_path<'a, 'b>(obj: &'b Obj<'a>, keys: &[&str]) -> Option<&'b Obj<'a>> {
+    let mut current: &Obj = obj;
+
+    for key in keys.iter() {
+      current = current.get(*key)?.first()?.get_obj()?;
+    }
+
+    return Some(current);
+  }
+
+  #[test]
+  fn localconfig_round_trips_unchanged() {
+    let original: Vdf = Vdf::parse(LOCALCONFIG_VDF).unwrap();
+    let mut written: Vdf = original.clone();
+    merge_json_into_vdf_obj(written.value.get_mut_obj().unwrap(), &Map::new());
+
+    let written_contents: String = written.to_string();
+    assert_eq!(Vdf::parse(&written_contents).unwrap(), original);
+  }
+
+  #[test]
+  fn localconfig_merge_keeps_untouched_keys() {
+    let original: Vdf = Vdf::parse(LOCALCONFIG_VDF).unwrap();
+    let mut written: Vdf = original.clone();
+    let changes: Map<String, Value> = serde_json::from_str(r#"{ "friends": { "PersonaName": "Someone Else" } }"#).unwrap();
+    merge_json_into_vdf_obj(written.value.get_mut_obj().unwrap(), &changes);
+
+    let written_contents: String = written.to_string();
+    let reread: Vdf = Vdf::parse(&written_contents).unwrap();
+    let steam_path: [&str; 3] = ["Software", "Valve", "Steam"];
+
+    assert_eq!(get_vdf_path(reread.value.get_obj().unwrap(), &steam_path), get_vdf_path(original.value.get_obj().unwrap(), &steam_path));
+    assert_eq!(get_vdf_path(reread.value.get_obj().unwrap(), &["friends"]).unwrap().get("PersonaName").unwrap()[0].get_str(), Some("Someone Else"));
+  }
+
+  #[test]
+  fn remove_old_grid_treats_missing_grids_as_removed() {
+    let grid_path: PathBuf = std::env::temp_dir().join(format!("sarm_remove_old_grid_{}.png", std::process::id()));
+    fs::write(&grid_path, [0u8; 4]).unwrap();
+    let grid_path_str: String = steam::path_to_string(&grid_path);
+
+    assert!(remove_old_grid(&grid_path_str).is_ok());
+    assert!(!grid_path.exists());
+    assert!(remove_old_grid(&grid_path_str).is_ok());
+  }
+
+  #[test]
+  fn diff_art_profiles_rejects_invalid_json() {
+    assert!(diff_art_profiles(String::from("{"), String::from("{}")).is_err());
+    assert!(diff_art_profiles(String::from("{}"), String::from("[1]")).is_err());
+    assert_eq!(diff_art_profiles(String::from("{}"), String::from("{}")), Ok(String::from("[]")));
+  }
+
+  #[test]
+  fn normalize_shortcut_appid_handles_signed_and_string_appids() {
+    assert_eq!(normalize_shortcut_appid(&Value::from(3000000000u32)), Some(String::from("3000000000")));
+    assert_eq!(normalize_shortcut_appid(&Value::from(-1294967296i64)), Some(String::from("3000000000")));
+    assert_eq!(normalize_shortcut_appid(&Value::from(-1i32)), Some(String::from("4294967295")));
+    assert_eq!(normalize_shortcut_appid(&Value::String(String::from(" -1294967296 "))), Some(String::from("3000000000")));
+    assert_eq!(normalize_shortcut_appid(&Value::String(String::from("3000000000"))), Some(String::from("3000000000")));
+
+    let signed_appid: String = normalize_shortcut_appid(&Value::from(-1294967296i64)).unwrap();
+    assert_eq!(get_grid_filename(&default_grid_types(), &signed_appid, "Icon", ".png"), Ok(String::from("3000000000_icon.png")));
+  }
+
+  #[test]
+  fn normalize_shortcut_appid_rejects_out_of_range_appids() {
+    assert_eq!(normalize_shortcut_appid(&Value::from(4294967296i64)), None);
+    assert_eq!(normalize_shortcut_appid(&Value::from(-2147483649i64)), None);
+    assert_eq!(normalize_shortcut_appid(&Value::from(u64::MAX)), None);
+    assert_eq!(normalize_shortcut_appid(&Value::String(String::from("not an appid"))), None);
+  }
+
+  fn single_grid_cache(appid: &str, grid_type: &str, path: &str) -> GridImageCache {
+    let mut app_grids: HashMap<String, String> = HashMap::new();
+    app_grids.insert(grid_type.to_owned(), path.to_owned());
+
+    let mut cache: GridImageCache = GridImageCache::new();
+    cache.insert(appid.to_owned(), app_grids);
+    return cache;
+  }
+
+  #[test]
+  fn diff_grid_caches_ignores_separator_differences() {
+    let backslash_cache: GridImageCache = single_grid_cache("10", "Capsule", "C:\\Steam\\userdata\\123\\config\\grid\\10p.png");
+    let slash_cache: GridImageCache = single_grid_cache("10", "Capsule", "C:/Steam/userdata/123/config/grid/10p.png");
+
+    assert!(diff_grid_caches(&backslash_cache, &slash_cache).is_empty());
+  }
+
+  #[test]
+  fn diff_grid_caches_reports_real_changes() {
+    let original_cache: GridImageCache = single_grid_cache("10", "Capsule", "C:\\Steam\\userdata\\123\\config\\grid\\10p.png");
+    let changed_cache: GridImageCache = single_grid_cache("10", "Capsule", "C:/Users/me/Pictures/capsule.png");
+    let removed_cache: GridImageCache = single_grid_cache("10", "Capsule", "REMOVE");
+
+    let changed_diffs: Vec<ArtProfileDiff> = diff_grid_caches(&original_cache, &changed_cache);
+    assert_eq!(changed_diffs.len(), 1);
+    assert_eq!(changed_diffs[0].change, "changed");
+
+    let removed_diffs: Vec<ArtProfileDiff> = diff_grid_caches(&original_cache, &removed_cache);
+    assert_eq!(removed_diffs.len(), 1);
+    assert_eq!(removed_diffs[0].change, "removed");
+  }
+}
@@ -0,0 +1,207 @@
+use crate::{app_cache, logger, steam};
+
+use std::{fs, path::{Path, PathBuf}};
+
+use keyvalues_parser::Vdf;
+use tauri::AppHandle;
+
+/// Dirs in a prefix's drive_c that hold games rather than being a game's own dir.
+const PREFIX_CONTAINER_DIRS: [&str; 6] = ["program files", "program files (x86)", "games", "gog games", "epic games", "users"];
+
+/// System wide dirs distro packages install compat tools to, besides the one in the steam install.
+const SYSTEM_COMPAT_TOOL_DIRS: [&str; 2] = ["/usr/share/steam/compatibilitytools.d", "/usr/local/share/steam/compatibilitytools.d"];
+
+#[derive(Clone, serde::Serialize)]
+#[allow(non_snake_case)]
+pub struct CompatTool {
+  name: String,
+  displayName: String,
+  path: String,
+  isOfficial: bool
+}
+
+#[derive(Clone, serde::Serialize)]
+#[allow(non_snake_case)]
+pub struct ProtonShortcutInfo {
+  appid: String,
+  isSteamApp: bool,
+  prefixPath: String,
+  displayName: String
+}
+
+/// Finds the compatdata prefix a path is in, returning the prefix's id and path.
+fn find_compatdata_prefix(path: &str) -> Option<(String, String)> {
+  let normalized_path: String = steam::normalize_path(path.trim().trim_matches('"'));
+  let segments: Vec<&str> = normalized_path.split('/').collect();
+
+  let compatdata_index: usize = segments.iter().position(| segment | segment.eq_ignore_ascii_case("compatdata"))?;
+  let prefix_id: &str = segments.get(compatdata_index + 1)?;
+
+  if prefix_id.is_empty() || !prefix_id.chars().all(| c | c.is_ascii_digit()) {
+    return None;
+  }
+
+  return Some((prefix_id.to_owned(), segments[..=compatdata_index + 1].join("/")));
+}
+
+/// Guesses a game's name from where its exe is in a prefix, using the first dir under drive_c that isn't a container like Program Files.
+fn guess_name_from_prefix_path(path: &str) -> Option<String> {
+  let normalized_path: String = steam::normalize_path(path.trim().trim_matches('"'));
+  let segments: Vec<&str> = normalized_path.split('/').filter(| segment | !segment.is_empty()).collect();
+
+  let drive_index: usize = segments.iter().position(| segment | segment.eq_ignore_ascii_case("drive_c"))?;
+  let game_dirs: &[&str] = &segments[drive_index + 1..];
+
+  // ? The last segment is the exe itself if the path points to a file.
+  let dir_count: usize = if Path::new(&normalized_path).extension().is_some() { game_dirs.len().saturating_sub(1) } else { game_dirs.len() };
+
+  let mut skip_next: bool = false;
+
+  for segment in game_dirs[..dir_count].iter() {
+    if skip_next {
+      // ? The dir after users is the wine user's name.
+      skip_next = false;
+      continue;
+    }
+
+    let lowercase_segment: String = segment.to_lowercase();
+
+    if PREFIX_CONTAINER_DIRS.contains(&lowercase_segment.as_str()) {
+      skip_next = lowercase_segment == "users";
+      continue;
+    }
+
+    return Some(segment.to_string());
+  }
+
+  return Path::new(&normalized_path).file_stem().and_then(| stem | stem.to_str()).map(| stem | stem.to_owned());
+}
+
+#[tauri::command]
+/// Checks if a shortcut runs under Proton, based on its exe and start dir being in a compatdata prefix.
+/// Returns the prefix's id, and a name to show for it. Prefixes with ids below 2^31 belong to Steam games, so their store name and art can be used.
+pub fn get_proton_shortcut_info(app_handle: AppHandle, exe: String, start_dir: String) -> Option<ProtonShortcutInfo> {
+  let (prefix_id, prefix_path) = find_compatdata_prefix(&exe).or_else(|| find_compatdata_prefix(&start_dir))?;
+
+  // ? Non-Steam shortcuts get appids with the top bit set, so anything below that is a Steam app.
+  let is_steam_app: bool = prefix_id.parse::<u64>().map(| id | id < 0x80000000).unwrap_or(false);
+
+  let store_name: Option<String> = if is_steam_app {
+    prefix_id.parse::<u32>().ok().and_then(| id | app_cache::get_app_name_map(&app_handle).remove(&id))
+  } else {
+    None
+  };
+
+  let display_name: String = store_name
+    .or_else(|| guess_name_from_prefix_path(&exe))
+    .or_else(|| guess_name_from_prefix_path(&start_dir))
+    .unwrap_or_else(|| prefix_id.to_owned());
+
+  logger::log_tagged_to_core_file(&app_handle, "proton", format!("Resolved Proton prefix {} as {}.", prefix_id, display_name).as_str(), 0);
+
+  return Some(ProtonShortcutInfo {
+    appid: prefix_id,
+    isSteamApp: is_steam_app,
+    prefixPath: prefix_path,
+    displayName: display_name
+  });
+}
+
+/// Reads the tools a custom compat tool's compatibilitytool.vdf declares, as their internal and display names. A single dir can declare several.
+fn read_compat_tool_manifest(tool_dir: &Path) -> Vec<(String, String)> {
+  let contents: String = match fs::read_to_string(tool_dir.join("compatibilitytool.vdf")) {
+    Ok(contents) => contents,
+    Err(_) => return Vec::new()
+  };
+
+  let vdf = match Vdf::parse(&contents) {
+    Ok(vdf) => vdf,
+    Err(_) => return Vec::new()
+  };
+
+  let compat_tools = vdf.value.get_obj()
+    .and_then(| root | root.get("compat_tools"))
+    .and_then(| entries | entries.first())
+    .and_then(| compat_tools | compat_tools.get_obj());
+
+  return compat_tools.into_iter().flat_map(| tools | tools.iter()).map(| (name, entries) | {
+    let display_name: String = entries.first()
+      .and_then(| tool | tool.get_obj())
+      .and_then(| tool | tool.get("display_name"))
+      .and_then(| display_names | display_names.first())
+      .and_then(| display_name | display_name.get_str())
+      .unwrap_or(name)
+      .to_owned();
+
+    return (name.to_string(), display_name);
+  }).collect();
+}
+
+/// Lists the custom compat tools, like GE-Proton, installed in a compatibilitytools.d dir.
+fn list_custom_compat_tools(tools_dir: &Path) -> Vec<CompatTool> {
+  let mut tool_dirs: Vec<PathBuf> = fs::read_dir(tools_dir).into_iter().flatten().flatten().map(| entry | entry.path()).filter(| path | path.is_dir()).collect();
+  tool_dirs.sort();
+
+  return tool_dirs.into_iter().flat_map(| tool_dir | {
+    let path: String = steam::path_to_string(&tool_dir);
+    let mut declared_tools: Vec<(String, String)> = read_compat_tool_manifest(&tool_dir);
+
+    // ? Some tools don't ship a manifest, so their dir name is the best name there is.
+    if declared_tools.is_empty() {
+      let dir_name: String = tool_dir.file_name().and_then(| name | name.to_str()).unwrap_or("").to_owned();
+      declared_tools.push((dir_name.to_owned(), dir_name));
+    }
+
+    return declared_tools.into_iter().map(move | (name, display_name) | CompatTool { name, displayName: display_name, path: path.to_owned(), isOfficial: false });
+  }).collect();
+}
+
+/// Lists the official Proton versions installed in a steam library's steamapps/common dir, going by the dirs that have a proton script.
+fn list_official_proton_installs(library_dir: &Path) -> Vec<CompatTool> {
+  let mut proton_dirs: Vec<PathBuf> = fs::read_dir(library_dir.join("steamapps/common")).into_iter().flatten().flatten()
+    .map(| entry | entry.path())
+    .filter(| path | path.file_name().and_then(| name | name.to_str()).is_some_and(| name | name.starts_with("Proton")) && path.join("proton").is_file())
+    .collect();
+  proton_dirs.sort();
+
+  return proton_dirs.into_iter().map(| proton_dir | {
+    let display_name: String = proton_dir.file_name().and_then(| name | name.to_str()).unwrap_or("").to_owned();
+    // ? Steam's internal names for Proton are lowercase with underscores, like proton_experimental.
+    let name: String = display_name.to_lowercase().replace([' ', '.', '-'], "_");
+
+    return CompatTool { name, displayName: display_name, path: steam::path_to_string(&proton_dir), isOfficial: true };
+  }).collect();
+}
+
+#[tauri::command]
+/// Gets the compat tools installed for steam, both custom ones in compatibilitytools.d and the official Proton versions in every steam library.
+/// Compat tools are only used on Linux, so this returns an empty list anywhere else.
+pub fn get_compat_tools(app_handle: AppHandle) -> String {
+  if !cfg!(target_os = "linux") {
+    return "[]".to_owned();
+  }
+
+  let steam_root: PathBuf = match steam::get_steam_root_dir() {
+    Ok(steam_root) => steam_root,
+    Err(err) => {
+      logger::log_tagged_to_core_file(&app_handle, "proton", format!("Can't list compat tools without a steam install: {}", err).as_str(), 1);
+      return "[]".to_owned();
+    }
+  };
+
+  let mut compat_tools: Vec<CompatTool> = list_custom_compat_tools(&steam_root.join("compatibilitytools.d"));
+
+  for system_dir in SYSTEM_COMPAT_TOOL_DIRS.iter() {
+    compat_tools.extend(list_custom_compat_tools(Path::new(system_dir)));
+  }
+
+  let mut libraries: Vec<PathBuf> = vec![steam_root.to_owned()];
+  libraries.extend(steam::get_library_folders(&steam_root));
+
+  for library in libraries.iter() {
+    compat_tools.extend(list_official_proton_installs(library));
+  }
+
+  logger::log_tagged_to_core_file(&app_handle, "proton", format!("Found {} compat tools.", compat_tools.len()).as_str(), 0);
+  return serde_json::to_string(&compat_tools).expect("Should have been able to serialize compat tools.");
+}
@@ -0,0 +1,34 @@
+use crate::app_dirs;
+
+use std::{path::PathBuf, fs};
+
+use serde_json::{Map, Value};
+use tauri::AppHandle;
+
+/// Gets the path of the app's settings file.
+pub fn get_settings_path(app_handle: &AppHandle) -> PathBuf {
+  let app_config_dir: PathBuf = app_dirs::resolve_app_config_dir(app_handle);
+  return app_config_dir.join("settings.json");
+}
+
+/// Gets a setting from the app's settings file, if it has been set.
+pub fn get_setting(app_handle: &AppHandle, key: &str) -> Option<Value> {
+  let settings_path: PathBuf = get_settings_path(app_handle);
+  let settings_contents = fs::read_to_string(settings_path).ok()?;
+  let settings: Map<String, Value> = serde_json::from_str(&settings_contents).ok()?;
+
+  return settings.get(key).cloned();
+}
+
+/// Sets a setting in the app's settings file. Returns false if the file couldn't be written.
+pub fn set_setting(app_handle: &AppHandle, key: &str, value: Value) -> bool {
+  let settings_path: PathBuf = get_settings_path(app_handle);
+  let mut settings: Map<String, Value> = fs::read_to_string(&settings_path).ok()
+    .and_then(| contents | serde_json::from_str(&contents).ok())
+    .unwrap_or_default();
+
+  settings.insert(key.to_owned(), value);
+
+  let settings_contents: String = serde_json::to_string_pretty(&settings).expect("Should have been able to serialize settings.");
+  return fs::write(settings_path, settings_contents).is_ok();
+}
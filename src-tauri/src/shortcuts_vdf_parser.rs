@@ -4,7 +4,7 @@ use std::io::{Read, Write};
 use serde_json::{ Value, Map };
 
 use crate::reader::Reader;
-use crate::vdf_reader::read_entry_map;
+use crate::vdf_reader::{read_entry_map, typed_value, TYPED_VALUE_KEY};
 use crate::writer::Writer;
 
 /// Opens the shortcuts.vdf file and returns the values as JSON.
@@ -34,50 +34,46 @@ fn read(reader: &mut Reader) -> Value {
   return Value::Object(read_entry_map(reader));
 }
 
-/// Writes the shortcuts.vdf file from JSON.
+/// Writes the shortcuts.vdf file from JSON. Returns false if the data couldn't be written.
 pub fn write_shortcuts_vdf(path: &PathBuf, data: Value) -> bool {
-  if data.is_object() {
-    let shortcuts = data.as_object().expect("Should have been able to convert to an object.");
-    
-    let mut buffer: Vec<u8> = vec![0; 1000];
-    let mut writer: Writer = Writer::new(&mut buffer);
-    
-    write_entry_map(&mut writer, shortcuts);
-
-    writer.trim();
-
-    let mut file = fs::File::create(path).expect("Should have been able to create file or truncate if existed.");
-    let write_res = file.write_all(&buffer[..]);
-
-    if write_res.is_err() {
-      let err = write_res.err().unwrap();
-      panic!("Error writing shortcuts: {}", err);
-    }
+  return try_write_shortcuts_vdf(path, data).is_ok();
+}
 
-    return true;
-  } else {
-    panic!("Error writing shortcuts: data was not an object!");
-  }
+/// Writes the shortcuts.vdf file from JSON, returning an error if the data isn't valid shortcuts. The file is left alone unless all of the data could be written.
+pub fn try_write_shortcuts_vdf(path: &PathBuf, data: Value) -> Result<(), String> {
+  let shortcuts: &Map<String, Value> = data.as_object().ok_or(String::from("Error writing shortcuts: data was not an object!"))?;
+
+  let mut buffer: Vec<u8> = vec![0; 1000];
+  let mut writer: Writer = Writer::new(&mut buffer);
+
+  write_entry_map(&mut writer, shortcuts)?;
+
+  writer.trim();
+
+  let mut file = fs::File::create(path).map_err(| err | format!("Error creating {}: {}", path.display(), err))?;
+  return file.write_all(&buffer[..]).map_err(| err | format!("Error writing shortcuts: {}", err));
 }
 
 /// Writes a shortcuts.vdf entry map from JSON.
-fn write_entry_map(writer: &mut Writer, map: &Map<String, Value>) {
+fn write_entry_map(writer: &mut Writer, map: &Map<String, Value>) -> Result<(), String> {
   for (key, val) in map.into_iter() {
-    write_entry_field(writer, key, val);
+    write_entry_field(writer, key, val)?;
   }
   
   writer.write_uint8(0x08, true);
+  return Ok(());
 }
 
-/// Writes a shortcuts.vdf entry field from JSON.
-fn write_entry_field(writer: &mut Writer, key: &String, field: &Value) {
+/// Writes a shortcuts.vdf entry field from JSON. Returns an error if the field can't be stored in a shortcuts.vdf file.
+fn write_entry_field(writer: &mut Writer, key: &String, field: &Value) -> Result<(), String> {
   let key_owned: String = key.to_owned();
 
   if field.is_number() {
+    // ? Negative numbers are signed appids, which have the same bits as the unsigned ones steam writes.
+    let number: u64 = field.as_u64().or_else(|| field.as_i64().map(| signed | signed as i32 as u32 as u64)).ok_or(format!("{} should have been a whole number, but was {}.", key, field))?;
+
     writer.write_uint8(0x02, true);
     writer.write_string(key_owned, false, true);
-
-    let number: u64 = field.as_u64().expect("Should have been able to convert to a number.");
     writer.write_uint32(number as u32, true);
   } else if field.is_string() {
     writer.write_uint8(0x01, true);
@@ -85,13 +81,257 @@ fn write_entry_field(writer: &mut Writer, key: &String, field: &Value) {
 
     let string: &str = field.as_str().expect("Should have been able to convert to a string.");
     writer.write_string(string.to_owned(), false, true);
+  } else if let Some(value_type) = field.get(TYPED_VALUE_KEY).and_then(| value_type | value_type.as_str()) {
+    // ? Values JSON can't hold exactly are kept as strings, and written back with the type they were read as.
+    let value: &str = field.get("value").and_then(| value | value.as_str()).ok_or(format!("Typed value {} should have had a string value.", key))?;
+
+    match value_type {
+      "float32" => {
+        let float_value: f32 = value.parse::<f32>().map_err(| err | format!("Failed to parse float32 value {} of {}: {}", value, key, err))?;
+        writer.write_uint8(0x03, true);
+        writer.write_string(key_owned, false, true);
+        writer.write_float32(float_value, true);
+      },
+      "uint64" => {
+        let uint_value: u64 = value.parse::<u64>().map_err(| err | format!("Failed to parse uint64 value {} of {}: {}", value, key, err))?;
+        writer.write_uint8(0x07, true);
+        writer.write_string(key_owned, false, true);
+        writer.write_uint64(uint_value, true);
+      },
+      "int64" => {
+        let int_value: i64 = value.parse::<i64>().map_err(| err | format!("Failed to parse int64 value {} of {}: {}", value, key, err))?;
+        writer.write_uint8(0x0A, true);
+        writer.write_string(key_owned, false, true);
+        writer.write_int64(int_value, true);
+      },
+      _ => return Err(format!("Unexpected typed value {} for {}.", value_type, key))
+    }
   } else if field.is_object() {
     writer.write_uint8(0x00, true);
     writer.write_string(key_owned, false, true);
 
     let field_map = field.as_object().expect("Should have been able to convert to an object.");
-    write_entry_map(writer, field_map);
+    write_entry_map(writer, field_map)?;
   } else {
-    panic!("Value was not an object, number or string!");
+    return Err(format!("{} was not an object, number or string!", key));
+  }
+
+  return Ok(());
+}
+
+/// How deep maps can be nested before a file is treated as corrupt.
+const MAX_ENTRY_DEPTH: usize = 32;
+
+/// A binary vdf reader that returns errors instead of panicking when the data is malformed.
+struct CheckedReader<'a> {
+  data: &'a [u8],
+  offset: usize
+}
+
+impl<'a> CheckedReader<'a> {
+  /// Reads the next byte.
+  fn read_uint8(&mut self) -> Result<u8, String> {
+    let byte: u8 = *self.data.get(self.offset).ok_or(format!("File ended early at byte {}.", self.offset))?;
+    self.offset += 1;
+    return Ok(byte);
+  }
+
+  /// Reads the next little endian 32 bit unsigned int.
+  fn read_uint32(&mut self) -> Result<u32, String> {
+    let bytes: &[u8] = self.data.get(self.offset..self.offset + 4).ok_or(format!("File ended early at byte {}.", self.offset))?;
+    self.offset += 4;
+    return Ok(u32::from_le_bytes(bytes.try_into().expect("Slice should have been 4 bytes.")));
+  }
+
+  /// Reads the next little endian 64 bit unsigned int.
+  fn read_uint64(&mut self) -> Result<u64, String> {
+    let bytes: &[u8] = self.data.get(self.offset..self.offset + 8).ok_or(format!("File ended early at byte {}.", self.offset))?;
+    self.offset += 8;
+    return Ok(u64::from_le_bytes(bytes.try_into().expect("Slice should have been 8 bytes.")));
+  }
+
+  /// Reads the next null terminated string. Strings that aren't valid utf8 are read as latin1, like the regular reader does.
+  fn read_string(&mut self) -> Result<String, String> {
+    let length: usize = self.data[self.offset.min(self.data.len())..].iter().position(| byte | *byte == 0).ok_or(format!("Unterminated string at byte {}.", self.offset))?;
+    let bytes: &[u8] = &self.data[self.offset..self.offset + length];
+    self.offset += length + 1;
+
+    return Ok(String::from_utf8(bytes.to_vec()).unwrap_or_else(| _ | bytes.iter().map(| byte | *byte as char).collect()));
+  }
+}
+
+/// Reads a vdf entry map to JSON, returning an error if it's malformed.
+fn try_read_entry_map(reader: &mut CheckedReader, depth: usize) -> Result<Map<String, Value>, String> {
+  if depth > MAX_ENTRY_DEPTH {
+    return Err(format!("Maps are nested too deeply at byte {}.", reader.offset));
+  }
+
+  let mut props: Map<String, Value> = Map::new();
+  let mut field_type: u8 = reader.read_uint8()?;
+
+  while field_type != 0x08 {
+    let key: String = reader.read_string()?;
+    let value: Value = match field_type {
+      0x00 => Value::Object(try_read_entry_map(reader, depth + 1)?),
+      0x01 => Value::String(reader.read_string()?),
+      0x02 => Value::Number(reader.read_uint32()?.into()),
+      0x03 => typed_value("float32", f32::from_bits(reader.read_uint32()?).to_string()),
+      0x07 => typed_value("uint64", reader.read_uint64()?.to_string()),
+      0x0A => typed_value("int64", (reader.read_uint64()? as i64).to_string()),
+      _ => return Err(format!("Unexpected field type {} for {} at byte {}.", field_type, key, reader.offset))
+    };
+
+    props.insert(key, value);
+    field_type = reader.read_uint8()?;
+  }
+
+  return Ok(props);
+}
+
+/// Checks if a vdf file uses steam's binary format, like shortcuts.vdf, rather than the text one.
+pub fn is_binary_vdf(contents: &[u8]) -> bool {
+  // ? Binary vdfs start with the type byte of their root map, which is never valid at the start of a text vdf.
+  return contents.first() == Some(&0x00) || std::str::from_utf8(contents).is_err();
+}
+
+/// Reads any binary vdf file as JSON keyed by its root key, returning an error instead of panicking if it's malformed.
+pub fn try_open_binary_vdf(path: &PathBuf) -> Result<Value, String> {
+  let data: Vec<u8> = fs::read(path).map_err(| err | err.to_string())?;
+  let mut reader: CheckedReader = CheckedReader { data: &data, offset: 0 };
+
+  if reader.read_uint8()? != 0x00 {
+    return Err(String::from("File doesn't start with a map."));
   }
-}
\ No newline at end of file
+
+  let root_key: String = reader.read_string()?;
+  let mut root: Map<String, Value> = Map::new();
+  root.insert(root_key, Value::Object(try_read_entry_map(&mut reader, 1)?));
+
+  return Ok(Value::Object(root));
+}
+
+/// Finds where the next shortcut entry starts after a corrupt one, by looking for a map field keyed by an index.
+fn find_next_shortcut_entry(data: &[u8], from: usize) -> Option<usize> {
+  return (from..data.len()).find(| start | {
+    if data[*start] != 0x00 {
+      return false;
+    }
+
+    let key_length: usize = data[start + 1..].iter().take_while(| byte | byte.is_ascii_digit()).count();
+    let key_end: usize = start + 1 + key_length;
+
+    return key_length > 0 && data.get(key_end) == Some(&0x00) && matches!(data.get(key_end + 1), Some(0x00 | 0x01 | 0x02));
+  });
+}
+
+/// The shortcuts that could be read from a shortcuts.vdf file, and the problems hit while reading it.
+pub struct CheckedShortcuts {
+  pub shortcuts: Map<String, Value>,
+  pub issues: Vec<(Option<String>, String)>
+}
+
+/// Reads a shortcuts.vdf file without panicking. Corrupt shortcuts are skipped and reported, and the rest are still read.
+pub fn try_open_shortcuts_vdf(path: &PathBuf) -> Result<CheckedShortcuts, String> {
+  let data: Vec<u8> = fs::read(path).map_err(| err | err.to_string())?;
+  let mut reader: CheckedReader = CheckedReader { data: &data, offset: 0 };
+
+  if reader.read_uint8()? != 0x00 || reader.read_string()? != "shortcuts" {
+    return Err(String::from("File doesn't start with the shortcuts map."));
+  }
+
+  let mut checked: CheckedShortcuts = CheckedShortcuts { shortcuts: Map::new(), issues: Vec::new() };
+
+  loop {
+    let entry_start: usize = reader.offset;
+
+    let field_type: u8 = match reader.read_uint8() {
+      Ok(field_type) => field_type,
+      Err(err) => {
+        checked.issues.push((None, err));
+        break;
+      }
+    };
+
+    if field_type == 0x08 {
+      break;
+    }
+
+    let entry_res: Result<(String, Value), String> = reader.read_string().and_then(| key | {
+      if field_type != 0x00 {
+        return Err(format!("Shortcut {} isn't a map.", key));
+      }
+
+      return try_read_entry_map(&mut reader, 1).map(| entry | (key, Value::Object(entry)));
+    });
+
+    match entry_res {
+      Ok((key, entry)) => {
+        checked.shortcuts.insert(key, entry);
+      },
+      Err(err) => {
+        checked.issues.push((None, format!("Corrupt shortcut at byte {}: {}", entry_start, err)));
+
+        match find_next_shortcut_entry(&data, entry_start + 1) {
+          Some(next_entry) => reader.offset = next_entry,
+          None => break
+        }
+      }
+    }
+  }
+
+  return Ok(checked);
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  fn get_temp_vdf_path(name: &str) -> PathBuf {
+    return std::env::temp_dir().join(format!("sarm_{}_{}.vdf", name, std::process::id()));
+  }
+
+  #[test]
+  fn typed_values_round_trip() {
+    let shortcuts_data: Value = json!({
+      "shortcuts": {
+        "0": {
+          "appid": 3000000000u32,
+          "AppName": "Test Game",
+          "UnknownField": "kept as is",
+          "SortScale": typed_value("float32", String::from("1.5")),
+          "LastPlayedId": typed_value("uint64", String::from("18446744073709551615")),
+          "PlaytimeOffset": typed_value("int64", String::from("-9223372036854775808"))
+        }
+      }
+    });
+
+    let vdf_path: PathBuf = get_temp_vdf_path("typed_values_round_trip");
+    try_write_shortcuts_vdf(&vdf_path, shortcuts_data.clone()).unwrap();
+
+    let read_data: Value = try_open_binary_vdf(&vdf_path).unwrap();
+    let opened_data: Value = open_shortcuts_vdf(&vdf_path);
+    let _ = fs::remove_file(&vdf_path);
+
+    assert_eq!(read_data, shortcuts_data);
+    assert_eq!(opened_data, shortcuts_data["shortcuts"]);
+  }
+
+  #[test]
+  fn invalid_typed_values_return_errors() {
+    let invalid_fields: [Value; 4] = [
+      typed_value("float32", String::from("not a float")),
+      typed_value("uint64", String::from("-1")),
+      typed_value("int128", String::from("1")),
+      json!({ TYPED_VALUE_KEY: "int64", "value": 1 })
+    ];
+
+    for invalid_field in invalid_fields.into_iter() {
+      let vdf_path: PathBuf = get_temp_vdf_path("invalid_typed_values_return_errors");
+      let shortcuts_data: Value = json!({ "shortcuts": { "0": { "AppName": "Test Game", "Broken": invalid_field } } });
+
+      assert!(try_write_shortcuts_vdf(&vdf_path, shortcuts_data).is_err());
+      assert!(!vdf_path.exists());
+    }
+  }
+}
@@ -1,19 +1,75 @@
 
-use crate::logger;
+use crate::{logger, settings};
+use crate::shortcuts_vdf_parser::{is_binary_vdf, try_open_binary_vdf};
 
 use std::fs;
 use std::path::{ PathBuf, Path };
+use std::sync::Mutex;
 
 use serde_json::{Value, Map};
 
 #[cfg(target_os = "windows")]
 use winreg::{ enums::*, RegKey };
 
-use tauri::AppHandle;
+use tauri::{AppHandle, Manager};
 
 #[cfg(target_os = "linux")]
 use home::home_dir;
 
+use keyvalues_parser::Vdf;
+
+/// The difference between a steam user's 64 bit id and their 32 bit account id.
+const STEAM_ID64_OFFSET: u64 = 76561197960265728;
+
+/// Holds the user picked to manage art for this session, if one has been pinned.
+#[derive(Default)]
+pub struct ActiveUserOverride {
+  user_id: Mutex<Option<String>>
+}
+
+/// Converts a steam user's 32 bit account id to their 64 bit id.
+pub fn steamid32_to_64(id32: u32) -> u64 {
+  return id32 as u64 + STEAM_ID64_OFFSET;
+}
+
+/// Converts a steam user's 64 bit id to their 32 bit account id. Returns None if the id isn't a valid individual account id.
+pub fn steamid64_to_32(id64: u64) -> Option<u32> {
+  let id32: u64 = id64.checked_sub(STEAM_ID64_OFFSET)?;
+  return u32::try_from(id32).ok();
+}
+
+/// Normalizes a path's separators to forward slashes, which is how paths are passed to the frontend.
+pub fn normalize_path(path: &str) -> String {
+  return path.replace("\\", "/");
+}
+
+/// Converts a path to a string with normalized separators.
+pub fn path_to_string(path: &Path) -> String {
+  return normalize_path(path.to_str().expect("Should have been able to convert path to string."));
+}
+
+/// Resolves any symlinks in a path to the real path they point to. Returns the path unchanged if it can't be resolved.
+/// Windows is skipped, since canonicalizing there produces verbatim \\?\ paths that the webview can't load.
+pub fn resolve_real_path(path: &Path) -> PathBuf {
+  if cfg!(target_os = "windows") {
+    return path.to_path_buf();
+  }
+
+  return fs::canonicalize(path).unwrap_or_else(| _ | path.to_path_buf());
+}
+
+/// Checks if two paths point to the same file. Separators are ignored, and so is case on windows since its filesystem is case-insensitive.
+pub fn paths_match(path_a: &str, path_b: &str) -> bool {
+  let normalized_a: String = normalize_path(path_a);
+  let normalized_b: String = normalize_path(path_b);
+
+  if cfg!(target_os = "windows") {
+    return normalized_a.to_lowercase() == normalized_b.to_lowercase();
+  }
+
+  return normalized_a == normalized_b;
+}
+
 #[cfg(target_os = "windows")]
 /// Gets the steam root dir for windows systems.
 pub fn get_steam_root_dir() -> Result<PathBuf, String> {
@@ -27,7 +83,7 @@ pub fn get_steam_root_dir() -> Result<PathBuf, String> {
 
     if steam_install_path_res.is_ok() {
       let steam_install_path: String = steam_install_path_res.ok().expect("Should have been able to get steam install from registry.");
-      return Ok(Path::new(&(steam_install_path.replace("\\", "/"))).to_path_buf());
+      return Ok(Path::new(&normalize_path(&steam_install_path)).to_path_buf());
     } else {
       return Err(String::from("Couldn't get SteamPath from the registry."));
     }
@@ -36,10 +92,16 @@ pub fn get_steam_root_dir() -> Result<PathBuf, String> {
   }
 }
 
+#[cfg(target_os = "linux")]
+/// Gets the user's home dir. Some service and container contexts don't have one.
+fn get_home_dir() -> Result<PathBuf, String> {
+  return home_dir().ok_or(String::from("Couldn't find your home directory, so Steam couldn't be located. Make sure $HOME is set."));
+}
+
 #[cfg(target_os = "linux")]
 /// Gets the steam root dir for linux systems.
 pub fn get_steam_root_dir() -> Result<PathBuf, String> {
-  let mut pc_home_dir: PathBuf = home_dir().expect("Couldn't get user's home dir.");
+  let mut pc_home_dir: PathBuf = get_home_dir()?;
 
   if pc_home_dir.join(".var/app/com.valvesoftware.Steam/data/steam").exists() {
     pc_home_dir = pc_home_dir.join(".var/app/com.valvesoftware.Steam/data/steam");
@@ -54,57 +116,580 @@ pub fn get_steam_root_dir() -> Result<PathBuf, String> {
   }
 }
 
+/// Gets the steam root dir to use, preferring the provided root over the detected one.
+pub fn resolve_steam_root(root: Option<String>) -> PathBuf {
+  if root.is_some() {
+    let root_str: String = root.unwrap();
+
+    if !root_str.is_empty() {
+      return PathBuf::from(root_str);
+    }
+  }
+
+  return get_steam_root_dir().ok().expect("Steam install path should have been fine if this point is reached.");
+}
+
+#[derive(Clone, serde::Serialize)]
+/// A steam install found on this computer.
+pub struct SteamInstall {
+  kind: String,
+  root: String,
+  users: Map<String, Value>
+}
+
+#[cfg(target_os = "windows")]
+/// Gets the possible steam install locations for windows systems, along with their kind.
+fn get_steam_install_candidates() -> Vec<(String, PathBuf)> {
+  let mut candidates: Vec<(String, PathBuf)> = Vec::new();
+
+  if let Ok(steam_root) = get_steam_root_dir() {
+    candidates.push((String::from("native"), steam_root));
+  }
+
+  return candidates;
+}
+
+#[cfg(target_os = "linux")]
+/// Gets the possible steam install locations for linux systems, along with their kind.
+fn get_steam_install_candidates() -> Vec<(String, PathBuf)> {
+  let pc_home_dir: PathBuf = match get_home_dir() {
+    Ok(pc_home_dir) => pc_home_dir,
+    Err(_) => return Vec::new()
+  };
+
+  return vec![
+    (String::from("native"), pc_home_dir.join(".steam/steam")),
+    (String::from("native"), pc_home_dir.join(".local/share/Steam")),
+    (String::from("flatpak"), pc_home_dir.join(".var/app/com.valvesoftware.Steam/data/steam")),
+    (String::from("flatpak"), pc_home_dir.join(".var/app/com.valvesoftware.Steam/.local/share/Steam")),
+    (String::from("snap"), pc_home_dir.join("snap/steam/common/.local/share/Steam"))
+  ];
+}
+
+#[tauri::command]
+/// Gets every steam install on this computer, along with its users.
+pub fn get_all_steam_installs(app_handle: AppHandle) -> Vec<SteamInstall> {
+  logger::log_tagged_to_core_file(&app_handle, "steam", "Checking for steam installs...", 0);
+
+  let mut installs: Vec<SteamInstall> = Vec::new();
+  let mut seen_roots: Vec<PathBuf> = Vec::new();
+
+  for (kind, candidate) in get_steam_install_candidates().into_iter() {
+    if !candidate.join("userdata").is_dir() && !candidate.join("config/loginusers.vdf").is_file() {
+      continue;
+    }
+
+    // ? ~/.steam/steam is usually a symlink to ~/.local/share/Steam, so compare the resolved paths.
+    let resolved_root: PathBuf = fs::canonicalize(&candidate).unwrap_or(candidate.clone());
+
+    if seen_roots.contains(&resolved_root) {
+      continue;
+    }
+
+    seen_roots.push(resolved_root);
+
+    let users: Map<String, Value> = read_steam_users(&candidate);
+    let root: String = path_to_string(&candidate);
+
+    logger::log_tagged_to_core_file(&app_handle, "steam", format!("Found {} steam install at {} with {} users.", kind, root, users.len()).as_str(), 0);
+    installs.push(SteamInstall { kind, root, users });
+  }
+
+  return installs;
+}
+
+/// Creates a directory and its parents if it doesn't exist yet, like the grids dir of a new account.
+pub fn ensure_directory_exists(app_handle: &AppHandle, dir: &Path) -> bool {
+  if dir.is_dir() {
+    return true;
+  }
+
+  if let Err(err) = fs::create_dir_all(dir) {
+    logger::log_tagged_to_core_file(app_handle, "steam", format!("Failed to create {}: {}", dir.display(), err).as_str(), 2);
+    return false;
+  }
+
+  logger::log_tagged_to_core_file(app_handle, "steam", format!("Created missing directory {}.", dir.display()).as_str(), 0);
+  return true;
+}
+
+/// The setting mapping user ids to the grids dir they picked when auto-detection doesn't work for their setup.
+pub const GRIDS_DIRECTORY_OVERRIDE_SETTING: &str = "gridsDirectoryOverrideMap";
+
+/// Gets the grids dirs users picked manually, by user id.
+pub fn get_grids_directory_overrides(app_handle: &AppHandle) -> Map<String, Value> {
+  return settings::get_setting(app_handle, GRIDS_DIRECTORY_OVERRIDE_SETTING)
+    .and_then(| overrides | overrides.as_object().cloned())
+    .unwrap_or_default();
+}
+
+/// Gets the grids dir a user picked manually, if they have.
+pub fn get_grids_directory_override(app_handle: &AppHandle, steam_user_id: &str) -> Option<PathBuf> {
+  let overrides: Map<String, Value> = get_grids_directory_overrides(app_handle);
+  let override_path: &str = overrides.get(steam_user_id)?.as_str()?;
+
+  if override_path.is_empty() {
+    return None;
+  }
+
+  return Some(PathBuf::from(override_path));
+}
+
+/// Saves the grids dir a user picked manually. Returns true if it was saved.
+pub fn set_grids_directory_override(app_handle: &AppHandle, steam_user_id: &str, grids_dir: &Path) -> bool {
+  let mut overrides: Map<String, Value> = get_grids_directory_overrides(app_handle);
+  overrides.insert(steam_user_id.to_owned(), Value::String(path_to_string(grids_dir)));
+
+  return settings::set_setting(app_handle, GRIDS_DIRECTORY_OVERRIDE_SETTING, Value::Object(overrides));
+}
+
+/// Gets the path of a user's grids dir without creating it.
+pub fn get_grids_dir_path(steam_user_id: &str, root: Option<String>) -> PathBuf {
+  return resolve_steam_root(root).join("userdata").join(steam_user_id).join("config/grid");
+}
+
+/// Gets the path of a user's grids dir without creating it, using the one they picked if they have.
+pub fn get_user_grids_dir_path(app_handle: &AppHandle, steam_user_id: &str) -> PathBuf {
+  return get_grids_directory_override(app_handle, steam_user_id).unwrap_or_else(|| get_grids_dir_path(steam_user_id, None));
+}
+
+/// Lists the files in a grids dir. Steam reads grids from the top level, but some tools put them in per-app subfolders instead.
+/// A subfolder is treated as holding grids if its name is an appid (only digits), and its files are read like top-level ones.
+/// Top-level files are listed first so they take priority over nested copies, since they're the ones Steam uses.
+pub fn list_grid_files(grids_dir: &Path) -> Vec<PathBuf> {
+  let mut grid_files: Vec<PathBuf> = Vec::new();
+  let mut appid_dirs: Vec<PathBuf> = Vec::new();
+
+  let grids_dir_contents = match fs::read_dir(grids_dir) {
+    Ok(contents) => contents,
+    Err(_) => return grid_files
+  };
+
+  for dir_entry in grids_dir_contents.flatten() {
+    let path: PathBuf = dir_entry.path();
+    let is_appid_dir: bool = dir_entry.file_name().to_str().map(| name | !name.is_empty() && name.chars().all(| c | c.is_ascii_digit())).unwrap_or(false);
+
+    if path.is_file() {
+      grid_files.push(path);
+    } else if path.is_dir() && is_appid_dir {
+      appid_dirs.push(path);
+    }
+  }
+
+  for appid_dir in appid_dirs.into_iter() {
+    if let Ok(appid_dir_contents) = fs::read_dir(&appid_dir) {
+      for dir_entry in appid_dir_contents.flatten() {
+        if dir_entry.path().is_file() {
+          grid_files.push(dir_entry.path());
+        }
+      }
+    }
+  }
+
+  return grid_files;
+}
+
+/// Gets the 32 bit id of the user steam marks as the most recent one, falling back to the first user.
+fn get_most_recent_user_id(steam_root: &PathBuf) -> Option<String> {
+  let steam_users: Map<String, Value> = read_steam_users(steam_root);
+
+  let is_most_recent = | user: &&Value | user.as_object()
+    .and_then(| user_map | user_map.iter().find(| (key, _) | key.eq_ignore_ascii_case("mostrecent")))
+    .and_then(| (_, value) | value.as_str())
+    == Some("1");
+
+  let user: &Value = steam_users.values().find(is_most_recent).or_else(|| steam_users.values().next())?;
+  return user.get("id32").and_then(| id | id.as_str()).map(| id | id.to_owned());
+}
+
+/// Gets the id of the user to manage art for. Ids passed by the frontend are used as is, but empty ones fall back to the pinned user, and then to steam's most recent user.
+pub fn resolve_active_user_id(app_handle: &AppHandle, steam_active_user_id: &str) -> String {
+  if !steam_active_user_id.trim().is_empty() {
+    return steam_active_user_id.to_owned();
+  }
+
+  let override_state = app_handle.state::<ActiveUserOverride>();
+  let override_user_id: Option<String> = override_state.user_id.lock().expect("Should have been able to lock the active user override.").clone();
+
+  if let Some(user_id) = override_user_id {
+    return user_id;
+  }
+
+  return match get_steam_root_dir().ok().and_then(| steam_root | get_most_recent_user_id(&steam_root)) {
+    Some(user_id) => user_id,
+    None => {
+      logger::log_tagged_to_core_file(app_handle, "steam", "No active user was provided, and none could be detected.", 1);
+      String::new()
+    }
+  };
+}
+
+#[tauri::command]
+/// Pins the user to manage art for this session, so commands passed an empty user id use them. Passing None or an empty id goes back to detecting the user.
+pub fn set_active_user_override(app_handle: AppHandle, user_id: Option<String>) {
+  let user_id: Option<String> = user_id.filter(| id | !id.trim().is_empty());
+
+  match &user_id {
+    Some(id) => logger::log_tagged_to_core_file(&app_handle, "steam", format!("Pinned {} as the active user.", id).as_str(), 0),
+    None => logger::log_tagged_to_core_file(&app_handle, "steam", "Cleared the active user override.", 0)
+  }
+
+  let override_state = app_handle.state::<ActiveUserOverride>();
+  *override_state.user_id.lock().expect("Should have been able to lock the active user override.") = user_id;
+}
+
 #[tauri::command]
 /// Gets the steam grids directory.
-pub fn get_grids_directory(app_handle: AppHandle, steam_active_user_id: String) -> String {
-  logger::log_to_core_file(app_handle.to_owned(), "Getting steam grids folder...", 0);
+pub fn get_grids_directory(app_handle: AppHandle, steam_active_user_id: String, root: Option<String>) -> String {
+  logger::log_tagged_to_core_file(&app_handle, "steam", "Getting steam grids folder...", 0);
+  let steam_active_user_id: String = resolve_active_user_id(&app_handle, &steam_active_user_id);
   
-  let steam_root: PathBuf = get_steam_root_dir().ok().expect("Steam install path should have been fine if this point is reached.");
-  let grids_dir: String = steam_root.join("userdata").join(steam_active_user_id.to_string()).join("config/grid").to_str().expect("Should have been able to convert to a string.").to_owned().replace("\\", "/");
+  // ? A picked grids dir only replaces the detected one, not one in an explicitly provided steam install.
+  let grids_dir_path: PathBuf = if root.is_none() { get_grids_directory_override(&app_handle, &steam_active_user_id) } else { None }
+    .unwrap_or_else(|| get_grids_dir_path(&steam_active_user_id, root));
+  let grids_dir: String = path_to_string(&grids_dir_path);
 
-  let dir_create_res = fs::create_dir_all(grids_dir.clone());
-  if dir_create_res.is_err() {
-    logger::log_to_core_file(app_handle.to_owned(), "Should have been able to create the grids dir!", 2);
+  if !ensure_directory_exists(&app_handle, Path::new(&grids_dir)) {
     panic!("Should have been able to create the grids dir!");
   }
 
+  // ? Some users symlink the grids dir to another drive. Its real path isn't covered by steam's scope, so images saved there wouldn't load.
+  let real_grids_dir_path: PathBuf = resolve_real_path(&grids_dir_path);
+
+  if !paths_match(&path_to_string(&real_grids_dir_path), &grids_dir) {
+    logger::log_tagged_to_core_file(&app_handle, "steam", format!("Grids folder {} resolves to {}.", grids_dir, real_grids_dir_path.display()).as_str(), 0);
+
+    if !app_handle.asset_protocol_scope().is_allowed(&real_grids_dir_path) {
+      crate::add_directory_to_scope(&app_handle, &real_grids_dir_path, "grids symlink target");
+    }
+
+    return path_to_string(&real_grids_dir_path);
+  }
+
   return grids_dir;
 }
 
 #[tauri::command]
 /// Gets the steam library cache directory.
-pub fn get_library_cache_directory(app_handle: AppHandle) -> String {
-  logger::log_to_core_file(app_handle.to_owned(), "Getting steam library cache folder...", 0);
+pub fn get_library_cache_directory(app_handle: AppHandle, root: Option<String>) -> String {
+  logger::log_tagged_to_core_file(&app_handle, "steam", "Getting steam library cache folder...", 0);
   
-  let steam_root: PathBuf = get_steam_root_dir().ok().expect("Steam install path should have been fine if this point is reached.");
-  return steam_root.join("appcache/librarycache").to_str().expect("Should have been able to convert to a string.").to_owned().replace("\\", "/");
+  let steam_root: PathBuf = resolve_steam_root(root);
+  return path_to_string(&steam_root.join("appcache/librarycache"));
+}
+
+/// Gets the steam library folders listed in steamapps/libraryfolders.vdf, excluding the steam install itself.
+/// Falls back to the ones in config/config.vdf if there isn't a libraryfolders.vdf.
+pub fn get_library_folders(steam_root: &Path) -> Vec<PathBuf> {
+  let mut library_folders: Vec<PathBuf> = Vec::new();
+
+  let contents: String = match fs::read_to_string(steam_root.join("steamapps/libraryfolders.vdf")) {
+    Ok(contents) => contents,
+    Err(_) => return get_config_library_folders(steam_root)
+  };
+
+  let vdf = match Vdf::parse(&contents) {
+    Ok(vdf) => vdf,
+    Err(_) => return library_folders
+  };
+
+  if let Some(folders_obj) = vdf.value.get_obj() {
+    for (_, entries) in folders_obj.iter() {
+      // ? Older versions of the file map the index straight to the path, newer ones nest it under "path".
+      let folder_path: Option<&str> = match entries.first() {
+        Some(keyvalues_parser::Value::Str(path)) => Some(path.as_ref()),
+        Some(keyvalues_parser::Value::Obj(folder_obj)) => folder_obj.get("path").and_then(| paths | paths.first()).and_then(| path | path.get_str()),
+        None => None
+      };
+
+      if let Some(folder_path) = folder_path {
+        let folder: PathBuf = PathBuf::from(folder_path.replace("\\\\", "\\"));
+
+        if folder != steam_root && !library_folders.contains(&folder) {
+          library_folders.push(folder);
+        }
+      }
+    }
+  }
+
+  return library_folders;
+}
+
+/// Gets the steam library folders older versions of steam listed in config/config.vdf as BaseInstallFolder_1, BaseInstallFolder_2, etc.
+fn get_config_library_folders(steam_root: &Path) -> Vec<PathBuf> {
+  let mut library_folders: Vec<PathBuf> = Vec::new();
+
+  let config: Map<String, Value> = match read_config_vdf_json(steam_root) {
+    Ok(config) => config,
+    Err(_) => return library_folders
+  };
+
+  let mut steam_config: Option<&Map<String, Value>> = Some(&config);
+
+  // ? Steam isn't consistent about the case of these keys.
+  for key in ["InstallConfigStore", "Software", "Valve", "Steam"].iter() {
+    steam_config = steam_config
+      .and_then(| obj | obj.iter().find(| (child_key, _) | child_key.eq_ignore_ascii_case(key)))
+      .and_then(| (_, child) | child.as_object());
+  }
+
+  let mut folder_keys: Vec<(u32, &str)> = steam_config.into_iter().flat_map(| obj | obj.iter()).filter_map(| (key, value) | {
+    let index: u32 = key.to_lowercase().strip_prefix("baseinstallfolder_")?.parse::<u32>().ok()?;
+    return Some((index, value.as_str()?));
+  }).collect();
+  folder_keys.sort();
+
+  for (_, folder_path) in folder_keys.into_iter() {
+    let folder: PathBuf = PathBuf::from(folder_path.replace("\\\\", "\\"));
+
+    if folder != steam_root && !library_folders.contains(&folder) {
+      library_folders.push(folder);
+    }
+  }
+
+  return library_folders;
+}
+
+/// Gets the library cache dirs of the steam install and every library folder that has one. The install's cache always comes first.
+pub fn get_library_cache_dirs(root: Option<String>) -> Vec<PathBuf> {
+  let steam_root: PathBuf = resolve_steam_root(root);
+  let mut cache_dirs: Vec<PathBuf> = vec![steam_root.join("appcache/librarycache")];
+
+  for library_folder in get_library_folders(&steam_root).into_iter() {
+    let cache_dir: PathBuf = library_folder.join("appcache/librarycache");
+
+    if cache_dir.is_dir() {
+      cache_dirs.push(cache_dir);
+    }
+  }
+
+  return cache_dirs;
+}
+
+#[tauri::command]
+/// Gets the library cache directories of every steam library folder, starting with the steam install's.
+pub fn get_library_cache_directories(app_handle: AppHandle, root: Option<String>) -> Vec<String> {
+  logger::log_tagged_to_core_file(&app_handle, "steam", "Getting steam library cache folders...", 0);
+
+  return get_library_cache_dirs(root).into_iter()
+    .map(| cache_dir | path_to_string(&cache_dir))
+    .collect();
+}
+
+#[tauri::command]
+/// Gets the steam library folder an app is installed in, based on which one has its appmanifest. Returns None if it isn't installed.
+pub fn get_app_library(app_handle: AppHandle, appid: String) -> Option<String> {
+  if appid.is_empty() || !appid.chars().all(| c | c.is_ascii_digit()) {
+    logger::log_tagged_to_core_file(&app_handle, "steam", format!("Can't look up the library of invalid appid {}.", appid).as_str(), 1);
+    return None;
+  }
+
+  let steam_root: PathBuf = resolve_steam_root(None);
+  let manifest_name: String = format!("appmanifest_{}.acf", appid);
+  let mut libraries: Vec<PathBuf> = vec![steam_root.to_owned()];
+  libraries.extend(get_library_folders(&steam_root));
+
+  return libraries.into_iter()
+    .find(| library | library.join("steamapps").join(&manifest_name).is_file())
+    .map(| library | path_to_string(&library));
+}
+
+#[derive(Clone, serde::Serialize)]
+#[allow(non_snake_case)]
+pub struct ArtLocations {
+  gridsDir: String,
+  libraryCacheDirs: Vec<String>,
+  libraryCacheLayout: String
+}
+
+/// Detects how library caches store art. Older steam clients name files appid_type.ext at the top level ("flat"),
+/// while newer ones give each app its own folder, with some art nested a level further in hashed folders ("nested").
+/// Caches that were partly migrated are "mixed", and ones without any art yet are "empty".
+pub fn library_cache_layout(cache_dirs: &[PathBuf]) -> &'static str {
+  let mut has_flat: bool = false;
+  let mut has_nested: bool = false;
+
+  for cache_dir in cache_dirs.iter() {
+    for entry in fs::read_dir(cache_dir).into_iter().flatten().flatten() {
+      let name: String = entry.file_name().to_string_lossy().into_owned();
+
+      if entry.path().is_dir() && !name.is_empty() && name.chars().all(| c | c.is_ascii_digit()) {
+        has_nested = true;
+      } else if name.split_once("_").is_some_and(| (appid, _) | !appid.is_empty() && appid.chars().all(| c | c.is_ascii_digit())) {
+        has_flat = true;
+      }
+
+      if has_flat && has_nested {
+        return "mixed";
+      }
+    }
+  }
+
+  return match (has_flat, has_nested) {
+    (true, true) => "mixed",
+    (false, true) => "nested",
+    (true, false) => "flat",
+    (false, false) => "empty"
+  };
+}
+
+/// Checks if a file is an image steam could have cached, going by its extension.
+fn is_library_cache_image(path: &Path) -> bool {
+  let extension: String = path.extension().and_then(| extension | extension.to_str()).map(| extension | format!(".{}", extension.to_lowercase())).unwrap_or_default();
+  return path.is_file() && crate::GRID_IMAGE_EXTENSIONS.contains(&extension.as_str());
+}
+
+/// Lists the images in a dir, sorted so the same one wins on every run.
+fn list_sorted_images(dir: &Path) -> Vec<PathBuf> {
+  let mut images: Vec<PathBuf> = fs::read_dir(dir).into_iter().flatten().flatten().map(| entry | entry.path()).filter(| path | is_library_cache_image(path)).collect();
+  images.sort();
+
+  return images;
+}
+
+/// Lists the art in a library cache dir as (appid, art name, path), reading whichever layouts the dir uses.
+/// Flat art comes first, then each app folder's own files before the ones in its hashed folders, so earlier entries take priority.
+fn list_library_cache_dir_art(cache_dir: &PathBuf) -> Vec<(String, String, PathBuf)> {
+  let layout: &str = library_cache_layout(std::slice::from_ref(cache_dir));
+  let mut art: Vec<(String, String, PathBuf)> = Vec::new();
+
+  if layout == "flat" || layout == "mixed" {
+    for path in list_sorted_images(cache_dir).into_iter() {
+      let stem: String = path.file_stem().and_then(| stem | stem.to_str()).unwrap_or("").to_owned();
+
+      if let Some((appid, art_name)) = stem.split_once("_").filter(| (appid, _) | !appid.is_empty() && appid.chars().all(| c | c.is_ascii_digit())) {
+        art.push((appid.to_owned(), art_name.to_owned(), path.to_owned()));
+      }
+    }
+  }
+
+  if layout == "nested" || layout == "mixed" {
+    let mut app_dirs: Vec<PathBuf> = fs::read_dir(cache_dir).into_iter().flatten().flatten()
+      .filter(| entry | entry.file_name().to_str().is_some_and(| name | !name.is_empty() && name.chars().all(| c | c.is_ascii_digit())))
+      .map(| entry | entry.path())
+      .filter(| path | path.is_dir())
+      .collect();
+    app_dirs.sort();
+
+    for app_dir in app_dirs.into_iter() {
+      let appid: String = app_dir.file_name().and_then(| name | name.to_str()).unwrap_or("").to_owned();
+
+      let mut hashed_dirs: Vec<PathBuf> = fs::read_dir(&app_dir).into_iter().flatten().flatten().map(| entry | entry.path()).filter(| path | path.is_dir()).collect();
+      hashed_dirs.sort();
+
+      let mut image_paths: Vec<PathBuf> = list_sorted_images(&app_dir);
+      image_paths.extend(hashed_dirs.iter().flat_map(| hashed_dir | list_sorted_images(hashed_dir)));
+
+      for path in image_paths.into_iter() {
+        let art_name: String = path.file_stem().and_then(| stem | stem.to_str()).unwrap_or("").to_owned();
+        art.push((appid.to_owned(), art_name, path));
+      }
+    }
+  }
+
+  return art;
+}
+
+#[derive(Clone, serde::Serialize)]
+#[allow(non_snake_case)]
+pub struct LibraryCacheArt {
+  appId: String,
+  artName: String,
+  path: String
+}
+
+#[tauri::command]
+/// Lists the art in every steam library cache, whether it uses the flat or per-app folder layout. Art names are steam's, like library_600x900 or header.
+/// Caches are listed in the same order as get_library_cache_directories, and earlier entries for the same app and art name take priority.
+pub fn get_library_cache_art(app_handle: AppHandle, root: Option<String>) -> Vec<LibraryCacheArt> {
+  let mut cache_art: Vec<LibraryCacheArt> = Vec::new();
+
+  for cache_dir in get_library_cache_dirs(root).iter() {
+    let dir_art: Vec<(String, String, PathBuf)> = list_library_cache_dir_art(cache_dir);
+    logger::log_tagged_to_core_file(&app_handle, "steam", format!("Found {} cached images in {}.", dir_art.len(), cache_dir.display()).as_str(), 0);
+
+    cache_art.extend(dir_art.into_iter().map(| (appid, art_name, path) | LibraryCacheArt { appId: appid, artName: art_name, path: path_to_string(&path) }));
+  }
+
+  return cache_art;
+}
+
+#[tauri::command]
+/// Gets where steam reads art from for a user. Both the desktop library and Big Picture (gamepadui) read custom art from the same grids dir,
+/// so there's no separate Big Picture location to write to. They differ only in how they fall back to the official art in the library cache.
+pub fn get_art_locations(app_handle: AppHandle, steam_active_user_id: String) -> ArtLocations {
+  let grids_dir: String = get_grids_directory(app_handle.to_owned(), steam_active_user_id, None);
+  let cache_dirs: Vec<PathBuf> = get_library_cache_dirs(None);
+  let layout: &str = library_cache_layout(&cache_dirs);
+
+  logger::log_tagged_to_core_file(&app_handle, "steam", format!("Library cache layout is {}.", layout).as_str(), 0);
+
+  return ArtLocations {
+    gridsDir: grids_dir,
+    libraryCacheDirs: cache_dirs.iter().map(| cache_dir | path_to_string(cache_dir)).collect(),
+    libraryCacheLayout: layout.to_owned()
+  };
 }
 
 #[tauri::command]
 /// Gets the steam appinfo.vdf path.
-pub fn get_appinfo_path(app_handle: AppHandle) -> String {
-  logger::log_to_core_file(app_handle.to_owned(), "Getting steam appinfo.vdf...", 0);
+pub fn get_appinfo_path(app_handle: AppHandle, root: Option<String>) -> String {
+  logger::log_tagged_to_core_file(&app_handle, "steam", "Getting steam appinfo.vdf...", 0);
   
-  let steam_root: PathBuf = get_steam_root_dir().ok().expect("Steam install path should have been fine if this point is reached.");
-  return steam_root.join("appcache/appinfo.vdf").to_str().expect("Should have been able to convert to a string.").to_owned().replace("\\", "/");
+  let steam_root: PathBuf = resolve_steam_root(root);
+  return path_to_string(&steam_root.join("appcache/appinfo.vdf"));
 }
 
 #[tauri::command]
 /// Gets the steam shortcuts.vdf path.
-pub fn get_shortcuts_path(app_handle: AppHandle, steam_active_user_id: String) -> String {
-  logger::log_to_core_file(app_handle.to_owned(), "Getting steam shortcuts.vdf...", 0);
+pub fn get_shortcuts_path(app_handle: AppHandle, steam_active_user_id: String, root: Option<String>) -> String {
+  logger::log_tagged_to_core_file(&app_handle, "steam", "Getting steam shortcuts.vdf...", 0);
+  let steam_active_user_id: String = resolve_active_user_id(&app_handle, &steam_active_user_id);
   
-  let steam_root: PathBuf = get_steam_root_dir().ok().expect("Steam install path should have been fine if this point is reached.");
-  return steam_root.join("userdata").join(steam_active_user_id.to_string()).join("config/shortcuts.vdf").to_str().expect("Should have been able to convert to a string.").to_owned().replace("\\", "/");
+  let steam_root: PathBuf = resolve_steam_root(root);
+  return path_to_string(&steam_root.join("userdata").join(steam_active_user_id.to_string()).join("config/shortcuts.vdf"));
 }
 
 #[tauri::command]
 /// Gets the steam localconfig.vdf path.
-pub fn get_localconfig_path(app_handle: AppHandle, steam_active_user_id: String) -> String {
-  logger::log_to_core_file(app_handle.to_owned(), "Getting steam localconfig.vdf...", 0);
+pub fn get_localconfig_path(app_handle: AppHandle, steam_active_user_id: String, root: Option<String>) -> String {
+  logger::log_tagged_to_core_file(&app_handle, "steam", "Getting steam localconfig.vdf...", 0);
+  let steam_active_user_id: String = resolve_active_user_id(&app_handle, &steam_active_user_id);
   
-  let steam_root: PathBuf = get_steam_root_dir().ok().expect("Steam install path should have been fine if this point is reached.");
-  return steam_root.join("userdata").join(steam_active_user_id.to_string()).join("config/localconfig.vdf").to_str().expect("Should have been able to convert to a string.").to_owned().replace("\\", "/");
+  let steam_root: PathBuf = resolve_steam_root(root);
+  return path_to_string(&steam_root.join("userdata").join(steam_active_user_id.to_string()).join("config/localconfig.vdf"));
+}
+
+#[tauri::command]
+/// Gets the path of a steam user's cached avatar, if steam has cached one.
+pub fn get_user_avatar_path(app_handle: AppHandle, steam_user_id: String, root: Option<String>) -> Option<String> {
+  let id_32: u32 = match steam_user_id.parse::<u32>() {
+    Ok(id) => id,
+    Err(_) => {
+      logger::log_tagged_to_core_file(&app_handle, "steam", format!("Invalid steam user id {}.", steam_user_id).as_str(), 1);
+      return None;
+    }
+  };
+  let id_64: u64 = steamid32_to_64(id_32);
+
+  let steam_root: PathBuf = resolve_steam_root(root);
+  let avatar_dirs: [PathBuf; 2] = [
+    steam_root.join("config/avatarcache"),
+    steam_root.join("appcache/librarycache/avatarcache")
+  ];
+
+  for avatar_dir in avatar_dirs.iter() {
+    for extension in ["png", "jpg"] {
+      let avatar_path: PathBuf = avatar_dir.join(format!("{}.{}", id_64, extension));
+
+      if avatar_path.is_file() {
+        return Some(path_to_string(&avatar_path));
+      }
+    }
+  }
+
+  logger::log_tagged_to_core_file(&app_handle, "steam", format!("No cached avatar found for user {}.", steam_user_id).as_str(), 0);
+  return None;
 }
 
 /// Reads a steam user's id.
@@ -114,9 +699,9 @@ fn read_steam_user_id(user_block: &str) -> String {
   return id_str.to_owned();
 }
 
-/// Reads a steam user.
-fn read_steam_user(user_id: &str, user_block: &str) -> Map<String, Value> {
-  let id_32 = user_id.parse::<u64>().unwrap() - 76561197960265728;
+/// Reads a steam user. Returns None if their id is malformed.
+fn read_steam_user(user_id: &str, user_block: &str) -> Option<Map<String, Value>> {
+  let id_32: u32 = steamid64_to_32(user_id.parse::<u64>().ok()?)?;
 
   let mut steam_user: Map<String, Value> = Map::new();
   steam_user.insert("id64".to_owned(), Value::String(user_id.to_owned()));
@@ -138,15 +723,19 @@ fn read_steam_user(user_id: &str, user_block: &str) -> Map<String, Value> {
     }
   }
 
-  return steam_user;
+  return Some(steam_user);
 }
 
-/// Reads the steam users.
-fn read_steam_users() -> Map<String, Value> {
+/// Reads the steam users of the provided steam install.
+fn read_steam_users(steam_root: &PathBuf) -> Map<String, Value> {
   let mut steam_users: Map<String, Value> = Map::new();
     
-  let steam_root: PathBuf = get_steam_root_dir().ok().expect("Steam install path should have been fine if this point is reached.");
   let loginusers_vdf: PathBuf = steam_root.join("config/loginusers.vdf");
+
+  if !loginusers_vdf.exists() {
+    return steam_users;
+  }
+
   let contents: String = fs::read_to_string(loginusers_vdf).unwrap();
 
   let id_start_matches: Vec<(usize, &str)> = contents.match_indices("\n\t\"").collect();
@@ -158,22 +747,419 @@ fn read_steam_users() -> Map<String, Value> {
 
     let id: String = read_steam_user_id(&user_block);
     
-    let user_map: Map<String, Value> = read_steam_user(&id, &user_block[(id.len() + 4)..]);
-
-    steam_users.insert(id.to_string(), Value::Object(user_map));
+    // ? Skip entries with malformed ids rather than failing to load every user.
+    if let Some(user_map) = read_steam_user(&id, &user_block[(id.len() + 4)..]) {
+      steam_users.insert(id.to_string(), Value::Object(user_map));
+    }
   }
 
   return steam_users;
 }
 
+/// Converts a text VDF object to JSON. Only the first entry of repeated keys is kept.
+pub fn vdf_obj_to_json(obj: &keyvalues_parser::Obj) -> Map<String, Value> {
+  let mut json: Map<String, Value> = Map::new();
+
+  for (key, entries) in obj.iter() {
+    let value: Value = match entries.first() {
+      Some(keyvalues_parser::Value::Str(val)) => Value::String(val.to_string()),
+      Some(keyvalues_parser::Value::Obj(child)) => Value::Object(vdf_obj_to_json(child)),
+      None => continue
+    };
+
+    json.insert(key.to_string(), value);
+  }
+
+  return json;
+}
+
+#[tauri::command]
+/// Reads config/loginusers.vdf, mapping each user's 64 bit id to everything steam stores about them.
+pub fn read_loginusers(app_handle: AppHandle) -> String {
+  let loginusers_vdf: PathBuf = match get_steam_root_dir() {
+    Ok(steam_root) => steam_root.join("config/loginusers.vdf"),
+    Err(err) => {
+      logger::log_tagged_to_core_file(&app_handle, "steam", format!("Couldn't find steam root to read loginusers.vdf: {}", err).as_str(), 2);
+      return "{}".to_owned();
+    }
+  };
+
+  let contents: String = match fs::read_to_string(&loginusers_vdf) {
+    Ok(contents) => contents,
+    Err(err) => {
+      logger::log_tagged_to_core_file(&app_handle, "steam", format!("Failed to read {}: {}", loginusers_vdf.display(), err).as_str(), 1);
+      return "{}".to_owned();
+    }
+  };
+
+  let users: Map<String, Value> = match Vdf::parse(&contents) {
+    Ok(vdf) => vdf.value.get_obj().map(vdf_obj_to_json).unwrap_or_default(),
+    Err(err) => {
+      logger::log_tagged_to_core_file(&app_handle, "steam", format!("Failed to parse loginusers.vdf: {}", err).as_str(), 2);
+      return "{}".to_owned();
+    }
+  };
+
+  logger::log_tagged_to_core_file(&app_handle, "steam", format!("Read {} users from loginusers.vdf.", users.len()).as_str(), 0);
+
+  return serde_json::to_string(&users).expect("Should have been able to serialize loginusers.vdf.");
+}
+
+/// Reads config/config.vdf as JSON keyed by its root key. Steam writes it as text, but both the text and binary vdf formats are supported.
+fn read_config_vdf_json(steam_root: &Path) -> Result<Map<String, Value>, String> {
+  let config_path: PathBuf = steam_root.join("config/config.vdf");
+  let contents: Vec<u8> = fs::read(&config_path).map_err(| err | format!("Failed to read {}: {}", config_path.display(), err))?;
+
+  if is_binary_vdf(&contents) {
+    return match try_open_binary_vdf(&config_path)? {
+      Value::Object(config) => Ok(config),
+      _ => Err(String::from("config.vdf should have been an object."))
+    };
+  }
+
+  let text: String = String::from_utf8_lossy(&contents).into_owned();
+  let vdf = Vdf::parse(&text).map_err(| err | format!("Failed to parse config.vdf: {}", err))?;
+
+  let mut config: Map<String, Value> = Map::new();
+  config.insert(vdf.key.to_string(), Value::Object(vdf.value.get_obj().map(vdf_obj_to_json).unwrap_or_default()));
+
+  return Ok(config);
+}
+
+#[tauri::command]
+/// Reads config/config.vdf, which holds install wide settings like library folders and depot info, as JSON.
+pub fn read_config_vdf(app_handle: AppHandle) -> String {
+  let steam_root: PathBuf = match get_steam_root_dir() {
+    Ok(steam_root) => steam_root,
+    Err(err) => {
+      logger::log_tagged_to_core_file(&app_handle, "steam", format!("Couldn't find steam root to read config.vdf: {}", err).as_str(), 2);
+      return "{}".to_owned();
+    }
+  };
+
+  return match read_config_vdf_json(&steam_root) {
+    Ok(config) => {
+      logger::log_tagged_to_core_file(&app_handle, "steam", "Read config.vdf.", 0);
+      serde_json::to_string(&config).expect("Should have been able to serialize config.vdf.")
+    },
+    Err(err) => {
+      logger::log_tagged_to_core_file(&app_handle, "steam", &err, 2);
+      "{}".to_owned()
+    }
+  };
+}
+
+#[derive(Clone, serde::Serialize)]
+#[allow(non_snake_case)]
+pub struct SteamApp {
+  pub appId: u32,
+  pub name: String
+}
+
+#[cfg(target_os = "windows")]
+/// Reads the apps steam has stored in the registry for windows systems.
+fn read_registry_apps() -> Vec<(String, String)> {
+  let mut steam_apps: Vec<(String, String)> = Vec::new();
+
+  let hkcu: RegKey = RegKey::predef(HKEY_CURRENT_USER);
+  let apps_key_res = hkcu.open_subkey("SOFTWARE\\Valve\\Steam\\Apps");
+
+  // ? Steam doesn't create the Apps key until an app has been installed.
+  if apps_key_res.is_err() {
+    return steam_apps;
+  }
+
+  let apps_key: RegKey = apps_key_res.unwrap();
+
+  for appid_res in apps_key.enum_keys() {
+    if appid_res.is_err() {
+      continue;
+    }
+
+    let appid: String = appid_res.unwrap();
+    let app_key_res = apps_key.open_subkey(&appid);
+    let mut name: String = String::from("");
+
+    if app_key_res.is_ok() {
+      name = app_key_res.unwrap().get_value("Name").unwrap_or(String::from(""));
+    }
+
+    steam_apps.push((appid, name));
+  }
+
+  return steam_apps;
+}
+
+#[cfg(target_os = "linux")]
+/// Gets the first entry of a key in a VDF object, if it is an object.
+fn get_vdf_child<'a, 'b>(obj: &'b keyvalues_parser::Obj<'a>, key: &str) -> Option<&'b keyvalues_parser::Obj<'a>> {
+  return obj.get(key)?.first()?.get_obj();
+}
+
+#[cfg(target_os = "linux")]
+/// Gets the path of registry.vdf, where steam keeps what it would store in the registry on windows.
+fn get_registry_vdf_path() -> Option<PathBuf> {
+  let pc_home_dir: PathBuf = get_home_dir().ok()?;
+
+  if pc_home_dir.join(".var/app/com.valvesoftware.Steam/.steam/registry.vdf").exists() {
+    return Some(pc_home_dir.join(".var/app/com.valvesoftware.Steam/.steam/registry.vdf"));
+  }
+
+  return Some(pc_home_dir.join(".steam/registry.vdf"));
+}
+
+#[cfg(target_os = "linux")]
+/// Reads the apps steam has stored in registry.vdf for linux systems.
+fn read_registry_apps() -> Vec<(String, String)> {
+  let registry_path: PathBuf = match get_registry_vdf_path() {
+    Some(registry_path) => registry_path,
+    None => return Vec::new()
+  };
+
+  return match fs::read_to_string(registry_path) {
+    Ok(registry_contents) => parse_registry_vdf_apps(&registry_contents),
+    Err(_) => Vec::new()
+  };
+}
+
+#[cfg(target_os = "linux")]
+/// Gets the apps listed in the contents of registry.vdf.
+fn parse_registry_vdf_apps(registry_contents: &str) -> Vec<(String, String)> {
+  let mut steam_apps: Vec<(String, String)> = Vec::new();
+
+  let vdf_res = Vdf::parse(registry_contents);
+  if vdf_res.is_err() {
+    return steam_apps;
+  }
+
+  let vdf = vdf_res.unwrap();
+
+  // ? Steam doesn't create the apps section until an app has been installed.
+  let apps_res = vdf.value.get_obj()
+    .and_then(| root | get_vdf_child(root, "HKCU"))
+    .and_then(| hkcu | get_vdf_child(hkcu, "Software"))
+    .and_then(| software | get_vdf_child(software, "Valve"))
+    .and_then(| valve | get_vdf_child(valve, "Steam"))
+    .and_then(| steam | get_vdf_child(steam, "apps"));
+
+  if apps_res.is_none() {
+    return steam_apps;
+  }
+
+  for (appid, app_entries) in apps_res.unwrap().iter() {
+    let mut name: String = String::from("");
+
+    if let Some(app_obj) = app_entries.first().and_then(| entry | entry.get_obj()) {
+      if let Some(name_entry) = app_obj.get("name").and_then(| entries | entries.first()) {
+        name = name_entry.get_str().unwrap_or("").to_owned();
+      }
+    }
+
+    steam_apps.push((appid.to_string(), name));
+  }
+
+  return steam_apps;
+}
+
+#[cfg(target_os = "windows")]
+/// Reads the language steam's client is set to from the registry for windows systems.
+fn read_steam_language() -> Option<String> {
+  let hkcu: RegKey = RegKey::predef(HKEY_CURRENT_USER);
+  let steam_key: RegKey = hkcu.open_subkey("SOFTWARE\\Valve\\Steam").ok()?;
+
+  return steam_key.get_value::<String, _>("Language").ok();
+}
+
+#[cfg(target_os = "linux")]
+/// Reads a string from a text vdf file, following the provided keys down to it. Keys are matched ignoring case, since steam isn't consistent about it.
+fn read_vdf_str(path: &Path, keys: &[&str]) -> Option<String> {
+  let contents: String = fs::read_to_string(path).ok()?;
+  let vdf = Vdf::parse(&contents).ok()?;
+  let (value_key, obj_keys) = keys.split_last()?;
+  let mut obj: &keyvalues_parser::Obj = vdf.value.get_obj()?;
+
+  for key in obj_keys.iter() {
+    let (_, entries) = obj.iter().find(| (child_key, _) | child_key.eq_ignore_ascii_case(key))?;
+    obj = entries.first()?.get_obj()?;
+  }
+
+  let (_, entries) = obj.iter().find(| (child_key, _) | child_key.eq_ignore_ascii_case(value_key))?;
+  return entries.first()?.get_str().map(| value | value.to_owned());
+}
+
+#[cfg(target_os = "linux")]
+/// Reads the language steam's client is set to from registry.vdf for linux systems, falling back to config/config.vdf.
+fn read_steam_language() -> Option<String> {
+  let registry_language: Option<String> = get_registry_vdf_path().and_then(| registry_path | read_vdf_str(&registry_path, &["HKCU", "Software", "Valve", "Steam", "language"]));
+
+  return registry_language.or_else(|| {
+    let config_path: PathBuf = get_steam_root_dir().ok()?.join("config/config.vdf");
+    return read_vdf_str(&config_path, &["InstallConfigStore", "Software", "Valve", "Steam", "Language"]);
+  });
+}
+
+#[tauri::command]
+/// Gets the language steam's client is set to, like "english" or "schinese". Defaults to english if it isn't set.
+pub fn get_steam_language(app_handle: AppHandle) -> String {
+  let language: String = read_steam_language()
+    .map(| language | language.trim().to_lowercase())
+    .filter(| language | !language.is_empty())
+    .unwrap_or_else(|| {
+      logger::log_tagged_to_core_file(&app_handle, "steam", "Couldn't find steam's language, defaulting to english.", 1);
+      return String::from("english");
+    });
+
+  logger::log_tagged_to_core_file(&app_handle, "steam", format!("Steam's language is {}.", language).as_str(), 0);
+  return language;
+}
+
+#[tauri::command]
+/// Gets the apps steam knows about.
+pub fn get_steam_apps(app_handle: AppHandle) -> Vec<SteamApp> {
+  logger::log_tagged_to_core_file(&app_handle, "steam", "Reading steam apps from the registry...", 0);
+
+  let mut steam_apps: Vec<SteamApp> = Vec::new();
+
+  for (appid, name) in read_registry_apps().into_iter() {
+    let appid_res = appid.parse::<u32>();
+
+    if appid_res.is_ok() {
+      steam_apps.push(SteamApp { appId: appid_res.unwrap(), name });
+    } else {
+      logger::log_tagged_to_core_file(&app_handle, "steam", format!("Skipping app with invalid id {}.", appid).as_str(), 1);
+    }
+  }
+
+  logger::log_tagged_to_core_file(&app_handle, "steam", format!("Loaded {} steam apps.", steam_apps.len()).as_str(), 0);
+
+  return steam_apps;
+}
+
 #[tauri::command]
 /// Gets all steam users that have logged in on this computer.
 pub fn get_steam_users(app_handle: AppHandle) -> String {
-  logger::log_to_core_file(app_handle.to_owned(), "Checking config/loginusers.vdf for current user info.", 0);
+  logger::log_tagged_to_core_file(&app_handle, "steam", "Checking config/loginusers.vdf for current user info.", 0);
     
-  let steam_users = read_steam_users();
+  let steam_root: PathBuf = get_steam_root_dir().ok().expect("Steam install path should have been fine if this point is reached.");
+  let steam_users = read_steam_users(&steam_root);
   
-  logger::log_to_core_file(app_handle.to_owned(), format!("Loaded {} steam users.", steam_users.len()).as_str(), 0);
+  logger::log_tagged_to_core_file(&app_handle, "steam", format!("Loaded {} steam users.", steam_users.len()).as_str(), 0);
 
   return serde_json::to_string(&steam_users).unwrap();
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn steam_apps_serialize_names_with_quotes_and_emoji() {
+    let names: [&str; 3] = ["The \"Quoted\" Game", "Back\\slash", "Pokémon ✨ 🎮"];
+    let steam_apps: Vec<SteamApp> = names.iter().enumerate().map(| (index, name) | SteamApp { appId: index as u32, name: name.to_string() }).collect();
+
+    let apps_json: String = serde_json::to_string(&steam_apps).unwrap();
+    let parsed: Vec<Value> = serde_json::from_str(&apps_json).unwrap();
+
+    for (index, name) in names.iter().enumerate() {
+      assert_eq!(parsed[index]["name"], Value::String(name.to_string()));
+    }
+  }
+
+  #[cfg(target_os = "linux")]
+  #[test]
+  fn registry_vdf_apps_keep_unicode_names() {
+    let registry_contents: &str = r#""Registry"
+{
+	"HKCU"
+	{
+		"Software"
+		{
+			"Valve"
+			{
+				"Steam"
+				{
+					"apps"
+					{
+						"1234"
+						{
+							"name"		"Pokémon ✨ 🎮"
+						}
+						"5678"
+						{
+							"installed"		"1"
+						}
+					}
+				}
+			}
+		}
+	}
+}"#;
+
+    let apps: Vec<(String, String)> = parse_registry_vdf_apps(registry_contents);
+    assert_eq!(apps, vec![(String::from("1234"), String::from("Pokémon ✨ 🎮")), (String::from("5678"), String::new())]);
+  }
+
+  #[test]
+  fn no_steam_apps_serialize_to_an_empty_array() {
+    assert_eq!(serde_json::to_string(&Vec::<SteamApp>::new()).unwrap(), "[]");
+  }
+
+  #[cfg(target_os = "linux")]
+  #[test]
+  fn registry_vdf_without_apps_has_no_apps() {
+    let no_apps_registry: &str = r#""Registry"
+{
+	"HKCU"
+	{
+		"Software"
+		{
+			"Valve"
+			{
+				"Steam"
+				{
+					"language"		"english"
+				}
+			}
+		}
+	}
+}"#;
+
+    assert!(parse_registry_vdf_apps("").is_empty());
+    assert!(parse_registry_vdf_apps(no_apps_registry).is_empty());
+  }
+
+  #[test]
+  fn steamid_conversions_round_trip() {
+    assert_eq!(steamid32_to_64(0), 76561197960265728);
+    assert_eq!(steamid32_to_64(12345678), 76561197972611406);
+    assert_eq!(steamid64_to_32(76561197972611406), Some(12345678));
+    assert_eq!(steamid64_to_32(steamid32_to_64(u32::MAX)), Some(u32::MAX));
+  }
+
+  #[test]
+  fn steamid64_to_32_rejects_out_of_range_ids() {
+    assert_eq!(steamid64_to_32(0), None);
+    assert_eq!(steamid64_to_32(76561197960265727), None);
+    assert_eq!(steamid64_to_32(steamid32_to_64(u32::MAX) + 1), None);
+    assert_eq!(steamid64_to_32(u64::MAX), None);
+  }
+
+  #[test]
+  fn normalize_path_uses_forward_slashes() {
+    assert_eq!(normalize_path("C:\\Program Files (x86)\\Steam/userdata\\123/config/grid\\10p.png"), "C:/Program Files (x86)/Steam/userdata/123/config/grid/10p.png");
+    assert_eq!(normalize_path("/home/user/.steam/steam/userdata/123/config/grid/10p.png"), "/home/user/.steam/steam/userdata/123/config/grid/10p.png");
+  }
+
+  #[test]
+  fn paths_match_ignores_mixed_separators() {
+    assert!(paths_match("C:\\Steam\\userdata\\123\\config\\grid\\10p.png", "C:/Steam/userdata/123/config/grid/10p.png"));
+    assert!(paths_match("C:/Steam\\userdata/123\\config/grid\\10p.png", "C:\\Steam/userdata\\123/config\\grid/10p.png"));
+    assert!(!paths_match("C:/Steam/userdata/123/config/grid/10p.png", "C:/Steam/userdata/123/config/grid/20p.png"));
+  }
+
+  #[test]
+  fn paths_match_ignores_case_only_on_windows() {
+    let case_insensitive: bool = paths_match("C:/Steam/userdata/123/config/grid/10P.PNG", "c:\\steam\\userdata\\123\\config\\grid\\10p.png");
+    assert_eq!(case_insensitive, cfg!(target_os = "windows"));
+  }
+}
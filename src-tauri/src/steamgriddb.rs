@@ -0,0 +1,219 @@
+use crate::{logger, settings};
+
+use std::time::Duration;
+
+use reqwest::{Client, StatusCode};
+use serde_json::Value;
+use tauri::AppHandle;
+
+/// The base url of SteamGridDB's API.
+pub const STEAMGRIDDB_API_URL: &str = "https://www.steamgriddb.com/api/v2";
+
+/// How long to wait for SteamGridDB before giving up on a request.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How many results SteamGridDB returns per page when its response doesn't say.
+const DEFAULT_API_PAGE_SIZE: u64 = 50;
+
+#[derive(Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct SearchFilters {
+  styles: Vec<String>,
+  dimensions: Vec<String>,
+  nsfw: Option<String>
+}
+
+#[derive(Clone, serde::Serialize)]
+#[allow(non_snake_case)]
+pub struct SearchPage {
+  results: Vec<Value>,
+  page: u32,
+  pageSize: u32,
+  total: Option<u64>,
+  hasMore: bool
+}
+
+#[derive(Clone, serde::Serialize)]
+pub struct KeyValidation {
+  valid: bool,
+  message: String
+}
+
+/// Makes an http client for SteamGridDB's API.
+pub fn build_client() -> Client {
+  return Client::builder().timeout(REQUEST_TIMEOUT).build().expect("Should have been able to successfully make the reqwest client.");
+}
+
+/// Gets the SteamGridDB endpoint and dimensions filter for a grid type. Capsules and wide capsules are both grids, told apart by their dimensions.
+fn get_art_endpoint(grid_type: &str) -> Option<(&'static str, Option<&'static str>)> {
+  return match grid_type {
+    "Capsule" => Some(("grids", Some("600x900,342x482,660x930"))),
+    "Wide Capsule" => Some(("grids", Some("460x215,920x430"))),
+    "Hero" => Some(("heroes", None)),
+    "Logo" => Some(("logos", None)),
+    "Icon" => Some(("icons", None)),
+    _ => None
+  };
+}
+
+/// Gets the url of SteamGridDB's top result for a steam app's grid type.
+pub async fn find_top_steam_art_url(http_client: &Client, key: &str, appid: &str, grid_type: &str) -> Result<String, String> {
+  let (endpoint, dimensions) = get_art_endpoint(grid_type).ok_or(format!("SteamGridDB doesn't have {} art.", grid_type))?;
+
+  let mut request = http_client.get(format!("{}/{}/steam/{}", STEAMGRIDDB_API_URL, endpoint, appid)).bearer_auth(key.trim());
+
+  if let Some(dimensions) = dimensions {
+    request = request.query(&[("dimensions", dimensions)]);
+  }
+
+  let response = request.send().await.map_err(| err | format!("Couldn't reach SteamGridDB: {}", err))?;
+
+  if !response.status().is_success() {
+    return Err(format!("SteamGridDB responded with {}.", response.status()));
+  }
+
+  let body: String = response.text().await.map_err(| err | err.to_string())?;
+  let results: Value = serde_json::from_str(&body).map_err(| err | format!("SteamGridDB sent an invalid response: {}", err))?;
+
+  return results.get("data")
+    .and_then(| data | data.as_array())
+    .and_then(| images | images.iter().find_map(| image | image.get("url").and_then(| url | url.as_str())))
+    .map(| url | url.to_owned())
+    .ok_or(format!("SteamGridDB has no {} art for {}.", grid_type, appid));
+}
+
+/// Fetches one of SteamGridDB's own result pages, returning its images along with the total and page size it reports.
+async fn fetch_api_page(http_client: &Client, key: &str, url: &str, query: &Vec<(&str, String)>, api_page: u64) -> Result<(Vec<Value>, Option<u64>, Option<u64>), String> {
+  let response = http_client.get(url)
+    .bearer_auth(key.trim())
+    .query(query)
+    .query(&[("page", api_page)])
+    .send()
+    .await
+    .map_err(| err | format!("Couldn't reach SteamGridDB: {}", err))?;
+
+  if !response.status().is_success() {
+    return Err(format!("SteamGridDB responded with {}.", response.status()));
+  }
+
+  let body: String = response.text().await.map_err(| err | err.to_string())?;
+  let results: Value = serde_json::from_str(&body).map_err(| err | format!("SteamGridDB sent an invalid response: {}", err))?;
+
+  let images: Vec<Value> = results.get("data").and_then(| data | data.as_array()).cloned().unwrap_or_default();
+  let total: Option<u64> = results.get("total").and_then(| total | total.as_u64());
+  let limit: Option<u64> = results.get("limit").and_then(| limit | limit.as_u64()).filter(| limit | *limit > 0);
+
+  return Ok((images, total, limit));
+}
+
+#[tauri::command]
+/// Gets a page of SteamGridDB's art for a game and grid type, so results can be loaded as the user scrolls. Pages are page_size results long, starting from 0.
+/// SteamGridDB pages its results with its own fixed size, so as many of its pages are fetched as are needed to fill the requested one.
+/// Filters are passed through to SteamGridDB, with dimensions replacing the ones a grid type uses by default.
+pub async fn search_grids_paged(app_handle: AppHandle, game_id: String, grid_type: String, page: u32, page_size: u32, filters: Option<SearchFilters>) -> Result<String, String> {
+  let key: String = settings::get_setting(&app_handle, "steamGridDbApiKey").and_then(| key | key.as_str().map(| key | key.to_owned())).unwrap_or_default();
+
+  if key.trim().is_empty() {
+    return Err(String::from("No SteamGridDB API key is set."));
+  }
+
+  let (endpoint, default_dimensions) = get_art_endpoint(&grid_type).ok_or(format!("SteamGridDB doesn't have {} art.", grid_type))?;
+  let filters: SearchFilters = filters.unwrap_or_default();
+  let page_size: u32 = page_size.max(1);
+
+  let mut query: Vec<(&str, String)> = Vec::new();
+
+  if !filters.styles.is_empty() {
+    query.push(("styles", filters.styles.join(",")));
+  }
+
+  if !filters.dimensions.is_empty() {
+    query.push(("dimensions", filters.dimensions.join(",")));
+  } else if let Some(dimensions) = default_dimensions {
+    query.push(("dimensions", dimensions.to_owned()));
+  }
+
+  if let Some(nsfw) = filters.nsfw {
+    query.push(("nsfw", nsfw));
+  }
+
+  let url: String = format!("{}/{}/game/{}", STEAMGRIDDB_API_URL, endpoint, game_id);
+  let http_client: Client = build_client();
+
+  let start: u64 = page as u64 * page_size as u64;
+  let end: u64 = start + page_size as u64;
+
+  let mut api_page_size: u64 = DEFAULT_API_PAGE_SIZE;
+  let mut api_page: u64 = start / api_page_size;
+  let mut results: Vec<Value> = Vec::new();
+  let mut total: Option<u64> = None;
+  let mut exhausted: bool = false;
+
+  while (results.len() as u64) < page_size as u64 {
+    let (images, page_total, limit) = fetch_api_page(&http_client, &key, &url, &query, api_page).await?;
+    total = page_total.or(total);
+
+    // ? If SteamGridDB's page size isn't the assumed one, the window needs to be worked out again before collecting anything.
+    if let Some(limit) = limit.filter(| limit | *limit != api_page_size && results.is_empty()) {
+      api_page_size = limit;
+
+      if start / api_page_size != api_page {
+        api_page = start / api_page_size;
+        continue;
+      }
+    }
+
+    let api_offset: u64 = api_page * api_page_size;
+    let image_count: u64 = images.len() as u64;
+
+    results.extend(images.into_iter().enumerate()
+      .filter(| (index, _) | (start..end).contains(&(api_offset + *index as u64)))
+      .map(| (_, image) | image));
+
+    if image_count < api_page_size {
+      exhausted = true;
+      break;
+    }
+
+    api_page += 1;
+  }
+
+  let has_more: bool = match total {
+    Some(total) => total > end,
+    None => !exhausted
+  };
+
+  logger::log_tagged_to_core_file(&app_handle, "steamgriddb", format!("Got page {} of {} art for game {}: {} results.", page, grid_type, game_id, results.len()).as_str(), 0);
+
+  let search_page: SearchPage = SearchPage { results, page, pageSize: page_size, total, hasMore: has_more };
+  return Ok(serde_json::to_string(&search_page).expect("Should have been able to serialize search page."));
+}
+
+#[tauri::command]
+/// Checks if SteamGridDB accepts an API key by making a small authenticated search.
+pub async fn validate_steamgriddb_key(app_handle: AppHandle, key: String) -> KeyValidation {
+  if key.trim().is_empty() {
+    return KeyValidation { valid: false, message: String::from("No API key was provided.") };
+  }
+
+  let response_res = build_client()
+    .get(format!("{}/search/autocomplete/steam", STEAMGRIDDB_API_URL))
+    .bearer_auth(key.trim())
+    .send()
+    .await;
+
+  let validation: KeyValidation = match response_res {
+    Ok(response) => match response.status() {
+      status if status.is_success() => KeyValidation { valid: true, message: String::from("API key is valid.") },
+      StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => KeyValidation { valid: false, message: String::from("API key is invalid.") },
+      // ? A rate limited key was still accepted, so it is valid.
+      StatusCode::TOO_MANY_REQUESTS => KeyValidation { valid: true, message: String::from("API key is valid, but is currently rate limited.") },
+      status => KeyValidation { valid: false, message: format!("SteamGridDB responded with {}.", status) }
+    },
+    Err(err) => KeyValidation { valid: false, message: format!("Couldn't reach SteamGridDB: {}", err) }
+  };
+
+  logger::log_tagged_to_core_file(&app_handle, "steamgriddb", format!("Validated SteamGridDB key: {}", validation.message).as_str(), if validation.valid { 0 } else { 1 });
+
+  return validation;
+}
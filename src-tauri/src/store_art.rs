@@ -0,0 +1,91 @@
+use crate::{logger, settings};
+
+use std::collections::HashMap;
+
+use tauri::AppHandle;
+
+/// The url template of the app header on steam's store CDN.
+const STORE_HEADER_URL_TEMPLATE: &str = "https://cdn.cloudflare.steamstatic.com/steam/apps/{appid}/header.jpg";
+
+/// The url templates of the art sources that are always available, as (source, grid type, template). Icons aren't available from the store CDN.
+const DEFAULT_ART_URL_TEMPLATES: [(&str, &str, &str); 4] = [
+  ("steam", "Capsule", "https://cdn.cloudflare.steamstatic.com/steam/apps/{appid}/library_600x900.jpg"),
+  ("steam", "Wide Capsule", STORE_HEADER_URL_TEMPLATE),
+  ("steam", "Hero", "https://cdn.cloudflare.steamstatic.com/steam/apps/{appid}/library_hero.jpg"),
+  ("steam", "Logo", "https://cdn.cloudflare.steamstatic.com/steam/apps/{appid}/logo.png")
+];
+
+/// Maps art sources to their url template for each grid type.
+type ArtUrlTemplates = HashMap<String, HashMap<String, String>>;
+
+/// Gets the url templates of every art source, including the ones from the "artUrlTemplates" setting. Configured templates override the defaults.
+fn get_art_url_templates(app_handle: &AppHandle) -> ArtUrlTemplates {
+  let mut templates: ArtUrlTemplates = HashMap::new();
+
+  for (source, grid_type, template) in DEFAULT_ART_URL_TEMPLATES.iter() {
+    templates.entry(source.to_string()).or_default().insert(grid_type.to_string(), template.to_string());
+  }
+
+  let configured_sources = settings::get_setting(app_handle, "artUrlTemplates").and_then(| value | value.as_object().cloned()).unwrap_or_default();
+
+  for (source, source_templates) in configured_sources.iter() {
+    let source_templates = match source_templates.as_object() {
+      Some(source_templates) => source_templates,
+      None => {
+        logger::log_tagged_to_core_file(app_handle, "store_art", format!("Ignoring url templates for {}. They should be a map of grid types to templates.", source).as_str(), 1);
+        continue;
+      }
+    };
+
+    for (grid_type, template) in source_templates.iter() {
+      match template.as_str() {
+        Some(template) if template.contains("{appid}") => {
+          templates.entry(source.to_owned()).or_default().insert(grid_type.to_owned(), template.to_owned());
+        },
+        _ => logger::log_tagged_to_core_file(app_handle, "store_art", format!("Ignoring {} url template for {}. Templates need to contain {{appid}}.", grid_type, source).as_str(), 1)
+      }
+    }
+  }
+
+  return templates;
+}
+
+/// Fills in a url template for an app.
+fn fill_url_template(template: &str, appid: &str) -> String {
+  return template.replace("{appid}", appid);
+}
+
+/// Gets the url of an app's art from a source. Returns an error if the source doesn't have a template for the grid type.
+pub fn get_art_url(app_handle: &AppHandle, source: &str, appid: &str, grid_type: &str) -> Result<String, String> {
+  let templates: ArtUrlTemplates = get_art_url_templates(app_handle);
+  let source_templates = templates.get(source).ok_or(format!("Unknown art source {}", source))?;
+  let template: &String = source_templates.get(grid_type).ok_or(format!("{} doesn't have {} art", source, grid_type))?;
+
+  return Ok(fill_url_template(template, appid));
+}
+
+#[tauri::command]
+/// Builds the url of an app's art from a source's url template for the grid type.
+pub fn build_art_url(app_handle: AppHandle, source: String, appid: String, grid_type: String) -> Result<String, String> {
+  return get_art_url(&app_handle, &source, &appid, &grid_type);
+}
+
+#[tauri::command]
+/// Gets the url of an app's store header.
+pub fn get_store_header_url(appid: String) -> String {
+  return fill_url_template(STORE_HEADER_URL_TEMPLATE, &appid);
+}
+
+#[tauri::command]
+/// Downloads an app's official art for the provided grid type. Icons aren't available from the store CDN.
+pub async fn download_store_art(app_handle: AppHandle, appid: String, grid_type: String, dest_path: String, timeout: u64) -> String {
+  let art_url: String = match get_art_url(&app_handle, "steam", &appid, &grid_type) {
+    Ok(art_url) => art_url,
+    Err(err) => {
+      logger::log_tagged_to_core_file(&app_handle, "store_art", format!("Can't download official art for {}: {}.", appid, err).as_str(), 1);
+      return String::from("failed");
+    }
+  };
+
+  return crate::download_grid(app_handle, art_url, dest_path, timeout, None).await;
+}
@@ -2,6 +2,19 @@ use serde_json::{Value, Map};
 
 use crate::reader::Reader;
 
+/// The key that marks a JSON object as a single vdf value with a type JSON can't hold losslessly, rather than a map.
+/// The value is kept as a string, since 64 bit ints lose precision as JSON numbers in the frontend.
+pub const TYPED_VALUE_KEY: &str = "__vdfType";
+
+/// Wraps a vdf value JSON can't represent exactly, along with its type, so it can be written back unchanged.
+pub fn typed_value(value_type: &str, value: String) -> Value {
+  let mut typed: Map<String, Value> = Map::new();
+  typed.insert(String::from(TYPED_VALUE_KEY), Value::String(value_type.to_owned()));
+  typed.insert(String::from("value"), Value::String(value));
+
+  return Value::Object(typed);
+}
+
 /// Reads a vdf entry map to JSON.
 pub fn read_entry_map(reader: &mut Reader) -> Map<String, Value> {
   let mut props = Map::new();
@@ -34,6 +47,15 @@ pub fn read_entry_field(reader: &mut Reader, field_type: u8) -> Value {
       let value = reader.read_uint32(true);
       return Value::Number(value.into());
     },
+    0x03 => { //? float
+      return typed_value("float32", reader.read_float32(true).to_string());
+    },
+    0x07 => { //? 64 bit number
+      return typed_value("uint64", reader.read_uint64(true).to_string());
+    },
+    0x0A => { //? signed 64 bit number
+      return typed_value("int64", reader.read_int64(true).to_string());
+    },
     _ => {
       panic!("Unexpected field type {}!", field_type);
     }
@@ -1,11 +1,86 @@
-use crate::logger;
+use crate::{logger, steam};
 
-use std::{path::PathBuf, io::{BufReader, self, Write}, fs::{File, read_dir, read}};
+use std::{path::PathBuf, io::{BufReader, self, Read, Write}, fs::{self, File, read_dir, read}};
 
+use chrono::prelude::*;
 use serde_json::{Map, Value};
 use tauri::AppHandle;
 use zip;
 
+/// The name of the optional manifest mapping grid file names to appids.
+pub const MANIFEST_FILE_NAME: &str = "manifest.json";
+
+/// The name of the file describing an art pack, like who made it and which games it covers.
+pub const PACK_FILE_NAME: &str = "pack.json";
+
+/// The similarity a shortcut name needs to be fuzzy matched when the caller doesn't provide a threshold.
+pub const DEFAULT_FUZZY_THRESHOLD: f64 = 0.8;
+
+/// How many times extracting a zip entry is tried before it's reported as failed.
+const MAX_EXTRACT_ATTEMPTS: u32 = 2;
+
+#[derive(Clone, serde::Serialize)]
+#[allow(non_snake_case)]
+pub struct FuzzyMatch {
+  importedName: String,
+  localName: String,
+  appId: String,
+  similarity: f64
+}
+
+#[derive(Clone, Default, serde::Serialize)]
+#[allow(non_snake_case)]
+pub struct ImportMatches {
+  fuzzyMatches: Vec<FuzzyMatch>,
+  unmatched: Vec<String>
+}
+
+#[derive(Clone, serde::Serialize)]
+pub struct ZipEntryFailure {
+  name: String,
+  error: String
+}
+
+#[derive(Clone, Default, serde::Serialize)]
+pub struct ZipExtractionReport {
+  succeeded: Vec<String>,
+  failed: Vec<ZipEntryFailure>
+}
+
+#[derive(Clone, serde::Serialize)]
+#[allow(non_snake_case)]
+pub struct PlannedImport {
+  name: String,
+  appId: String,
+  gridType: String,
+  action: String
+}
+
+#[derive(Clone, Default, serde::Serialize)]
+pub struct ImportPreview {
+  entries: Vec<PlannedImport>,
+  #[serde(flatten)]
+  matches: ImportMatches
+}
+
+#[derive(Clone, Default, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct ArtPackMetadata {
+  name: String,
+  author: String,
+  description: String,
+  version: String
+}
+
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+#[allow(non_snake_case)]
+pub struct ArtPackInfo {
+  #[serde(flatten)]
+  metadata: ArtPackMetadata,
+  createdAt: String,
+  games: Map<String, Value>
+}
+
 /// Gets the id for a grid from its name.
 pub fn get_id_from_grid_name(grid_name: &str) -> (String, String) {
   let dot_index: usize = grid_name.find(".").expect("File should have had a file extension");
@@ -65,8 +140,73 @@ fn deconstruct_grid_export_name(filename: &str) -> (String, String, String, Stri
   return (parts[0].to_owned(), parts[1].to_owned(), grid_type.to_owned(), file_ext.to_owned());
 }
 
-/// Get the proper name for a grid when importing.
-fn get_import_grid_name(app_handle: &AppHandle, filename: &str, name_id_map: &Map<String, Value>) -> (String, String, String) {
+/// Gets the Levenshtein distance between two strings.
+fn levenshtein_distance(a: &[char], b: &[char]) -> usize {
+  let mut prev_row: Vec<usize> = (0..=b.len()).collect();
+
+  for (i, a_char) in a.iter().enumerate() {
+    let mut row: Vec<usize> = vec![i + 1];
+
+    for (j, b_char) in b.iter().enumerate() {
+      let substitution_cost: usize = if a_char == b_char { 0 } else { 1 };
+      row.push((prev_row[j] + substitution_cost).min(prev_row[j + 1] + 1).min(row[j] + 1));
+    }
+
+    prev_row = row;
+  }
+
+  return prev_row[b.len()];
+}
+
+/// Gets how similar two game names are, from 0 to 1. Uses whichever of the edit distance and shared words scores higher.
+/// Shared words are divided by the longer name's word count, so a name contained in another, like "Dark Souls" in "Dark Souls III", isn't a perfect match.
+fn get_name_similarity(name_a: &str, name_b: &str) -> f64 {
+  let clean_a: String = name_a.to_lowercase().chars().map(| c | if c.is_alphanumeric() { c } else { ' ' }).collect();
+  let clean_b: String = name_b.to_lowercase().chars().map(| c | if c.is_alphanumeric() { c } else { ' ' }).collect();
+
+  let chars_a: Vec<char> = clean_a.split_whitespace().collect::<Vec<&str>>().join(" ").chars().collect();
+  let chars_b: Vec<char> = clean_b.split_whitespace().collect::<Vec<&str>>().join(" ").chars().collect();
+  let max_len: usize = chars_a.len().max(chars_b.len());
+
+  if max_len == 0 {
+    return 0.0;
+  }
+
+  let edit_similarity: f64 = 1.0 - (levenshtein_distance(&chars_a, &chars_b) as f64 / max_len as f64);
+
+  let words_a: Vec<&str> = clean_a.split_whitespace().collect();
+  let words_b: Vec<&str> = clean_b.split_whitespace().collect();
+  let max_words: usize = words_a.len().max(words_b.len());
+
+  let shared_words: usize = words_a.iter().filter(| word | words_b.contains(word)).count();
+  let word_similarity: f64 = shared_words as f64 / max_words as f64;
+
+  return edit_similarity.max(word_similarity);
+}
+
+/// Finds the shortcut whose name best matches the provided name, if any are similar enough.
+fn find_fuzzy_match(name: &str, name_id_map: &Map<String, Value>, fuzzy_threshold: f64) -> Option<FuzzyMatch> {
+  let mut best_match: Option<FuzzyMatch> = None;
+
+  for (local_name, id_value) in name_id_map.iter() {
+    let similarity: f64 = get_name_similarity(name, local_name);
+
+    if similarity >= fuzzy_threshold && (best_match.is_none() || similarity > best_match.as_ref().unwrap().similarity) {
+      best_match = Some(FuzzyMatch {
+        importedName: name.to_owned(),
+        localName: local_name.to_owned(),
+        appId: id_value.as_str().expect("Should have been able to convert shortcut id to str.").to_owned(),
+        similarity
+      });
+    }
+  }
+
+  return best_match;
+}
+
+/// Get the proper name for a grid when importing, and whether it was fuzzy matched and needs the user to confirm it before it's written.
+/// Shortcuts in confirmed_matches are matched to the id the user confirmed, instead of being fuzzy matched again.
+fn get_import_grid_name(app_handle: &AppHandle, filename: &str, name_id_map: &Map<String, Value>, confirmed_matches: &Map<String, Value>, fuzzy_threshold: f64, import_matches: &mut ImportMatches) -> (String, String, String, bool) {
   if filename.contains("__") {
     let (platform, filename_core, grid_type, file_ext) = deconstruct_grid_export_name(filename);
 
@@ -93,102 +233,630 @@ fn get_import_grid_name(app_handle: &AppHandle, filename: &str, name_id_map: &Ma
         file_grid_type = "";
       },
       _ => {
-        logger::log_to_core_file(app_handle.to_owned(), format!("Unexpected grid type: {}", grid_type).as_str(), 2);
+        logger::log_tagged_to_core_file(app_handle, "zip_controller", format!("Unexpected grid type: {}", grid_type).as_str(), 2);
         panic!("Unexpected grid type: {}", grid_type);
       }
     }
 
+    let fuzzy_id: String;
+    let mut needs_confirmation: bool = false;
+
     if platform == "nonsteam" && name_id_map.contains_key(file_core){
       let shortcut_id_value: &Value = name_id_map.get(file_core).expect("Should have been able to get shortcut id from name map");
       file_core = shortcut_id_value.as_str().expect("Should have been able to convert shortcut name to id.");
+    } else if platform == "nonsteam" && confirmed_matches.get(file_core).is_some_and(| id_value | id_value.is_string()) {
+      file_core = confirmed_matches.get(file_core).and_then(| id_value | id_value.as_str()).expect("Confirmed match should have been a string.");
+    } else if platform == "nonsteam" {
+      let fuzzy_match = find_fuzzy_match(file_core, name_id_map, fuzzy_threshold);
+
+      if fuzzy_match.is_some() {
+        let fuzzy_match: FuzzyMatch = fuzzy_match.unwrap();
+        logger::log_tagged_to_core_file(app_handle, "zip_controller", format!("Fuzzy matched {} to {} ({:.2}).", fuzzy_match.importedName, fuzzy_match.localName, fuzzy_match.similarity).as_str(), 0);
+
+        fuzzy_id = fuzzy_match.appId.to_owned();
+        file_core = &fuzzy_id;
+        needs_confirmation = true;
+
+        if !import_matches.fuzzyMatches.iter().any(| existing | existing.importedName == fuzzy_match.importedName) {
+          import_matches.fuzzyMatches.push(fuzzy_match);
+        }
+      } else if !import_matches.unmatched.iter().any(| name | name == file_core) {
+        logger::log_tagged_to_core_file(app_handle, "zip_controller", format!("No shortcut matched {}.", file_core).as_str(), 1);
+        import_matches.unmatched.push(file_core.to_owned());
+      }
     }
 
     let mut output_filename: String = String::from(file_core);
     output_filename.push_str(file_grid_type);
     output_filename.push_str(&file_ext);
 
-    return (platform.to_owned(), String::from(file_core), output_filename);
+    return (platform.to_owned(), String::from(file_core), output_filename, needs_confirmation);
   } else {
     let dot_index: usize = filename.find(".").expect("File should have had a file extension");
     let appid: &str = &filename[..dot_index];
-    return (String::from(""), appid.to_owned(), filename.to_owned());
+    return (String::from(""), appid.to_owned(), filename.to_owned(), false);
   }
 }
 
+#[allow(unused)]
+/// Checks if an app has any grids in the grids directory, so an export doesn't create an empty zip.
+fn has_app_grids(grids_dir_path: &PathBuf, appid: &str) -> bool {
+  return steam::list_grid_files(grids_dir_path).iter().any(| grid_path | {
+    let filename: &str = grid_path.file_name().and_then(| name | name.to_str()).unwrap_or("");
+    return get_id_from_grid_name(filename).0 == appid;
+  });
+}
+
+/// Writes the grids in the grids directory to a zip, optionally only including the grids of some apps. Returns the names of the written entries along with their appid.
+/// Grids that can't be read are skipped, but an error writing to the zip is returned, since the zip can't be trusted after it.
+fn write_grids_to_zip(app_handle: &AppHandle, zip_writer: &mut zip::ZipWriter<File>, grids_dir_path: PathBuf, platform_id_map: &Map<String, Value>, id_name_map: &Map<String, Value>, appid_filter: Option<&[String]>) -> Result<Vec<(String, String)>, String> {
+  let mut written_entries: Vec<(String, String)> = Vec::new();
+
+  if !grids_dir_path.is_dir() {
+    logger::log_tagged_to_core_file(app_handle, "zip_controller", format!("Grids dir {} doesn't exist. Treating it as having no grids.", grids_dir_path.display()).as_str(), 1);
+    return Ok(written_entries);
+  }
+
+  let entry_options = zip::write::FileOptions::default().compression_method(zip::CompressionMethod::Stored);
+  
+  for grid_path in steam::list_grid_files(&grids_dir_path).into_iter() {
+    let filename_str: &str = grid_path.file_name().unwrap().to_str().unwrap();
+    let mut in_zip_filename: String = String::from(filename_str);
+    let (id, grid_type) = get_id_from_grid_name(filename_str);
+
+    if appid_filter.is_some() && !appid_filter.unwrap().contains(&id) {
+      continue;
+    }
+
+    if platform_id_map.contains_key(&id) {
+      let platform_value: &Value = platform_id_map.get(&id).expect("Platform map should have contained game/shortcut id.");
+      let platform: &str = platform_value.as_str().expect("Should have been able to convert platform to string.");
+
+      let modified_filename = construct_grid_export_name(filename_str, &id, &grid_type, platform, id_name_map);
+      in_zip_filename = modified_filename;
+    }
+
+    // ? Grids in appid subfolders can share a name with top-level ones, which were listed first and take priority.
+    if written_entries.iter().any(| (entry_name, _) | *entry_name == in_zip_filename) {
+      logger::log_tagged_to_core_file(app_handle, "zip_controller", format!("Zip already has an entry named {}, skipping {}...", in_zip_filename, grid_path.display()).as_str(), 1);
+      continue;
+    }
+
+    let contents: Vec<u8> = match read(&grid_path) {
+      Ok(contents) => contents,
+      Err(err) => {
+        logger::log_tagged_to_core_file(app_handle, "zip_controller", format!("Failed to read {}, skipping it: {}", grid_path.display(), err).as_str(), 2);
+        continue;
+      }
+    };
+
+    zip_writer.start_file(in_zip_filename.clone(), entry_options).map_err(| err | format!("Failed to add {} to the zip: {}", in_zip_filename, err))?;
+    zip_writer.write_all(&contents).map_err(| err | format!("Failed to write {} to the zip: {}", in_zip_filename, err))?;
+    written_entries.push((in_zip_filename, id));
+    logger::log_tagged_to_core_file(app_handle, "zip_controller", format!("Wrote entry {} to zip.", filename_str).as_str(), 0);
+  }
+
+  return Ok(written_entries);
+}
+
 #[allow(unused)]
 /// Generates a Grids zip file export.
 pub fn generate_grids_zip(app_handle: &AppHandle, grids_dir_path: PathBuf, zip_file_path: PathBuf, platform_id_map: &Map<String, Value>, id_name_map: &Map<String, Value>) -> bool {
-  let grids_dir_contents = read_dir(grids_dir_path).unwrap();
   let zip_file: File = File::create(zip_file_path).expect("File's directory should have existed since user picked it.");
   let mut zip_writer: zip::ZipWriter<File> = zip::ZipWriter::new(zip_file);
   
+  let write_res: Result<(), String> = write_grids_to_zip(app_handle, &mut zip_writer, grids_dir_path, platform_id_map, id_name_map, None)
+    .and_then(| _ | zip_writer.finish().map(| _ | ()).map_err(| err | err.to_string()));
+
+  if let Err(err) = write_res {
+    logger::log_tagged_to_core_file(app_handle, "zip_controller", format!("Failed to write export zip: {}", err).as_str(), 2);
+    return false;
+  }
+
+  logger::log_tagged_to_core_file(app_handle, "zip_controller", "Successfully wrote export zip.", 0);
+  return true;
+}
+
+/// Generates a zip file export of a single app's grids, along with a manifest mapping them to the app.
+/// Returns an error without creating the zip if the app has no grids.
+pub fn generate_app_grids_zip(app_handle: &AppHandle, grids_dir_path: PathBuf, zip_file_path: PathBuf, appid: &str) -> Result<(), String> {
+  if !has_app_grids(&grids_dir_path, appid) {
+    return Err(format!("No grids found for {}.", appid));
+  }
+
+  let zip_file: File = File::create(&zip_file_path).map_err(| err | format!("Failed to create {}: {}", zip_file_path.display(), err))?;
+  let mut zip_writer: zip::ZipWriter<File> = zip::ZipWriter::new(zip_file);
+
+  let written_entries: Vec<(String, String)> = write_grids_to_zip(app_handle, &mut zip_writer, grids_dir_path, &Map::new(), &Map::new(), Some(&[appid.to_owned()]))?;
+
+  let mut manifest: Map<String, Value> = Map::new();
+  for (entry_name, _) in written_entries.into_iter() {
+    manifest.insert(entry_name, Value::String(appid.to_owned()));
+  }
+
+  let manifest_contents: String = serde_json::to_string_pretty(&manifest).expect("Should have been able to serialize grids manifest.");
   let entry_options = zip::write::FileOptions::default().compression_method(zip::CompressionMethod::Stored);
-  
-  for dir_entry in grids_dir_contents {
-    let entry = dir_entry.expect("Should have been able to get directory entry.");
-
-    if entry.file_type().unwrap().is_file() {
-      let contents: Vec<u8> = read(entry.path()).expect("Should have been able to read file, but couldn't.");
-      let filename = entry.file_name();
-      let filename_str: &str = filename.to_str().unwrap();
-      let mut in_zip_filename: String = String::from(filename_str);
-      let (id, grid_type) = get_id_from_grid_name(filename_str);
-      
-      if platform_id_map.contains_key(&id) {
-        let platform_value: &Value = platform_id_map.get(&id).expect("Platform map should have contained game/shortcut id.");
-        let platform: &str = platform_value.as_str().expect("Should have been able to convert platform to string.");
-
-        let modified_filename = construct_grid_export_name(filename_str, &id, &grid_type, platform, id_name_map);
-        in_zip_filename = modified_filename;
-      }
 
-      zip_writer.start_file(in_zip_filename, entry_options);
-      zip_writer.write(&contents);
-      logger::log_to_core_file(app_handle.to_owned(), format!("Wrote entry {} to zip.", entry.file_name().to_str().unwrap()).as_str(), 0);
-    } else {
-      logger::log_to_core_file(app_handle.to_owned(), format!("Zip entry {} is a directory, skipping...", entry.file_name().to_str().unwrap()).as_str(), 1);
+  zip_writer.start_file(MANIFEST_FILE_NAME, entry_options).map_err(| err | format!("Failed to add {} to the zip: {}", MANIFEST_FILE_NAME, err))?;
+  zip_writer.write_all(manifest_contents.as_bytes()).map_err(| err | format!("Failed to write {} to the zip: {}", MANIFEST_FILE_NAME, err))?;
+  zip_writer.finish().map_err(| err | format!("Failed to finish {}: {}", zip_file_path.display(), err))?;
+
+  logger::log_tagged_to_core_file(app_handle, "zip_controller", format!("Successfully wrote export zip for {}.", appid).as_str(), 0);
+  return Ok(());
+}
+
+/// Zips a set of files, naming each entry by its path relative to base_dir so they can be put back where they were. Returns the number of files written.
+pub fn generate_grid_backup_zip(base_dir: &PathBuf, file_paths: &[PathBuf], zip_file: File) -> Result<usize, String> {
+  let mut zip_writer: zip::ZipWriter<File> = zip::ZipWriter::new(zip_file);
+  let entry_options = zip::write::FileOptions::default().compression_method(zip::CompressionMethod::Stored);
+
+  for file_path in file_paths.iter() {
+    let entry_name: String = match file_path.strip_prefix(base_dir) {
+      Ok(relative_path) => steam::path_to_string(relative_path),
+      Err(_) => file_path.file_name().map(| name | name.to_string_lossy().into_owned()).unwrap_or_default()
+    };
+
+    let contents: Vec<u8> = read(file_path).map_err(| err | format!("Failed to read {}: {}", file_path.display(), err))?;
+
+    zip_writer.start_file(entry_name.clone(), entry_options).map_err(| err | format!("Failed to add {} to the zip: {}", entry_name, err))?;
+    zip_writer.write_all(&contents).map_err(| err | format!("Failed to write {} to the zip: {}", entry_name, err))?;
+  }
+
+  zip_writer.finish().map_err(| err | format!("Failed to finish the zip: {}", err))?;
+  return Ok(file_paths.len());
+}
+
+/// Generates an art pack for distribution, holding the grids of the provided apps, a manifest mapping them to their apps, and a pack.json describing the pack.
+/// The manifest keeps the same format as other exports, so packs can be imported like any grids zip.
+pub fn generate_art_pack_zip(app_handle: &AppHandle, grids_dir_path: PathBuf, zip_file_path: PathBuf, appids: &[String], metadata: &ArtPackMetadata, id_name_map: &Map<String, Value>) -> Result<usize, String> {
+  let zip_file: File = File::create(&zip_file_path).map_err(| err | format!("Failed to create {}: {}", zip_file_path.display(), err))?;
+  let mut zip_writer: zip::ZipWriter<File> = zip::ZipWriter::new(zip_file);
+
+  let written_entries: Vec<(String, String)> = write_grids_to_zip(app_handle, &mut zip_writer, grids_dir_path, &Map::new(), &Map::new(), Some(appids))?;
+
+  if written_entries.is_empty() {
+    let _ = zip_writer.finish();
+    let _ = fs::remove_file(&zip_file_path);
+    return Err(String::from("None of the selected apps have grids."));
+  }
+
+  let mut manifest: Map<String, Value> = Map::new();
+  let mut games: Map<String, Value> = Map::new();
+
+  for (entry_name, appid) in written_entries.iter() {
+    manifest.insert(entry_name.to_owned(), Value::String(appid.to_owned()));
+
+    let game_name: Value = id_name_map.get(appid).cloned().unwrap_or(Value::Null);
+    games.insert(appid.to_owned(), game_name);
+  }
+
+  let pack_info: ArtPackInfo = ArtPackInfo { metadata: metadata.to_owned(), createdAt: Local::now().to_rfc3339(), games };
+
+  let manifest_contents: String = serde_json::to_string_pretty(&manifest).expect("Should have been able to serialize grids manifest.");
+  let pack_contents: String = serde_json::to_string_pretty(&pack_info).expect("Should have been able to serialize art pack info.");
+  let entry_options = zip::write::FileOptions::default().compression_method(zip::CompressionMethod::Stored);
+
+  for (filename, contents) in [(MANIFEST_FILE_NAME, manifest_contents), (PACK_FILE_NAME, pack_contents)].iter() {
+    if zip_writer.start_file(*filename, entry_options).is_err() || zip_writer.write_all(contents.as_bytes()).is_err() {
+      return Err(format!("Failed to write {} to the pack.", filename));
     }
   }
 
-  zip_writer.finish();
-  logger::log_to_core_file(app_handle.to_owned(), "Successfully wrote export zip.", 0);
+  zip_writer.finish().map_err(| err | format!("Failed to finish {}: {}", zip_file_path.display(), err))?;
+
+  logger::log_tagged_to_core_file(app_handle, "zip_controller", format!("Wrote art pack {} with {} grids.", zip_file_path.display(), written_entries.len()).as_str(), 0);
+  return Ok(written_entries.len());
+}
+
+/// Reads an art pack's pack.json, if the zip has one.
+pub fn read_art_pack_info(app_handle: &AppHandle, zip_file_path: &PathBuf) -> Option<ArtPackInfo> {
+  let zip_file: File = File::open(zip_file_path).ok()?;
+  let mut zip_reader = zip::ZipArchive::new(BufReader::new(zip_file)).ok()?;
+  let mut pack_file = zip_reader.by_name(PACK_FILE_NAME).ok()?;
+
+  let mut pack_contents: String = String::new();
+  pack_file.read_to_string(&mut pack_contents).ok()?;
+
+  return match serde_json::from_str::<ArtPackInfo>(&pack_contents) {
+    Ok(pack_info) => Some(pack_info),
+    Err(err) => {
+      logger::log_tagged_to_core_file(app_handle, "zip_controller", format!("Failed to parse {} in {}: {}", PACK_FILE_NAME, zip_file_path.display(), err).as_str(), 1);
+      None
+    }
+  };
+}
+
+/// Writes the provided named files to a zip. Used for support artifacts like the diagnostics export.
+pub fn generate_files_zip(app_handle: &AppHandle, zip_file_path: PathBuf, files: &Vec<(String, Vec<u8>)>) -> bool {
+  let zip_file: File = match File::create(&zip_file_path) {
+    Ok(file) => file,
+    Err(err) => {
+      logger::log_tagged_to_core_file(app_handle, "zip_controller", format!("Failed to create zip {}: {}", zip_file_path.display(), err).as_str(), 2);
+      return false;
+    }
+  };
+
+  let mut zip_writer: zip::ZipWriter<File> = zip::ZipWriter::new(zip_file);
+  let entry_options = zip::write::FileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+
+  for (filename, contents) in files.iter() {
+    if zip_writer.start_file(filename.to_owned(), entry_options).is_err() || zip_writer.write_all(contents).is_err() {
+      logger::log_tagged_to_core_file(app_handle, "zip_controller", format!("Failed to write entry {} to zip.", filename).as_str(), 2);
+      return false;
+    }
+  }
+
+  if zip_writer.finish().is_err() {
+    logger::log_tagged_to_core_file(app_handle, "zip_controller", format!("Failed to finish zip {}.", zip_file_path.display()).as_str(), 2);
+    return false;
+  }
+
+  logger::log_tagged_to_core_file(app_handle, "zip_controller", format!("Successfully wrote {}.", zip_file_path.display()).as_str(), 0);
   return true;
 }
 
-/// Sets the users grids from a Grids zip file.
-pub fn set_grids_from_zip(app_handle: &AppHandle, grids_dir_path: PathBuf, zip_file_path: PathBuf, name_id_map: &Map<String, Value>) -> (bool, Map<String, Value>) {
+/// Parses a grids manifest, falling back to an empty one if it is invalid.
+fn parse_grids_manifest(app_handle: &AppHandle, manifest_contents: &str) -> Map<String, Value> {
+  let manifest_res = serde_json::from_str::<Map<String, Value>>(manifest_contents);
+
+  if manifest_res.is_ok() {
+    return manifest_res.unwrap();
+  } else {
+    let err = manifest_res.err().unwrap();
+    logger::log_tagged_to_core_file(app_handle, "zip_controller", format!("Failed to parse grids manifest: {}. Ignoring it.", err.to_string()).as_str(), 1);
+    return Map::new();
+  }
+}
+
+/// Reads the grids manifest from a zip, falling back to an empty one if the zip doesn't have a valid one.
+fn read_zip_manifest(app_handle: &AppHandle, zip_reader: &mut zip::ZipArchive<BufReader<File>>) -> Map<String, Value> {
+  let mut manifest_file = match zip_reader.by_name(MANIFEST_FILE_NAME) {
+    Ok(manifest_file) => manifest_file,
+    Err(_) => return Map::new()
+  };
+
+  let mut manifest_contents: String = String::new();
+
+  return match manifest_file.read_to_string(&mut manifest_contents) {
+    Ok(_) => parse_grids_manifest(app_handle, &manifest_contents),
+    Err(err) => {
+      logger::log_tagged_to_core_file(app_handle, "zip_controller", format!("Failed to read grids manifest: {}. Ignoring it.", err).as_str(), 1);
+      Map::new()
+    }
+  };
+}
+
+/// Gets the path a grid with the provided file name is written to, or None if it wouldn't be directly in the grids dir.
+/// Names from the zip or the shortcut maps could contain separators or "..", which would write the grid somewhere else.
+fn get_grid_dest_path(grids_dir_path: &PathBuf, file_name: &str) -> Option<PathBuf> {
+  let dest_path: PathBuf = grids_dir_path.join(file_name);
+
+  if dest_path.parent() != Some(grids_dir_path.as_path()) || dest_path.file_name().is_none() {
+    return None;
+  }
+
+  return Some(dest_path);
+}
+
+/// Gets the destination path of an imported grid, preferring the appid from the manifest if it has an entry for the grid.
+/// Also returns whether the grid was fuzzy matched, in which case it shouldn't be written until the user confirms the match.
+/// Manifest ids that aren't appids are ignored, and grids that would end up outside the grids dir return an error, since imported zips can come from anyone.
+fn get_import_grid_path(app_handle: &AppHandle, grids_dir_path: &PathBuf, filename: &str, name_id_map: &Map<String, Value>, confirmed_matches: &Map<String, Value>, fuzzy_threshold: f64, manifest: &Map<String, Value>, icon_map: &mut Map<String, Value>, import_matches: &mut ImportMatches) -> Result<(PathBuf, bool), String> {
+  let (platform, mut appid, mut adjusted_file_name, mut needs_confirmation) = get_import_grid_name(app_handle, filename, name_id_map, confirmed_matches, fuzzy_threshold, import_matches);
+
+  let manifest_id: Option<&str> = match manifest.get(filename) {
+    Some(Value::String(manifest_id)) if !manifest_id.is_empty() && manifest_id.chars().all(| c | c.is_ascii_digit()) => Some(manifest_id.as_str()),
+    Some(manifest_value) => {
+      logger::log_tagged_to_core_file(app_handle, "zip_controller", format!("Manifest entry for {} isn't a valid appid: {}. Ignoring it.", filename, manifest_value).as_str(), 1);
+      None
+    },
+    None => None
+  };
+
+  if let Some(manifest_id) = manifest_id {
+    // ? Plain grid names report their full stem as the appid, so the id needs to be parsed back out.
+    let id_prefix: String = if platform.is_empty() { get_id_from_grid_name(&adjusted_file_name).0 } else { appid.to_owned() };
+    adjusted_file_name = format!("{}{}", manifest_id, &adjusted_file_name[id_prefix.len()..]);
+    appid = manifest_id.to_owned();
+    // ? The manifest says exactly which app the grid is for, so there's nothing to confirm.
+    needs_confirmation = false;
+  }
+
+  let dest_path: PathBuf = get_grid_dest_path(grids_dir_path, &adjusted_file_name)
+    .ok_or(format!("{} would be written outside the grids dir as {}.", filename, adjusted_file_name))?;
+
+  if platform == "nonsteam" && adjusted_file_name.contains("icon") && !needs_confirmation {
+    let dest_path_str: &str = dest_path.to_str().expect("Should have been able to convert dest path to string.");
+    icon_map.insert(appid, Value::String(dest_path_str.to_owned()));
+  }
+
+  return Ok((dest_path, needs_confirmation));
+}
+
+/// Extracts a zip entry, checking it's the size the zip says it is. Reading an entry to the end also checks its CRC, so corrupt entries fail here too.
+/// Entries are written next to their destination first, so a failed extraction never replaces an existing grid.
+fn extract_zip_entry(zip_file: &mut zip::read::ZipFile<'_>, dest_path: &PathBuf) -> Result<(), String> {
+  let mut part_path = dest_path.to_owned().into_os_string();
+  part_path.push(".part");
+  let part_path: PathBuf = PathBuf::from(part_path);
+
+  let mut outfile = File::create(&part_path).map_err(| err | format!("Failed to create {}: {}", part_path.display(), err))?;
+  let copy_res = io::copy(zip_file, &mut outfile);
+  drop(outfile);
+
+  let copy_err: String = match copy_res {
+    Ok(written) if written == zip_file.size() => {
+      return fs::rename(&part_path, dest_path).map_err(| err | format!("Failed to move {} into place: {}", part_path.display(), err));
+    },
+    Ok(written) => format!("Extracted {} bytes, but the zip says it should be {}.", written, zip_file.size()),
+    Err(err) => err.to_string()
+  };
+
+  let _ = fs::remove_file(&part_path);
+  return Err(copy_err);
+}
+
+/// Sets the users grids from a Grids zip file. Entries that fail to extract are retried, then reported and skipped so the rest of the zip is still imported.
+/// Entries for fuzzy matched shortcuts aren't written. They're returned in the matches so the user can confirm them, and written when the import is run again with them in confirmed_matches.
+pub fn set_grids_from_zip(app_handle: &AppHandle, grids_dir_path: PathBuf, zip_file_path: PathBuf, name_id_map: &Map<String, Value>, confirmed_matches: &Map<String, Value>, fuzzy_threshold: f64) -> (bool, Map<String, Value>, ImportMatches, ZipExtractionReport) {
   let mut icon_map: Map<String, Value> = Map::new();
+  let mut import_matches: ImportMatches = ImportMatches::default();
+  let mut report: ZipExtractionReport = ZipExtractionReport::default();
 
   let zip_file = File::open(zip_file_path).expect("File should have existed since user picked it.");
   let buffer_reader = BufReader::new(zip_file);
-  let mut zip_reader = zip::ZipArchive::new(buffer_reader).expect("Should have been able to create reader because file existed.");
+
+  let mut zip_reader = match zip::ZipArchive::new(buffer_reader) {
+    Ok(zip_reader) => zip_reader,
+    Err(err) => {
+      logger::log_tagged_to_core_file(app_handle, "zip_controller", format!("Failed to read zip: {}", err).as_str(), 2);
+      return (false, icon_map, import_matches, report);
+    }
+  };
 
   if zip_reader.is_empty() {
-    logger::log_to_core_file(app_handle.to_owned(), "No entries in zip.", 0);
-    return (false, icon_map);
+    logger::log_tagged_to_core_file(app_handle, "zip_controller", "No entries in zip.", 0);
+    return (false, icon_map, import_matches, report);
+  }
+
+  let manifest: Map<String, Value> = read_zip_manifest(app_handle, &mut zip_reader);
+
+  for i in 0..zip_reader.len() {
+    let (filename, dest_path) = match zip_reader.by_index(i) {
+      Ok(zip_file) => {
+        if !zip_file.is_file() {
+          logger::log_tagged_to_core_file(app_handle, "zip_controller", format!("Zip entry {} is a directory, skipping...", zip_file.name()).as_str(), 1);
+          continue;
+        }
+
+        let filename: String = zip_file.mangled_name().to_string_lossy().into_owned();
+
+        if filename == MANIFEST_FILE_NAME || filename == PACK_FILE_NAME {
+          continue;
+        }
+
+        let (dest_path, needs_confirmation) = match get_import_grid_path(app_handle, &grids_dir_path, &filename, name_id_map, confirmed_matches, fuzzy_threshold, &manifest, &mut icon_map, &mut import_matches) {
+          Ok(import_path) => import_path,
+          Err(err) => {
+            logger::log_tagged_to_core_file(app_handle, "zip_controller", format!("Skipping zip entry {}: {}", filename, err).as_str(), 2);
+            report.failed.push(ZipEntryFailure { name: filename, error: err });
+            continue;
+          }
+        };
+
+        if needs_confirmation {
+          logger::log_tagged_to_core_file(app_handle, "zip_controller", format!("Zip entry {} was fuzzy matched, waiting for it to be confirmed.", filename).as_str(), 0);
+          continue;
+        }
+
+        (filename, dest_path)
+      },
+      Err(err) => {
+        logger::log_tagged_to_core_file(app_handle, "zip_controller", format!("Failed to read zip entry {}: {}", i, err).as_str(), 2);
+        report.failed.push(ZipEntryFailure { name: format!("#{}", i), error: err.to_string() });
+        continue;
+      }
+    };
+
+    let mut extract_res: Result<(), String> = Ok(());
+
+    for attempt in 1..=MAX_EXTRACT_ATTEMPTS {
+      extract_res = zip_reader.by_index(i)
+        .map_err(| err | err.to_string())
+        .and_then(| mut zip_file | extract_zip_entry(&mut zip_file, &dest_path));
+
+      match &extract_res {
+        Ok(_) => break,
+        Err(err) => logger::log_tagged_to_core_file(app_handle, "zip_controller", format!("Attempt {} to extract zip entry {} failed: {}", attempt, filename, err).as_str(), 1)
+      }
+    }
+
+    match extract_res {
+      Ok(_) => {
+        logger::log_tagged_to_core_file(app_handle, "zip_controller", format!("Wrote zip entry {}.", filename).as_str(), 0);
+        report.succeeded.push(filename);
+      },
+      Err(err) => {
+        logger::log_tagged_to_core_file(app_handle, "zip_controller", format!("Skipping zip entry {}: {}", filename, err).as_str(), 2);
+
+        // ? The icon was never written, so it shouldn't be set on its shortcut.
+        let dest_path_str: String = dest_path.to_string_lossy().into_owned();
+        icon_map.retain(| _, icon_path | icon_path.as_str() != Some(dest_path_str.as_str()));
+
+        report.failed.push(ZipEntryFailure { name: filename, error: err });
+      }
+    }
   }
 
+  // ? A zip where every grid failed didn't import anything, but one with only a manifest still succeeds like it used to.
+  let success: bool = !report.succeeded.is_empty() || report.failed.is_empty();
+  return (success, icon_map, import_matches, report);
+}
+
+/// Works out what importing a Grids zip would do without extracting anything, matching its entries to apps the same way set_grids_from_zip does.
+/// Each entry's action is add or replace depending on whether the grid already exists, confirm if its shortcut was fuzzy matched, or unmatched if it's for a shortcut that couldn't be found.
+pub fn preview_grids_from_zip(app_handle: &AppHandle, grids_dir_path: &PathBuf, zip_file_path: &PathBuf, name_id_map: &Map<String, Value>, confirmed_matches: &Map<String, Value>, fuzzy_threshold: f64) -> Result<ImportPreview, String> {
+  let zip_file: File = File::open(zip_file_path).map_err(| err | format!("Failed to open {}: {}", zip_file_path.display(), err))?;
+  let mut zip_reader = zip::ZipArchive::new(BufReader::new(zip_file)).map_err(| err | format!("Failed to read zip: {}", err))?;
+
+  let manifest: Map<String, Value> = read_zip_manifest(app_handle, &mut zip_reader);
+  let mut import_matches: ImportMatches = ImportMatches::default();
+  // ? Icons are only set on shortcuts after extracting, so the map isn't needed for a preview.
+  let mut icon_map: Map<String, Value> = Map::new();
+  let mut planned: Vec<(String, PathBuf, bool)> = Vec::new();
+
   for i in 0..zip_reader.len() {
-    let mut zip_file = zip_reader.by_index(i).unwrap();
+    let zip_file = zip_reader.by_index(i).map_err(| err | format!("Failed to read zip entry {}: {}", i, err))?;
+
+    if !zip_file.is_file() {
+      continue;
+    }
+
+    let filename: String = zip_file.mangled_name().to_string_lossy().into_owned();
+
+    if filename == MANIFEST_FILE_NAME || filename == PACK_FILE_NAME {
+      continue;
+    }
+
+    match get_import_grid_path(app_handle, grids_dir_path, &filename, name_id_map, confirmed_matches, fuzzy_threshold, &manifest, &mut icon_map, &mut import_matches) {
+      Ok((dest_path, needs_confirmation)) => planned.push((filename, dest_path, needs_confirmation)),
+      Err(err) => logger::log_tagged_to_core_file(app_handle, "zip_controller", format!("Leaving zip entry {} out of the preview: {}", filename, err).as_str(), 1)
+    }
+  }
+
+  let entries: Vec<PlannedImport> = planned.into_iter().map(| (filename, dest_path, needs_confirmation) | {
+    let dest_name: String = dest_path.file_name().map(| name | name.to_string_lossy().into_owned()).unwrap_or_default();
+    let (appid, grid_type) = get_id_from_grid_name(&dest_name);
+
+    let action: &str = if import_matches.unmatched.contains(&appid) {
+      "unmatched"
+    } else if needs_confirmation {
+      "confirm"
+    } else if dest_path.exists() {
+      "replace"
+    } else {
+      "add"
+    };
+
+    return PlannedImport { name: filename, appId: appid, gridType: grid_type, action: action.to_owned() };
+  }).collect();
+
+  return Ok(ImportPreview { entries, matches: import_matches });
+}
+
+/// Sets the users grids from a folder of grids. Like set_grids_from_zip, grids for fuzzy matched shortcuts are only written once they're in confirmed_matches.
+pub fn set_grids_from_folder(app_handle: &AppHandle, grids_dir_path: PathBuf, folder_path: PathBuf, name_id_map: &Map<String, Value>, confirmed_matches: &Map<String, Value>, fuzzy_threshold: f64) -> (bool, Map<String, Value>, ImportMatches) {
+  let mut icon_map: Map<String, Value> = Map::new();
+  let mut import_matches: ImportMatches = ImportMatches::default();
+  let mut manifest: Map<String, Value> = Map::new();
+
+  let manifest_path: PathBuf = folder_path.join(MANIFEST_FILE_NAME);
 
-    if zip_file.is_file() {
-      let mangled_name: PathBuf = zip_file.mangled_name();
-      let (platform, appid, adjusted_file_name) = get_import_grid_name(app_handle, mangled_name.to_str().expect("Should have been able to convert pathbuf to string."), name_id_map);
-      
-      let dest_path = grids_dir_path.join(PathBuf::from(&adjusted_file_name));
+  if manifest_path.is_file() {
+    match fs::read_to_string(&manifest_path) {
+      Ok(manifest_contents) => manifest = parse_grids_manifest(app_handle, &manifest_contents),
+      Err(err) => logger::log_tagged_to_core_file(app_handle, "zip_controller", format!("Failed to read {}, importing without it: {}", MANIFEST_FILE_NAME, err).as_str(), 1)
+    }
+  }
+
+  let folder_contents = match read_dir(&folder_path) {
+    Ok(folder_contents) => folder_contents,
+    Err(err) => {
+      logger::log_tagged_to_core_file(app_handle, "zip_controller", format!("Failed to read folder {}: {}", folder_path.display(), err).as_str(), 2);
+      return (false, icon_map, import_matches);
+    }
+  };
+
+  let mut wrote_entries: bool = false;
+
+  for dir_entry in folder_contents {
+    let entry = match dir_entry {
+      Ok(entry) => entry,
+      Err(err) => {
+        logger::log_tagged_to_core_file(app_handle, "zip_controller", format!("Failed to read a folder entry: {}", err).as_str(), 2);
+        continue;
+      }
+    };
+
+    let filename = entry.file_name();
+    let filename_str: &str = match filename.to_str() {
+      Some(filename_str) => filename_str,
+      None => {
+        logger::log_tagged_to_core_file(app_handle, "zip_controller", format!("Folder entry {} isn't a valid file name, skipping...", filename.to_string_lossy()).as_str(), 1);
+        continue;
+      }
+    };
+
+    if entry.file_type().is_ok_and(| file_type | file_type.is_file()) {
+      let lowercase_name: String = filename_str.to_lowercase();
+      let is_grid_file: bool = crate::GRID_IMAGE_EXTENSIONS.iter().any(| image_type | lowercase_name.ends_with(image_type)) || lowercase_name.ends_with(".json");
+
+      if filename_str == MANIFEST_FILE_NAME || filename_str == PACK_FILE_NAME || !is_grid_file {
+        logger::log_tagged_to_core_file(app_handle, "zip_controller", format!("Folder entry {} is not a grid, skipping...", filename_str).as_str(), 1);
+        continue;
+      }
+
+      let (dest_path, needs_confirmation) = match get_import_grid_path(app_handle, &grids_dir_path, filename_str, name_id_map, confirmed_matches, fuzzy_threshold, &manifest, &mut icon_map, &mut import_matches) {
+        Ok(import_path) => import_path,
+        Err(err) => {
+          logger::log_tagged_to_core_file(app_handle, "zip_controller", format!("Skipping folder entry {}: {}", filename_str, err).as_str(), 2);
+          continue;
+        }
+      };
+
+      if needs_confirmation {
+        logger::log_tagged_to_core_file(app_handle, "zip_controller", format!("Folder entry {} was fuzzy matched, waiting for it to be confirmed.", filename_str).as_str(), 0);
+        continue;
+      }
+
+      if let Err(err) = fs::copy(entry.path(), &dest_path) {
+        logger::log_tagged_to_core_file(app_handle, "zip_controller", format!("Failed to copy folder entry {}: {}", filename_str, err).as_str(), 2);
 
-      if platform == "nonsteam" && adjusted_file_name.contains("icon") {
-        let dest_path_str: &str = dest_path.to_str().expect("Should have been able to convert dest path to string.");
-        icon_map.insert(appid, Value::String(dest_path_str.to_owned()));
+        // ? The icon was never written, so it shouldn't be set on its shortcut.
+        let dest_path_str: String = dest_path.to_string_lossy().into_owned();
+        icon_map.retain(| _, icon_path | icon_path.as_str() != Some(dest_path_str.as_str()));
+        continue;
       }
-      
-      let mut outfile = File::create(&dest_path).unwrap();
-      io::copy(&mut zip_file, &mut outfile).expect("Should have been able to write file.");
-      logger::log_to_core_file(app_handle.to_owned(), format!("Wrote zip entry {}.", zip_file.name()).as_str(), 0);
+
+      wrote_entries = true;
+      logger::log_tagged_to_core_file(app_handle, "zip_controller", format!("Wrote folder entry {}.", filename_str).as_str(), 0);
     } else {
-      logger::log_to_core_file(app_handle.to_owned(), format!("Zip entry {} is a directory, skipping...", zip_file.name()).as_str(), 1);
+      logger::log_tagged_to_core_file(app_handle, "zip_controller", format!("Folder entry {} is a directory, skipping...", filename_str).as_str(), 1);
     }
   }
 
-  return (true, icon_map);
-}
\ No newline at end of file
+  if !wrote_entries {
+    logger::log_tagged_to_core_file(app_handle, "zip_controller", "No grids in folder.", 0);
+  }
+
+  return (wrote_entries, icon_map, import_matches);
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn name_similarity_does_not_treat_containment_as_a_match() {
+    assert!(get_name_similarity("Dark Souls", "Dark Souls III") < DEFAULT_FUZZY_THRESHOLD);
+    assert!(get_name_similarity("Witcher 3", "The Witcher 3: Wild Hunt") < DEFAULT_FUZZY_THRESHOLD);
+  }
+
+  #[test]
+  fn name_similarity_matches_small_differences() {
+    assert_eq!(get_name_similarity("Hollow Knight", "hollow knight!"), 1.0);
+    assert!(get_name_similarity("Celeste Classic", "Celeste Clasic") >= DEFAULT_FUZZY_THRESHOLD);
+    assert_eq!(get_name_similarity("", "Celeste"), 0.0);
+  }
+
+  #[test]
+  fn grid_dest_paths_stay_in_the_grids_dir() {
+    let grids_dir: PathBuf = PathBuf::from("/steam/userdata/123/config/grid");
+
+    assert_eq!(get_grid_dest_path(&grids_dir, "10_hero.png"), Some(grids_dir.join("10_hero.png")));
+    assert_eq!(get_grid_dest_path(&grids_dir, "../../x_hero.png"), None);
+    assert_eq!(get_grid_dest_path(&grids_dir, ".."), None);
+    assert_eq!(get_grid_dest_path(&grids_dir, "AC/DC_icon.png"), None);
+    assert_eq!(get_grid_dest_path(&grids_dir, "/etc/x_hero.png"), None);
+  }
+}
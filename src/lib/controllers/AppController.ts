@@ -163,13 +163,14 @@ export class AppController {
    * @param logoConfigs The list of logoConfig files.
    * ? Logging complete.
    */
-  private static async cacheLogoConfigs(logoConfigs: fs.FileEntry[]): Promise<void> {
+  private static async cacheLogoConfigs(logoConfigs: GridFile[]): Promise<void> {
     const configs = {};
 
     for (const logoConfig of logoConfigs) {
       const id = parseInt(logoConfig.name.substring(0, logoConfig.name.lastIndexOf(".")));
 
-      if (!isNaN(id)) {
+      // ? Top-level configs are listed before ones in appid subfolders, and they're the ones Steam uses.
+      if (!isNaN(id) && !configs[id]) {
         const contents = await fs.readTextFile(logoConfig.path);
         const jsonContents = JSON.parse(contents);
         if (jsonContents.logoPosition) configs[id] = jsonContents;
@@ -211,11 +212,11 @@ export class AppController {
   
   /**
    * Filters and structures the library grids based on the app's needs.
-   * @param gridsDirContents The contents of the grids dir.
+   * @param gridsDirContents The files in the grids dir, including ones in appid subfolders.
    * @returns The filtered and structured grids dir.
    * ? Logging complete.
    */
-  private static filterGridsDir(gridsDirContents: fs.FileEntry[]): [{ [appid: string]: LibraryCacheEntry }, fs.FileEntry[]] {
+  private static filterGridsDir(gridsDirContents: GridFile[]): [{ [appid: string]: LibraryCacheEntry }, GridFile[]] {
     let resKeys = [];
     const logoConfigs = [];
     const res: { [appid: string]: LibraryCacheEntry } = {};
@@ -250,13 +251,13 @@ export class AppController {
 
   /**
    * Filters and structures the library cache based on the app's needs.
-   * @param libraryCacheContents The contents of the library cache.
+   * @param libraryCacheContents The art in the library cache.
    * @param gridsInfos The filtered grid infos.
    * @param shortcuts The list of loaded shortcuts
    * @returns The filtered and structured library cache.
    * ? Logging complete.
    */
-  private static filterLibraryCache(libraryCacheContents: fs.FileEntry[], gridsInfos: { [appid: string]: LibraryCacheEntry }, shortcuts: GameStruct[]): { [appid: string]: LibraryCacheEntry } {
+  private static filterLibraryCache(libraryCacheContents: LibraryCacheArt[], gridsInfos: { [appid: string]: LibraryCacheEntry }, shortcuts: GameStruct[]): { [appid: string]: LibraryCacheEntry } {
     const shortcutIds = Object.values(shortcuts).map((shortcut) => shortcut.appid.toString());
 
     let resKeys = Object.keys(gridsInfos);
@@ -266,9 +267,8 @@ export class AppController {
     const unfiltered: { [appid: string]: LibraryCacheEntry } = {};
 
     for (const fileEntry of libraryCacheContents) {
-      const firstUnderscore = fileEntry.name.indexOf("_");
-      const appId = fileEntry.name.substring(0, firstUnderscore);
-      const type = fileEntry.name.substring(firstUnderscore + 1, fileEntry.name.indexOf("."));
+      const appId = fileEntry.appId;
+      const type = fileEntry.artName;
 
       if (libraryCacheLUT[type]) {
         if (!resKeys.includes(appId)) {
@@ -281,7 +281,7 @@ export class AppController {
         }
         
         if (!Object.keys(res[appId]).includes(libraryCacheLUT[type])) res[appId][libraryCacheLUT[type]] = fileEntry.path;
-        unfiltered[appId][libraryCacheLUT[type]] = fileEntry.path
+        if (!Object.keys(unfiltered[appId]).includes(libraryCacheLUT[type])) unfiltered[appId][libraryCacheLUT[type]] = fileEntry.path;
       }
     }
 
@@ -298,11 +298,11 @@ export class AppController {
    * @returns A promise resolving to the image data.
    */
   private static async getCacheData(shortcuts: GameStruct[]): Promise<{ [appid: string]: LibraryCacheEntry }> {
-    const gridDirContents = (await fs.readDir(await RustInterop.getGridsDirectory(get(activeUserId).toString())));
+    const gridDirContents = await RustInterop.listGridFiles(get(activeUserId).toString());
     const [filteredGrids, logoConfigs] = AppController.filterGridsDir(gridDirContents);
     LogController.log("Grids loaded.");
 
-    const libraryCacheContents = (await fs.readDir(await RustInterop.getLibraryCacheDirectory()));
+    const libraryCacheContents = await RustInterop.getLibraryCacheArt();
     const filteredCache = AppController.filterLibraryCache(libraryCacheContents, filteredGrids, shortcuts);
     LogController.log("Library Cache loaded.");
 
@@ -537,20 +537,27 @@ export class AppController {
       }
     }
 
-    const changedPaths = await RustInterop.saveChanges(get(activeUserId).toString(), libraryCache, originalCache, shortcuts, shortcutIcons, originalShortcutIcons, logoPosStrings);
+    const saveSummary = await RustInterop.saveChanges(get(activeUserId).toString(), libraryCache, originalCache, shortcuts, shortcutIcons, originalShortcutIcons, logoPosStrings);
     
-    if ((changedPaths as any).error !== undefined) {
+    if (saveSummary.failed > 0 && saveSummary.changedPaths.length === 0) {
       ToastController.showSuccessToast("Changes failed.");
       LogController.log("Changes failed.");
     } else {
-      for (const changedPath of (changedPaths as ChangedPath[])) {
+      for (const changedPath of saveSummary.changedPaths) {
         libraryCache[changedPath.appId][changedPath.gridType] = changedPath.targetPath == "REMOVE" ? "" : changedPath.targetPath;
         if (changedPath.gridType == GridTypes.ICON && shortcutIds.includes(changedPath.appId)) {
           const shortcut = shortcuts.find((s) => s.appid.toString() == changedPath.appId);
           shortcut.icon = changedPath.targetPath == "REMOVE" ? "" : changedPath.targetPath;
         }
       }
-      originalAppLibraryCache.set(JSON.parse(JSON.stringify(libraryCache)));
+      const savedCache = JSON.parse(JSON.stringify(libraryCache));
+
+      // ? Failed changes stay pending, so saving again retries just those.
+      for (const failedPath of saveSummary.failedPaths) {
+        if (savedCache[failedPath.appId]) savedCache[failedPath.appId][failedPath.gridType] = originalCache[failedPath.appId]?.[failedPath.gridType] ?? "";
+      }
+
+      originalAppLibraryCache.set(savedCache);
       appLibraryCache.set(libraryCache);
       
       originalSteamShortcuts.set(JSON.parse(JSON.stringify(shortcuts)));
@@ -563,11 +570,16 @@ export class AppController {
 
       originalLogoPositions.set(JSON.parse(JSON.stringify(Object.fromEntries(logoPosEntries))));
       steamLogoPositions.set(JSON.parse(JSON.stringify(Object.fromEntries(logoPosEntries))));
-      ToastController.showSuccessToast("Changes saved!");
-      LogController.log("Saved changes.");
+      if (saveSummary.failed > 0) {
+        ToastController.showWarningToast(`${saveSummary.failed} changes failed to save.`);
+        LogController.log(`Saved changes. ${saveSummary.failed} failed.`);
+      } else {
+        ToastController.showSuccessToast("Changes saved!");
+        LogController.log("Saved changes.");
+      }
     }
 
-    canSave.set(false);
+    canSave.set(saveSummary.failedPaths.length > 0);
   }
 
   /**
@@ -837,7 +849,34 @@ export class AppController {
     const idsMapEntries: [string, string][] = Object.entries(shortcuts).map(([shortcutId, shortcut]) => { return [shortcut.AppName, shortcutId]; });
     const shortcutIdsMap = Object.fromEntries(idsMapEntries);
 
-    const [succeeded, iconsToSet] = await RustInterop.importGridsFromZip(get(activeUserId).toString(), shortcutIdsMap);
+    const zipPath = await dialog.open({ title: "Pick a Grids Zip", filters: [ { name: "zip", extensions: [ "zip" ] } ] }) as string | null;
+
+    if (!zipPath) {
+      ToastController.showWarningToast("Cancelled.");
+      LogController.log("Import grids cancelled.");
+      return;
+    }
+
+    let [succeeded, iconsToSet, importMatches] = await RustInterop.importGridsFromZip(get(activeUserId).toString(), shortcutIdsMap, undefined, zipPath);
+
+    if (importMatches.fuzzyMatches.length > 0) {
+      const matchList = importMatches.fuzzyMatches.map((match) => `${match.importedName} -> ${match.localName}`).join("\n");
+      const shouldImportMatches = await dialog.ask(`Some grids were matched to shortcuts with similar names:\n${matchList}\n\nDo you want to import them too?`, {
+        title: "Confirm Matches",
+        type: "info"
+      });
+
+      if (shouldImportMatches) {
+        const confirmedMatches = Object.fromEntries(importMatches.fuzzyMatches.map((match) => [match.importedName, match.appId]));
+        const [confirmedSucceeded, confirmedIcons] = await RustInterop.importGridsFromZip(get(activeUserId).toString(), shortcutIdsMap, undefined, zipPath, confirmedMatches);
+        LogController.log(`Imported ${importMatches.fuzzyMatches.length} confirmed fuzzy matches.`);
+
+        succeeded = succeeded || confirmedSucceeded;
+        iconsToSet = { ...iconsToSet, ...confirmedIcons };
+      } else {
+        LogController.log(`Skipped ${importMatches.fuzzyMatches.length} unconfirmed fuzzy matches.`);
+      }
+    }
 
     if (succeeded) {
       const shortcuts = get(steamShortcuts);
@@ -927,6 +966,50 @@ export class AppController {
     }
   }
 
+  /**
+   * Renames grids whose extension doesn't match their image format, and points the cached grid paths at the renamed files.
+   * ? Logging complete.
+   */
+  static async repairGridExtensions(): Promise<void> {
+    const renames = await RustInterop.repairGridExtensions(get(activeUserId).toString());
+
+    if (renames.length === 0) {
+      ToastController.showSuccessToast("No grids needed repairing.");
+      LogController.log("No grids needed repairing.");
+      return;
+    }
+
+    const normalizePath = (path: string) => path.replaceAll("\\", "/");
+    const renamedPaths = Object.fromEntries(renames.map((rename) => [normalizePath(rename.from), rename.to]));
+    const getRenamedPath = (path: string) => renamedPaths[normalizePath(path)] ?? path;
+
+    // ? Both caches need the new paths, otherwise saving would treat the renamed grids as changes and undo the repair.
+    for (const cacheStore of [originalAppLibraryCache, appLibraryCache]) {
+      const cache = get(cacheStore);
+
+      for (const entry of Object.values(cache)) {
+        for (const [gridType, path] of Object.entries(entry)) {
+          if (path) entry[gridType] = getRenamedPath(path);
+        }
+      }
+
+      cacheStore.set(cache);
+    }
+
+    for (const shortcutsStore of [originalSteamShortcuts, steamShortcuts]) {
+      const shortcuts = get(shortcutsStore);
+
+      for (const shortcut of shortcuts) {
+        if (shortcut.icon) shortcut.icon = getRenamedPath(shortcut.icon);
+      }
+
+      shortcutsStore.set(shortcuts);
+    }
+
+    ToastController.showSuccessToast(`Repaired ${renames.length} grids!`);
+    LogController.log(`Repaired the extensions of ${renames.length} grids.`);
+  }
+
   /**
    * Function run on app closing/refreshing.
    * ? Logging complete.
@@ -158,6 +158,11 @@ export class CacheController {
           this.logWarnToFile(`Request for ${imageURL} timed out after ${requestTimeout / 1000} seconds.`, useCoreFile);
           return null;
         }
+        case "tooLarge": {
+          ToastController.showWarningToast("Grid was too large to download.");
+          this.logWarnToFile(`Request for ${imageURL} exceeded the max download size.`, useCoreFile);
+          return null;
+        }
         case "failed": {
           ToastController.showWarningToast("Failed to set grid.");
           this.logWarnToFile(`Request for ${imageURL} failed.`, useCoreFile);
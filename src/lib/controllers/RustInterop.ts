@@ -16,6 +16,7 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>
  */
 import { invoke } from "@tauri-apps/api";
+import type { GridTypes } from "../../Stores";
 
 /**
  * The available logging levels.
@@ -56,47 +57,131 @@ export class RustInterop {
     await invoke("log_to_batch_apply_file", {message: message, level: level});
   }
 
+  /**
+   * Gets the directory the app's settings and other persistent data are stored in.
+   * @returns A promise resolving to the directory's path.
+   */
+  static async getAppDataDir(): Promise<string> {
+    return await invoke<string>("get_app_data_dir", {});
+  }
+
+  /**
+   * Gets the directory the app's caches are stored in.
+   * @returns A promise resolving to the directory's path.
+   */
+  static async getAppCacheDir(): Promise<string> {
+    return await invoke<string>("get_app_cache_dir", {});
+  }
+
+  /**
+   * Pins the user to manage art for this session. Commands passed an empty user id will use them.
+   * @param userId The id of the user to pin, or null to go back to detecting the active user.
+   */
+  static async setActiveUserOverride(userId: string | null): Promise<void> {
+    await invoke("set_active_user_override", { userId: userId });
+  }
+
   /**
    * Gets the active steam user's grids directory.
    * @param activeUserId The id of the active user.
+   * @param root The optional steam install root to use instead of the detected one.
    * @returns A promise resolving to the active steam user's grids directory.
    */
-  static async getGridsDirectory(activeUserId: string): Promise<string> {
-    return await invoke<string>("get_grids_directory", { steamActiveUserId: activeUserId });
+  static async getGridsDirectory(activeUserId: string, root?: string): Promise<string> {
+    return await invoke<string>("get_grids_directory", { steamActiveUserId: activeUserId, root: root });
+  }
+
+  /**
+   * Lists the files in the active steam user's grids directory, including ones in appid subfolders.
+   * @param activeUserId The id of the active user.
+   * @returns A promise resolving to the grid files, with top-level files first.
+   */
+  static async listGridFiles(activeUserId: string): Promise<GridFile[]> {
+    return await invoke<GridFile[]>("list_grid_files", { steamActiveUserId: activeUserId });
   }
 
   /**
    * Gets the active steam user's appinfo.vdf path.
+   * @param root The optional steam install root to use instead of the detected one.
    * @returns A promise resolving to the active steam user's appinfo.vdf path.
    */
-  static async getAppinfoPath(): Promise<string> {
-    return await invoke<string>("get_appinfo_path", {});
+  static async getAppinfoPath(root?: string): Promise<string> {
+    return await invoke<string>("get_appinfo_path", { root: root });
   }
 
   /**
    * Gets the active steam user's shortcuts.vdf path.
    * @param activeUserId The id of the active user.
+   * @param root The optional steam install root to use instead of the detected one.
    * @returns A promise resolving to the active steam user's shortcuts.vdf path.
    */
-  static async getShortcutsPath(activeUserId: string): Promise<string> {
-    return await invoke<string>("get_shortcuts_path", { steamActiveUserId: activeUserId });
+  static async getShortcutsPath(activeUserId: string, root?: string): Promise<string> {
+    return await invoke<string>("get_shortcuts_path", { steamActiveUserId: activeUserId, root: root });
   }
 
   /**
    * Gets the active steam user's localconfig.vdf path.
    * @param activeUserId The id of the active user.
+   * @param root The optional steam install root to use instead of the detected one.
    * @returns A promise resolving to the active steam user's localconfig.vdf path.
    */
-  static async getLocalconfigPath(activeUserId: string): Promise<string> {
-    return await invoke<string>("get_localconfig_path", { steamActiveUserId: activeUserId });
+  static async getLocalconfigPath(activeUserId: string, root?: string): Promise<string> {
+    return await invoke<string>("get_localconfig_path", { steamActiveUserId: activeUserId, root: root });
+  }
+
+  /**
+   * Gets the path of a steam user's cached avatar.
+   * @param userId The 32 bit id of the user.
+   * @param root The optional steam install root to use instead of the detected one.
+   * @returns A promise resolving to the avatar's path, or null if steam hasn't cached one.
+   */
+  static async getUserAvatarPath(userId: string, root?: string): Promise<string | null> {
+    return await invoke<string | null>("get_user_avatar_path", { steamUserId: userId, root: root });
   }
 
   /**
    * Gets the active steam user's library cache directory.
+   * @param root The optional steam install root to use instead of the detected one.
    * @returns A promise resolving to the active steam user's library cache directory.
    */
-  static async getLibraryCacheDirectory(): Promise<string> {
-    return await invoke<string>("get_library_cache_directory", {});
+  static async getLibraryCacheDirectory(root?: string): Promise<string> {
+    return await invoke<string>("get_library_cache_directory", { root: root });
+  }
+
+  /**
+   * Gets the library cache directories of every steam library folder, starting with the steam install's.
+   * @param root The optional steam install root to use instead of the detected one.
+   * @returns A promise resolving to the library cache directories.
+   */
+  static async getLibraryCacheDirectories(root?: string): Promise<string[]> {
+    return await invoke<string[]>("get_library_cache_directories", { root: root });
+  }
+
+  /**
+   * Gets the art in every steam library cache, handling both the flat and per-app folder layouts.
+   * @param root The optional steam install root to use instead of the detected one.
+   * @returns A promise resolving to the cached art. Earlier entries take priority.
+   */
+  static async getLibraryCacheArt(root?: string): Promise<LibraryCacheArt[]> {
+    return await invoke<LibraryCacheArt[]>("get_library_cache_art", { root: root });
+  }
+
+  /**
+   * Gets where Steam reads art from for the active user. The desktop library and Big Picture both read custom art from the grids directory.
+   * @param activeUserId The id of the active user.
+   * @returns A promise resolving to the grids directory, the library cache directories, and how the library cache is laid out.
+   */
+  static async getArtLocations(activeUserId: string): Promise<{ gridsDir: string, libraryCacheDirs: string[], libraryCacheLayout: "flat" | "nested" | "mixed" | "empty" }> {
+    return await invoke<{ gridsDir: string, libraryCacheDirs: string[], libraryCacheLayout: "flat" | "nested" | "mixed" | "empty" }>("get_art_locations", { steamActiveUserId: activeUserId });
+  }
+
+  /**
+   * Gets the Steam library folder an app is installed in.
+   * @param appid The id of the app.
+   * @returns A promise resolving to the library's path, or null if the app isn't installed.
+   */
+  static async getAppLibrary(appid: string): Promise<string | null> {
+    return await invoke<string | null>("get_app_library", { appid: appid });
   }
 
   /**
@@ -107,6 +192,184 @@ export class RustInterop {
     return JSON.parse(await invoke<string>("get_steam_users", {}));
   }
 
+  /**
+   * Reads everything steam stores about the users in loginusers.vdf.
+   * @returns A promise resolving to a map of 64 bit user ids to their loginusers.vdf entries.
+   */
+  static async readLoginUsers(): Promise<{ [id64: string]: { [key: string]: string } }> {
+    return JSON.parse(await invoke<string>("read_loginusers", {}));
+  }
+
+  /**
+   * Reads steam's install wide settings from config.vdf.
+   * @returns A promise resolving to config.vdf as JSON, keyed by its root key.
+   */
+  static async readConfigVdf(): Promise<{ [key: string]: any }> {
+    return JSON.parse(await invoke<string>("read_config_vdf", {}));
+  }
+
+  /**
+   * Gets the apps steam has stored in the registry.
+   * @returns A promise resolving to the list of steam apps.
+   */
+  static async getSteamApps(): Promise<SteamRegistryApp[]> {
+    return await invoke<SteamRegistryApp[]>("get_steam_apps", {});
+  }
+
+  /**
+   * Gets the language steam's client is set to.
+   * @returns A promise resolving to steam's language code, like "english". Defaults to "english" if it isn't set.
+   */
+  static async getSteamLanguage(): Promise<string> {
+    return await invoke<string>("get_steam_language", {});
+  }
+
+  /**
+   * Gets every steam install on this computer.
+   * @returns A promise resolving to the list of steam installs and their users.
+   */
+  static async getAllSteamInstalls(): Promise<SteamInstall[]> {
+    return await invoke<SteamInstall[]>("get_all_steam_installs", {});
+  }
+
+  /**
+   * Gets the games in the user's Heroic Games Launcher library.
+   * @returns A promise resolving to the list of Heroic games, or an empty list if Heroic isn't installed.
+   */
+  static async getHeroicGames(): Promise<HeroicGame[]> {
+    return JSON.parse(await invoke<string>("get_heroic_games", {}));
+  }
+
+  /**
+   * Gets the games in the user's Lutris library.
+   * Their names are approximated from Lutris' config file names, so they may not exactly match what Lutris shows.
+   * @returns A promise resolving to the list of Lutris games, or an empty list if Lutris isn't installed.
+   */
+  static async getLutrisGames(): Promise<LutrisGame[]> {
+    return JSON.parse(await invoke<string>("get_lutris_games", {}));
+  }
+
+  /**
+   * Lets the user pick their grids directory when it can't be detected. The picked folder is saved as an override for the user.
+   * @param activeUserId The id of the user the folder is for.
+   * @returns A promise resolving to the picked folder, or null if none was picked or it didn't look like a grids folder.
+   */
+  static async pickGridsDirectory(activeUserId: string): Promise<string | null> {
+    return await invoke<string | null>("pick_grids_directory", { steamActiveUserId: activeUserId });
+  }
+
+  /**
+   * Adds a directory to the app's file system and asset scope so its images can be loaded.
+   * @param path The directory to add.
+   * @returns A promise resolving to true if the directory was added.
+   */
+  static async addPathToScope(path: string): Promise<boolean> {
+    return await invoke<boolean>("add_path_to_scope", { path: path });
+  }
+
+  /**
+   * Gets the grids an app already has in the active user's grids directory.
+   * @param activeUserId The id of the active user.
+   * @param appid The id of the app to check.
+   * @returns A promise resolving to a map of grid types to their existing paths.
+   */
+  static async getExistingArt(activeUserId: string, appid: string): Promise<{ [gridType: string]: string }> {
+    return await invoke<{ [gridType: string]: string }>("get_existing_art", { steamActiveUserId: activeUserId, appid: appid });
+  }
+
+  /**
+   * Gets the appids that have at least one custom grid.
+   * @param activeUserId The id of the active user.
+   * @returns A promise resolving to the customized appids, sorted numerically.
+   */
+  static async getCustomizedAppids(activeUserId: string): Promise<string[]> {
+    return await invoke<string[]>("get_customized_appids", { steamActiveUserId: activeUserId });
+  }
+
+  /**
+   * Counts the custom grids each app has.
+   * @param activeUserId The id of the active user.
+   * @returns A promise resolving to a map of appids to their number of custom grids.
+   */
+  static async getCustomGridCounts(activeUserId: string): Promise<{ [appid: string]: number }> {
+    return await invoke<{ [appid: string]: number }>("get_custom_grid_counts", { steamActiveUserId: activeUserId });
+  }
+
+  /**
+   * Reports which of the five standard grid types each app has.
+   * @param activeUserId The id of the active user.
+   * @param appids The ids of the apps to check.
+   * @returns A promise resolving to each app's filled and missing grid types, and how many apps are fully themed, partially themed, or untouched.
+   */
+  static async getArtCoverage(activeUserId: string, appids: string[]): Promise<{ apps: { appId: string, filled: string[], missing: string[] }[], fullyThemed: number, partiallyThemed: number, untouched: number }> {
+    return JSON.parse(await invoke<string>("get_art_coverage", { steamActiveUserId: activeUserId, appids: appids }));
+  }
+
+  /**
+   * Lists every image in the active user's grids directory with its metadata.
+   * @param activeUserId The id of the active user.
+   * @returns A promise resolving to the grid files. Files that couldn't be read are left out.
+   */
+  static async listGridsDetailed(activeUserId: string): Promise<GridFileInfo[]> {
+    return JSON.parse(await invoke<string>("list_grids_detailed", { steamActiveUserId: activeUserId }));
+  }
+
+  /**
+   * Lists the images that can be reused as art offline, from the active user's grids directory and the local art library folder.
+   * @param activeUserId The id of the active user.
+   * @returns A promise resolving to the local art. Files that couldn't be read are left out.
+   */
+  static async listLocalArtLibrary(activeUserId: string): Promise<LocalArtInfo[]> {
+    return JSON.parse(await invoke<string>("list_local_art_library", { steamActiveUserId: activeUserId }));
+  }
+
+  /**
+   * Finds grids in the active user's grids directory that are the same image, even if saved in different formats.
+   * @param activeUserId The id of the active user.
+   * @returns A promise resolving to the groups of duplicate grid paths.
+   */
+  static async findDuplicateGrids(activeUserId: string): Promise<string[][]> {
+    return await invoke<string[][]>("find_duplicate_grids", { steamActiveUserId: activeUserId });
+  }
+
+  /**
+   * Takes a snapshot of the active user's grids directory, recording each file's hash, modified time, and size.
+   * @param activeUserId The id of the active user.
+   * @returns A promise resolving to the snapshot as a JSON string.
+   */
+  static async snapshotGrids(activeUserId: string): Promise<string> {
+    return await invoke<string>("snapshot_grids", { steamActiveUserId: activeUserId });
+  }
+
+  /**
+   * Compares the active user's grids directory to an earlier snapshot.
+   * @param activeUserId The id of the active user.
+   * @param priorSnapshot The snapshot to compare against, as returned by snapshotGrids.
+   * @returns A promise resolving to the files added, removed, and modified since the snapshot. Rejects if the snapshot is invalid.
+   */
+  static async diffGridsSnapshot(activeUserId: string, priorSnapshot: string): Promise<{ added: string[], removed: string[], modified: string[] }> {
+    return await invoke<{ added: string[], removed: string[], modified: string[] }>("diff_grids_snapshot", { steamActiveUserId: activeUserId, priorJson: priorSnapshot });
+  }
+
+  /**
+   * Exports a JSON inventory of the active user's grids.
+   * @param activeUserId The id of the active user.
+   * @returns A promise resolving to the art manifest.
+   */
+  static async exportArtManifest(activeUserId: string): Promise<ArtManifest> {
+    return JSON.parse(await invoke<string>("export_art_manifest", { steamActiveUserId: activeUserId }));
+  }
+
+  /**
+   * Applies an art manifest to the active user's grids.
+   * @param activeUserId The id of the active user.
+   * @param manifest The art manifest to apply.
+   * @returns A promise resolving to the number of grids applied, and the source files that were missing.
+   */
+  static async importArtManifest(activeUserId: string, manifest: ArtManifest): Promise<{ applied: number, missing: string[], error: string | null }> {
+    return await invoke<{ applied: number, missing: string[], error: string | null }>("import_art_manifest", { steamActiveUserId: activeUserId, manifest: JSON.stringify(manifest) });
+  }
+
   /**
    * Exports the active user's grids to a zip file.
    * @param activeUserId The id of the active user.
@@ -118,17 +381,90 @@ export class RustInterop {
     return await invoke<boolean>("export_grids_to_zip", { steamActiveUserId: activeUserId, platformIdMap: platformIdMap, idNameMap: idNameMap });
   }
 
+  /**
+   * Exports a single app's grids to a zip file.
+   * @param activeUserId The id of the active user.
+   * @param appid The id of the app to export.
+   * @param appName The name of the app, used to name the zip.
+   * @param destPath The directory to write the zip to.
+   * @returns A promise resolving to true if the operation suceeded.
+   */
+  static async exportAppArt(activeUserId: string, appid: string, appName: string | null, destPath: string): Promise<boolean> {
+    return await invoke<boolean>("export_app_art", { steamActiveUserId: activeUserId, appid: appid, appName: appName, destPath: destPath });
+  }
+
+  /**
+   * Copies the active user's grids into a plain folder, named exactly as steam names them.
+   * @param activeUserId The id of the active user.
+   * @param destDir The folder to copy the grids to. It's created if it doesn't exist.
+   * @param appids The optional apps to copy the grids of. Every app's grids are copied if it isn't provided.
+   * @returns A promise resolving to the paths of the copied files.
+   */
+  static async exportGridsToFolder(activeUserId: string, destDir: string, appids?: string[]): Promise<string[]> {
+    return await invoke<string[]>("export_grids_to_folder", { steamActiveUserId: activeUserId, destDir: destDir, appids: appids });
+  }
+
   /**
    * Imports the active user's grids from a zip file.
    * @param activeUserId The id of the active user.
    * @param nameIdMap A map of shortcut names to their id.
-   * @returns A promise resolving to a tuple of (success, map of shortcut icons that need to be written).
+   * @param fuzzyThreshold The optional similarity (0-1) needed to match a shortcut by a close name.
+   * @param zipPath The optional path of the zip to import. The user is asked to pick one if it isn't provided.
+   * @param confirmedMatches The optional map of fuzzy matched names the user confirmed to their shortcut id. Grids for fuzzy matches are only imported once they're confirmed.
+   * @returns A promise resolving to a tuple of (success, map of shortcut icons that need to be written, fuzzy and unmatched names, entries that were and weren't extracted).
    */
-  static async importGridsFromZip(activeUserId: string, nameIdMap: { [id: string]: string }): Promise<[boolean, { [appid: string]: string}]> {
-    const res = await invoke<[boolean, { [appid: string]: string}]>("import_grids_from_zip", { steamActiveUserId: activeUserId, nameIdMap: nameIdMap });
+  static async importGridsFromZip(activeUserId: string, nameIdMap: { [id: string]: string }, fuzzyThreshold?: number, zipPath?: string, confirmedMatches?: { [name: string]: string }): Promise<[boolean, { [appid: string]: string}, ImportMatches, ZipExtractionReport]> {
+    const res = await invoke<[boolean, { [appid: string]: string}, ImportMatches, ZipExtractionReport]>("import_grids_from_zip", { steamActiveUserId: activeUserId, nameIdMap: nameIdMap, confirmedMatches: confirmedMatches, fuzzyThreshold: fuzzyThreshold, zipPath: zipPath });
     return res;
   }
 
+  /**
+   * Previews importing a grids zip without extracting it, so the user can confirm the import first.
+   * @param activeUserId The id of the active user.
+   * @param zipPath The path of the zip to preview.
+   * @param nameIdMap A map of shortcut names to their id.
+   * @param fuzzyThreshold The optional similarity (0-1) needed to match a shortcut by a close name.
+   * @param confirmedMatches The optional map of fuzzy matched names the user confirmed to their shortcut id.
+   * @returns A promise resolving to the planned entries, and the fuzzy and unmatched names.
+   */
+  static async previewImport(activeUserId: string, zipPath: string, nameIdMap: { [id: string]: string }, fuzzyThreshold?: number, confirmedMatches?: { [name: string]: string }): Promise<ImportPreview> {
+    return JSON.parse(await invoke<string>("preview_import", { steamActiveUserId: activeUserId, zipPath: zipPath, nameIdMap: nameIdMap, confirmedMatches: confirmedMatches, fuzzyThreshold: fuzzyThreshold }));
+  }
+
+  /**
+   * Exports the grids of the provided apps as an art pack for sharing.
+   * @param activeUserId The id of the active user.
+   * @param appids The ids of the apps to include.
+   * @param metadata The pack's name, author, description, and version.
+   * @param destPath The path to write the pack to.
+   * @returns A promise resolving to the number of grids in the pack. Rejects if the pack couldn't be written.
+   */
+  static async createArtPack(activeUserId: string, appids: string[], metadata: ArtPackMetadata, destPath: string): Promise<number> {
+    return await invoke<number>("create_art_pack", { steamActiveUserId: activeUserId, appids: appids, metadata: metadata, destPath: destPath });
+  }
+
+  /**
+   * Reads an art pack's info so it can be shown before importing it.
+   * @param packPath The path of the pack.
+   * @returns A promise resolving to the pack's info, or null if the zip isn't an art pack.
+   */
+  static async readArtPackInfo(packPath: string): Promise<ArtPackInfo | null> {
+    return await invoke<ArtPackInfo | null>("read_art_pack_info", { packPath: packPath });
+  }
+
+  /**
+   * Imports the active user's grids from a folder.
+   * @param activeUserId The id of the active user.
+   * @param folderPath The path of the folder to import from.
+   * @param nameIdMap A map of shortcut names to their id.
+   * @param fuzzyThreshold The optional similarity (0-1) needed to match a shortcut by a close name.
+   * @param confirmedMatches The optional map of fuzzy matched names the user confirmed to their shortcut id. Grids for fuzzy matches are only imported once they're confirmed.
+   * @returns A promise resolving to a tuple of (success, map of shortcut icons that need to be written, fuzzy and unmatched names).
+   */
+  static async importGridsFromFolder(activeUserId: string, folderPath: string, nameIdMap: { [id: string]: string }, fuzzyThreshold?: number, confirmedMatches?: { [name: string]: string }): Promise<[boolean, { [appid: string]: string}, ImportMatches]> {
+    return await invoke<[boolean, { [appid: string]: string}, ImportMatches]>("import_grids_from_folder", { steamActiveUserId: activeUserId, folderPath: folderPath, nameIdMap: nameIdMap, confirmedMatches: confirmedMatches, fuzzyThreshold: fuzzyThreshold });
+  }
+
   /**
    * Reads the current user's apps from the appinfo.vdf file.
    * @returns A promise resolving to the contents of the appinfo.vdf file.
@@ -137,6 +473,133 @@ export class RustInterop {
     return JSON.parse(await invoke<string>("read_appinfo_vdf", {}));
   }
 
+  /**
+   * Reads a single app's entry from the appinfo.vdf file.
+   * @param appid The id of the app to read.
+   * @returns A promise resolving to the app's appinfo entry, or an empty object if it wasn't found.
+   */
+  static async getAppInfo(appid: number): Promise<any> {
+    return JSON.parse(await invoke<string>("get_app_info", { appid: appid }));
+  }
+
+  /**
+   * Searches the current user's apps by name, ignoring case and accents.
+   * @param query The name to search for.
+   * @param limit The maximum number of results to return.
+   * @returns A promise resolving to the best matching apps.
+   */
+  static async searchApps(query: string, limit: number): Promise<{ appId: number, name: string }[]> {
+    return JSON.parse(await invoke<string>("search_apps", { query: query, limit: limit }));
+  }
+
+  /**
+   * Builds the maps between the current user's app names and appids.
+   * @returns A promise resolving to the name to id map and the id to name map.
+   */
+  static async buildNameIdMaps(): Promise<{ nameIdMap: { [name: string]: string }, idNameMap: { [id: string]: string } }> {
+    return JSON.parse(await invoke<string>("build_name_id_maps", {}));
+  }
+
+  /**
+   * Checks if an image's dimensions fit the aspect ratio Steam expects for a grid type.
+   * @param srcPath The path of the image to check.
+   * @param gridType The grid type the image will be used for.
+   * @returns A promise resolving to the image's dimensions and whether they match, or null if the image couldn't be read.
+   */
+  static async checkGridDimensions(srcPath: string, gridType: GridTypes): Promise<GridDimensions | null> {
+    return await invoke<GridDimensions | null>("check_grid_dimensions", { srcPath: srcPath, gridType: gridType });
+  }
+
+  /**
+   * Guesses which grid type an image is meant for from its aspect ratio and transparency.
+   * @param srcPath The path of the image to check.
+   * @returns A promise resolving to the guessed grid type, or null if it doesn't clearly fit one.
+   */
+  static async guessGridType(srcPath: string): Promise<GridTypes | null> {
+    return await invoke<GridTypes | null>("guess_grid_type", { srcPath: srcPath });
+  }
+
+  /**
+   * Checks if an image can be fully decoded, catching corrupt or truncated files.
+   * @param path The path of the image to check.
+   * @returns A promise resolving to whether the image decoded.
+   */
+  static async canDecodeImage(path: string): Promise<boolean> {
+    return await invoke<boolean>("can_decode_image", { path: path });
+  }
+
+  /**
+   * Shrinks an oversized grid in place, keeping its aspect ratio, and optionally converts it to another format.
+   * @param path The path of the grid to optimize.
+   * @param maxDimension The largest width or height the grid should have.
+   * @param quality The JPEG quality (1-100) to use.
+   * @param format The optional format to convert the grid to. Only "webp" is supported, and the grid keeps its format if it isn't provided.
+   * @returns A promise resolving to the number of bytes saved and the grid's path, which changes if it was converted.
+   */
+  static async optimizeGrid(path: string, maxDimension: number, quality: number, format?: "original" | "webp"): Promise<{ bytesSaved: number, path: string }> {
+    return await invoke<{ bytesSaved: number, path: string }>("optimize_grid", { path: path, maxDimension: maxDimension, quality: quality, format: format });
+  }
+
+  /**
+   * Reads an image as a base64 data uri, for previews outside of the asset protocol's scope.
+   * @param path The path of the image to read.
+   * @param maxDimension The optional largest width or height to downscale the image to.
+   * @returns A promise resolving to the data uri, or an empty string if the image couldn't be read or was too large.
+   */
+  static async readImageAsBase64(path: string, maxDimension?: number): Promise<string> {
+    return await invoke<string>("read_image_as_base64", { path: path, maxDimension: maxDimension });
+  }
+
+  /**
+   * Clears the app's caches to reclaim disk space.
+   * @param which The caches to clear. Supports "thumbnails", "downloads", "appinfo", and "all".
+   * @returns A promise resolving to the number of bytes freed.
+   */
+  static async clearCaches(which: ("thumbnails" | "downloads" | "appinfo" | "all")[]): Promise<{ freedBytes: number }> {
+    return await invoke<{ freedBytes: number }>("clear_caches", { which: which });
+  }
+
+  /**
+   * Starts watching the active user's grids directory. Changes are emitted as "grids-changed" events.
+   * @param activeUserId The id of the active user.
+   * @returns A promise resolving to true if the watcher was started.
+   */
+  static async watchGridsDirectory(activeUserId: string): Promise<boolean> {
+    return await invoke<boolean>("watch_grids_directory", { steamActiveUserId: activeUserId });
+  }
+
+  /**
+   * Stops watching the active user's grids directory.
+   */
+  static async unwatchGridsDirectory(): Promise<void> {
+    await invoke("unwatch_grids_directory", {});
+  }
+
+  /**
+   * Starts streaming core.log, emitting each new line as a log-line event.
+   * @returns A promise resolving to true if the stream was started.
+   */
+  static async startLogStream(): Promise<boolean> {
+    return await invoke<boolean>("start_log_stream", {});
+  }
+
+  /**
+   * Stops streaming core.log.
+   */
+  static async stopLogStream(): Promise<void> {
+    await invoke("stop_log_stream", {});
+  }
+
+  /**
+   * Exports the logs, steam paths, version info, and redacted settings to a zip for bug reports.
+   * @param destPath The path of the zip to write.
+   * @param activeUserId The optional id of the active user, used to report their steam paths.
+   * @returns A promise resolving to true if the zip was written.
+   */
+  static async exportDiagnostics(destPath: string, activeUserId?: string): Promise<boolean> {
+    return await invoke<boolean>("export_diagnostics", { destPath: destPath, steamActiveUserId: activeUserId });
+  }
+
   /**
    * Reads the current user's non steam games from the shortcuts.vdf file.
    * @param activeUserId The id of the active user.
@@ -146,6 +609,33 @@ export class RustInterop {
     return JSON.parse(await invoke<string>("read_shortcuts_vdf", { steamActiveUserId: activeUserId }));
   }
 
+  /**
+   * Checks if the current user's shortcuts.vdf file exists, and how many shortcuts it has.
+   * @param activeUserId The id of the active user.
+   * @returns A promise resolving to the file's status.
+   */
+  static async getShortcutsStatus(activeUserId: string): Promise<{ exists: boolean, path: string, shortcutCount: number }> {
+    return await invoke<{ exists: boolean, path: string, shortcutCount: number }>("shortcuts_status", { steamActiveUserId: activeUserId });
+  }
+
+  /**
+   * Checks if the active user's grids directory can be written to.
+   * @param activeUserId The id of the active user.
+   * @returns A promise resolving to whether the directory is writable, and why not if it isn't.
+   */
+  static async checkGridsWritable(activeUserId: string): Promise<{ writable: boolean, reason: string | null }> {
+    return await invoke<{ writable: boolean, reason: string | null }>("check_grids_writable", { steamActiveUserId: activeUserId });
+  }
+
+  /**
+   * Gets the space available on the drive holding the active user's grids directory.
+   * @param activeUserId The id of the active user.
+   * @returns A promise resolving to the number of free bytes, or 0 if it couldn't be read.
+   */
+  static async getFreeSpace(activeUserId: string): Promise<number> {
+    return await invoke<number>("get_free_space", { steamActiveUserId: activeUserId });
+  }
+
   /**
    * Reads the current user's non steam games from the localconfig.vdf file.
    * @param activeUserId The id of the active user.
@@ -155,6 +645,48 @@ export class RustInterop {
     return JSON.parse(await invoke<string>("read_localconfig_vdf", { steamActiveUserId: activeUserId }));
   }
 
+  /**
+   * Writes changes to the current user's localconfig.vdf file.
+   * @param activeUserId The id of the active user.
+   * @param data The changes to merge into the file, mirroring its structure. Null values remove a key.
+   * @returns A promise resolving to true if the write was successful.
+   */
+  static async writeLocalconfigVdf(activeUserId: string, data: { [key: string]: any }): Promise<boolean> {
+    return await invoke<boolean>("write_localconfig_vdf", { steamActiveUserId: activeUserId, data: data });
+  }
+
+  /**
+   * Reads a controller config from the active user's config directory. Both text and binary vdfs are supported.
+   * @param activeUserId The id of the active user.
+   * @param configPath The path of the config, relative to the user's config directory.
+   * @returns A promise resolving to the config's contents, or an empty object if it couldn't be read.
+   */
+  static async readControllerConfig(activeUserId: string, configPath: string): Promise<any> {
+    return JSON.parse(await invoke<string>("read_controller_config", { steamActiveUserId: activeUserId, configPath: configPath }));
+  }
+
+  /**
+   * Writes a controller config in the active user's config directory, replacing its contents.
+   * @param activeUserId The id of the active user.
+   * @param configPath The path of the config, relative to the user's config directory.
+   * @param data The config's contents. Text vdfs need a single root key.
+   * @returns A promise resolving to true if the config was written.
+   */
+  static async writeControllerConfig(activeUserId: string, configPath: string, data: any): Promise<boolean> {
+    return await invoke<boolean>("write_controller_config", { steamActiveUserId: activeUserId, configPath: configPath, data: data });
+  }
+
+  /**
+   * Counts the grid changes that saving would apply, without applying them.
+   * @param activeUserId The id of the active user.
+   * @param currentArt The current changes.
+   * @param originalArt The original art dictionary.
+   * @returns A promise resolving to the number of changed grids.
+   */
+  static async countPendingChanges(activeUserId: string, currentArt: { [appid: string]: LibraryCacheEntry }, originalArt: { [appid: string]: LibraryCacheEntry }): Promise<number> {
+    return await invoke<number>("count_pending_changes", { steamActiveUserId: activeUserId, currentArt: JSON.stringify(currentArt), originalArt: JSON.stringify(originalArt) });
+  }
+
   /**
    * Saves the user's changes.
    * @param activeUserId The id of the active user.
@@ -164,7 +696,10 @@ export class RustInterop {
    * @param shortcutIcons The map of shortcutIds to updated icons.
    * @param originalShortcutIcons The map of shortcutIds to original icons.
    * @param changedLogoPositions The changed logo positions.
-   * @returns A promise resolving to a string of serialized changed tuples.
+   * @param manageShortcutIcons Whether to point shortcut icons at their new grids. Defaults to true.
+   * @param validateDimensions Whether to warn about images that don't fit their grid type's aspect ratio. Defaults to false.
+   * @param verifyDecode Whether to fully decode each image first, skipping corrupt ones. Defaults to false since it's slow for big saves.
+   * @returns A promise resolving to a summary of the save, including the changes that were applied. Progress is emitted as "save-progress" events.
    */
   static async saveChanges(
     activeUserId: string,
@@ -173,15 +708,231 @@ export class RustInterop {
     shortcuts: SteamShortcut[],
     shortcutIcons: { [id: string]: string },
     originalShortcutIcons: { [id: string]: string },
-    changedLogoPositions: { [appid: string]: string }
-  ): Promise<ChangedPath[] | { error: string }> {
+    changedLogoPositions: { [appid: string]: string },
+    manageShortcutIcons?: boolean,
+    validateDimensions?: boolean,
+    verifyDecode?: boolean
+  ): Promise<SaveSummary> {
     const shortcutsObj = {
       "shortcuts": {...shortcuts}
     }
-    const res = await invoke<string>("save_changes", { currentArt: JSON.stringify(currentArt), originalArt: JSON.stringify(originalArt), shortcutsStr: JSON.stringify(shortcutsObj), steamActiveUserId: activeUserId, shortcutIcons: shortcutIcons, originalShortcutIcons: originalShortcutIcons, changedLogoPositions: changedLogoPositions });
+    const res = await invoke<string>("save_changes", { currentArt: JSON.stringify(currentArt), originalArt: JSON.stringify(originalArt), shortcutsStr: JSON.stringify(shortcutsObj), steamActiveUserId: activeUserId, shortcutIcons: shortcutIcons, originalShortcutIcons: originalShortcutIcons, changedLogoPositions: changedLogoPositions, manageShortcutIcons: manageShortcutIcons, validateDimensions: validateDimensions, verifyDecode: verifyDecode });
     return JSON.parse(res);
   }
 
+  /**
+   * Saves the user's changes as a single transaction, so the grids and shortcuts.vdf are either both updated or both left untouched.
+   * @param activeUserId The id of the active user.
+   * @param currentArt The current changes.
+   * @param originalArt The original art dictionary.
+   * @param shortcuts The list of shortcuts.
+   * @param shortcutIcons The map of shortcutIds to updated icons.
+   * @param originalShortcutIcons The map of shortcutIds to original icons.
+   * @param changedLogoPositions The changed logo positions.
+   * @param manageShortcutIcons Whether to point shortcut icons at their new grids. Defaults to true.
+   * @param validateDimensions Whether to warn about images that don't fit their grid type's aspect ratio. Defaults to false.
+   * @returns A promise resolving to a summary of the save. If anything failed, every change is listed as failed since none were applied.
+   */
+  static async saveChangesTransactional(
+    activeUserId: string,
+    currentArt: { [appid: string]: LibraryCacheEntry },
+    originalArt: { [appid: string]: LibraryCacheEntry },
+    shortcuts: SteamShortcut[],
+    shortcutIcons: { [id: string]: string },
+    originalShortcutIcons: { [id: string]: string },
+    changedLogoPositions: { [appid: string]: string },
+    manageShortcutIcons?: boolean,
+    validateDimensions?: boolean
+  ): Promise<SaveSummary> {
+    const shortcutsObj = {
+      "shortcuts": {...shortcuts}
+    }
+    const res = await invoke<string>("save_changes_transactional", { currentArt: JSON.stringify(currentArt), originalArt: JSON.stringify(originalArt), shortcutsStr: JSON.stringify(shortcutsObj), steamActiveUserId: activeUserId, shortcutIcons: shortcutIcons, originalShortcutIcons: originalShortcutIcons, changedLogoPositions: changedLogoPositions, manageShortcutIcons: manageShortcutIcons, validateDimensions: validateDimensions });
+    return JSON.parse(res);
+  }
+
+  /**
+   * Applies an explicit list of grid changes without diffing the current and original art.
+   * @param activeUserId The id of the active user.
+   * @param changes The changes to apply. Entries outside the user's grids directory are rejected.
+   * @returns A promise resolving to a summary of the changes, including the ones that were rejected or failed.
+   */
+  static async applyChanges(activeUserId: string, changes: ChangedPath[]): Promise<SaveSummary> {
+    const res = await invoke<string>("apply_changes", { steamActiveUserId: activeUserId, changes: changes });
+    return JSON.parse(res);
+  }
+
+  /**
+   * Compares two art profiles without touching the disk.
+   * @param profileA The art profile to compare from.
+   * @param profileB The art profile to compare to.
+   * @returns A promise resolving to the grids added, removed, or changed going from profileA to profileB. Rejects if either profile couldn't be parsed.
+   */
+  static async diffArtProfiles(profileA: { [appid: string]: LibraryCacheEntry }, profileB: { [appid: string]: LibraryCacheEntry }): Promise<ArtProfileDiff[]> {
+    return JSON.parse(await invoke<string>("diff_art_profiles", { profileA: JSON.stringify(profileA), profileB: JSON.stringify(profileB) }));
+  }
+
+  /**
+   * Saves the user's current grids as a named art profile.
+   * @param activeUserId The id of the active user.
+   * @param name The name of the profile.
+   * @param bundle Whether to store copies of the grids with the profile instead of referencing the grids directory. Defaults to true.
+   * @returns A promise resolving to true if the profile was saved.
+   */
+  static async saveArtProfile(activeUserId: string, name: string, bundle?: boolean): Promise<boolean> {
+    return await invoke<boolean>("save_art_profile", { steamActiveUserId: activeUserId, name: name, bundle: bundle });
+  }
+
+  /**
+   * Lists the names of the saved art profiles.
+   * @returns A promise resolving to the profile names.
+   */
+  static async listArtProfiles(): Promise<string[]> {
+    return await invoke<string[]>("list_art_profiles", {});
+  }
+
+  /**
+   * Applies a saved art profile to the user's grids.
+   * @param activeUserId The id of the active user.
+   * @param name The name of the profile.
+   * @returns A promise resolving to a summary of the changes that were applied.
+   */
+  static async applyArtProfile(activeUserId: string, name: string): Promise<SaveSummary> {
+    return JSON.parse(await invoke<string>("apply_art_profile", { steamActiveUserId: activeUserId, name: name }));
+  }
+
+  /**
+   * Applies the user's grid and logo position changes to several steam users.
+   * @param userIds The ids of the users to apply the changes to.
+   * @param currentArt The current changes.
+   * @param originalArt The original art dictionary.
+   * @param changedLogoPositions The changed logo positions.
+   * @returns A promise resolving to a map of user ids to the result of saving for that user.
+   */
+  static async saveChangesMultiUser(
+    userIds: string[],
+    currentArt: { [appid: string]: LibraryCacheEntry },
+    originalArt: { [appid: string]: LibraryCacheEntry },
+    changedLogoPositions: { [appid: string]: string }
+  ): Promise<{ [userId: string]: UserSaveResult }> {
+    return await invoke<{ [userId: string]: UserSaveResult }>("save_changes_multi_user", { userIds: userIds, currentArt: JSON.stringify(currentArt), originalArt: JSON.stringify(originalArt), changedLogoPositions: changedLogoPositions });
+  }
+
+  /**
+   * Creates an empty shortcuts.vdf file for the user if they don't have one yet.
+   * @param activeUserId The id of the active user.
+   * @returns A promise resolving to true if a new file was created.
+   */
+  static async ensureShortcutsVdf(activeUserId: string): Promise<boolean> {
+    return await invoke<boolean>("ensure_shortcuts_vdf", { steamActiveUserId: activeUserId });
+  }
+
+  /**
+   * Exports the user's shortcuts as a human readable json file.
+   * @param activeUserId The id of the active user.
+   * @param destPath The path to write the json file to.
+   * @returns A promise resolving to true if the export succeeded.
+   */
+  static async exportShortcutsJson(activeUserId: string, destPath: string): Promise<boolean> {
+    return await invoke<boolean>("export_shortcuts_json", { steamActiveUserId: activeUserId, destPath: destPath });
+  }
+
+  /**
+   * Replaces the user's shortcuts with the ones in a json export.
+   * @param activeUserId The id of the active user.
+   * @param srcPath The path of the json file to import.
+   * @returns A promise resolving to true if the import succeeded.
+   */
+  static async importShortcutsJson(activeUserId: string, srcPath: string): Promise<boolean> {
+    return await invoke<boolean>("import_shortcuts_json", { steamActiveUserId: activeUserId, srcPath: srcPath });
+  }
+
+  /**
+   * Merges another set of shortcuts into the user's shortcuts, skipping ones with the same exe and name.
+   * @param activeUserId The id of the active user.
+   * @param otherShortcuts The shortcuts to merge in, in the same shape as readShortcutsVdf returns.
+   * @returns A promise resolving to the number of shortcuts added.
+   */
+  static async mergeShortcuts(activeUserId: string, otherShortcuts: any): Promise<number> {
+    return await invoke<number>("merge_shortcuts", { steamActiveUserId: activeUserId, otherShortcutsJson: JSON.stringify(otherShortcuts) });
+  }
+
+  /**
+   * Checks if a shortcut runs under Proton, based on its exe and start dir.
+   * @param exe The shortcut's exe.
+   * @param startDir The shortcut's start dir.
+   * @returns A promise resolving to the shortcut's Proton prefix info, or null if it isn't run under Proton.
+   */
+  static async getProtonShortcutInfo(exe: string, startDir: string): Promise<{ appid: string, isSteamApp: boolean, prefixPath: string, displayName: string } | null> {
+    return await invoke<{ appid: string, isSteamApp: boolean, prefixPath: string, displayName: string } | null>("get_proton_shortcut_info", { exe: exe, startDir: startDir });
+  }
+
+  /**
+   * Gets the compat tools installed for steam, including custom ones like GE-Proton and the official Proton versions.
+   * @returns A promise resolving to the installed compat tools. Always empty on platforms other than Linux.
+   */
+  static async getCompatTools(): Promise<{ name: string, displayName: string, path: string, isOfficial: boolean }[]> {
+    return JSON.parse(await invoke<string>("get_compat_tools"));
+  }
+
+  /**
+   * Checks the active user's shortcuts.vdf for corrupt entries and shortcuts missing required fields.
+   * @param activeUserId The id of the active user.
+   * @returns A promise resolving to whether the file is valid, and the issues found.
+   */
+  static async validateShortcuts(activeUserId: string): Promise<{ valid: boolean, issues: { shortcutId: string | null, issue: string }[] }> {
+    return await invoke<{ valid: boolean, issues: { shortcutId: string | null, issue: string }[] }>("validate_shortcuts", { steamActiveUserId: activeUserId });
+  }
+
+  /**
+   * Rewrites the active user's shortcuts.vdf without its corrupt entries. The original is backed up first.
+   * @param activeUserId The id of the active user.
+   * @returns A promise resolving to the number of shortcuts kept. Rejects if the file couldn't be repaired.
+   */
+  static async repairShortcuts(activeUserId: string): Promise<number> {
+    return await invoke<number>("repair_shortcuts", { steamActiveUserId: activeUserId });
+  }
+
+  /**
+   * Finds the active user's shortcuts whose icon file doesn't exist anymore.
+   * @param activeUserId The id of the active user.
+   * @returns A promise resolving to the shortcuts with missing icons.
+   */
+  static async findBrokenShortcutIcons(activeUserId: string): Promise<{ appId: string, appName: string, icon: string }[]> {
+    return await invoke<{ appId: string, appName: string, icon: string }[]>("find_broken_shortcut_icons", { steamActiveUserId: activeUserId });
+  }
+
+  /**
+   * Blanks the icons of the active user's shortcuts whose icon file doesn't exist anymore. shortcuts.vdf is backed up first.
+   * @param activeUserId The id of the active user.
+   * @returns A promise resolving to the number of shortcuts cleared. Rejects if shortcuts.vdf couldn't be written.
+   */
+  static async clearBrokenShortcutIcons(activeUserId: string): Promise<number> {
+    return await invoke<number>("clear_broken_shortcut_icons", { steamActiveUserId: activeUserId });
+  }
+
+  /**
+   * Repoints the active user's shortcut icons from an old grids directory to a new one. shortcuts.vdf is backed up first.
+   * @param activeUserId The id of the active user.
+   * @param oldBase The directory the icons were in.
+   * @param newBase The directory the icons were moved to.
+   * @returns A promise resolving to the number of shortcuts updated.
+   */
+  static async rebaseShortcutIcons(activeUserId: string, oldBase: string, newBase: string): Promise<number> {
+    return await invoke<number>("rebase_shortcut_icons", { steamActiveUserId: activeUserId, oldBase: oldBase, newBase: newBase });
+  }
+
+  /**
+   * Renames a grid to the file name of another grid type, keeping its appid and extension. Shortcut icons pointing at it are updated too.
+   * @param activeUserId The id of the active user.
+   * @param path The path of the grid to rename.
+   * @param newGridType The grid type the file should be named as.
+   * @returns A promise resolving to the new path, or an empty string if it couldn't be renamed.
+   */
+  static async retagGrid(activeUserId: string, path: string, newGridType: string): Promise<string> {
+    return await invoke<string>("retag_grid", { steamActiveUserId: activeUserId, path: path, newGridType: newGridType });
+  }
+
   /**
    * Writes changes to the steam shortcuts.
    * @param activeUserId The id of the active user.
@@ -196,23 +947,172 @@ export class RustInterop {
     return JSON.parse(res);
   }
 
+  /**
+   * Lists the active user's grid backups.
+   * @param activeUserId The id of the active user.
+   * @returns A promise resolving to the backups with their size and number of files, newest first. Corrupt backups are marked invalid.
+   */
+  static async listBackups(activeUserId: string): Promise<{ name: string, timestamp: string | null, sizeBytes: number, fileCount: number, valid: boolean }[]> {
+    return await invoke<{ name: string, timestamp: string | null, sizeBytes: number, fileCount: number, valid: boolean }[]>("list_backups", { steamActiveUserId: activeUserId });
+  }
+
+  /**
+   * Deletes one of the active user's grid backups.
+   * @param activeUserId The id of the active user.
+   * @param backupName The name of the backup to delete.
+   * @returns A promise resolving to true if the backup was deleted.
+   */
+  static async deleteBackup(activeUserId: string, backupName: string): Promise<boolean> {
+    return await invoke<boolean>("delete_backup", { steamActiveUserId: activeUserId, backupName: backupName });
+  }
+
   /**
    * Downloads a file to the provided destination from a given url.
    * @param gridUrl The url of the grid to download.
    * @param destPath The path to write the file to.
    * @param timeout The time before the request times out.
-   * @returns A promise resolving to true if the file was successfully downloaded.
+   * @param jobId The optional id used to cancel the download with cancelDownload.
+   * @returns A promise resolving to the download's status. "cached" means the server reported the existing file was still current, "cancelled" means the download was cancelled, and "tooLarge" means it exceeded the max download size.
    */
-  static async downloadGrid(gridUrl: string, destPath: string, timeout: number): Promise<string> {
+  static async downloadGrid(gridUrl: string, destPath: string, timeout: number, jobId?: string): Promise<string> {
     let timedOut = false;
 
     setTimeout(() => timedOut = true, timeout - 1);
 
-    const status = await invoke<string>("download_grid", { gridUrl: gridUrl, destPath: destPath, timeout: timeout });
+    const status = await invoke<string>("download_grid", { gridUrl: gridUrl, destPath: destPath, timeout: timeout, jobId: jobId });
 
     return timedOut ? "timedOut" : status;
   }
 
+  /**
+   * Requests a url once and reports what came back, without saving anything.
+   * @param url The url to test.
+   * @param timeout The time before the request times out.
+   * @returns A promise resolving to the response's status, content type, and size, and whether it's a valid image.
+   */
+  static async testDownload(url: string, timeout: number): Promise<{ status: number | null, contentType: string | null, bytes: number, isImage: boolean, detectedFormat: string | null, error: string | null }> {
+    return await invoke<{ status: number | null, contentType: string | null, bytes: number, isImage: boolean, detectedFormat: string | null, error: string | null }>("test_download", { url: url, timeout: timeout });
+  }
+
+  /**
+   * Downloads grids from a list of urls and saves them for their apps.
+   * @param activeUserId The id of the active user.
+   * @param jobs The grids to import.
+   * @param timeout The time before each request times out.
+   * @param updateShortcutIcons Whether to point shortcut icons at imported icons. Defaults to true.
+   * @returns A promise resolving to the result of each job.
+   */
+  static async importGridsFromUrls(activeUserId: string, jobs: { appId: string, gridType: GridTypes, url: string }[], timeout: number, updateShortcutIcons?: boolean): Promise<{ appId: string, gridType: string, success: boolean, path: string | null, error: string | null }[]> {
+    return await invoke<{ appId: string, gridType: string, success: boolean, path: string | null, error: string | null }[]>("import_grids_from_urls", { steamActiveUserId: activeUserId, jobs: jobs, timeout: timeout, updateShortcutIcons: updateShortcutIcons });
+  }
+
+  /**
+   * Fills an app's empty grid slots with the top art from a source.
+   * @param activeUserId The id of the active user.
+   * @param appid The id of the app to fill.
+   * @param source The source to use, either "steamgriddb" or one with url templates like "steam".
+   * @param timeout The time before each request times out.
+   * @param force Whether to replace slots that already have custom art. Defaults to false.
+   * @returns A promise resolving to the result of each slot that was filled or attempted.
+   */
+  static async autofillGameArt(activeUserId: string, appid: string, source: string, timeout: number, force?: boolean): Promise<{ appId: string, gridType: string, success: boolean, path: string | null, error: string | null }[]> {
+    return await invoke<{ appId: string, gridType: string, success: boolean, path: string | null, error: string | null }[]>("autofill_game_art", { steamActiveUserId: activeUserId, appid: appid, source: source, timeout: timeout, force: force });
+  }
+
+  /**
+   * Copies an app's art from Steam's library cache into the active user's grids directory.
+   * @param activeUserId The id of the active user.
+   * @param appid The id of the app to apply the art for.
+   * @param gridTypes The grid types to apply.
+   * @returns A promise resolving to the grid types that were applied.
+   */
+  static async applyDefaultArt(activeUserId: string, appid: string, gridTypes: GridTypes[]): Promise<GridTypes[]> {
+    return await invoke<GridTypes[]>("apply_default_art", { steamActiveUserId: activeUserId, appid: appid, gridTypes: gridTypes });
+  }
+
+  /**
+   * Gets the url of an app's official store header.
+   * @param appid The id of the app.
+   * @returns A promise resolving to the header's url.
+   */
+  static async getStoreHeaderUrl(appid: string): Promise<string> {
+    return await invoke<string>("get_store_header_url", { appid: appid });
+  }
+
+  /**
+   * Builds the url of an app's art from an art source's url template for a grid type.
+   * @param source The art source, like "steam" or one configured in the artUrlTemplates setting.
+   * @param appid The id of the app.
+   * @param gridType The grid type to get the url for.
+   * @returns A promise resolving to the art's url. Rejects if the source doesn't have a template for the grid type.
+   */
+  static async buildArtUrl(source: string, appid: string, gridType: GridTypes): Promise<string> {
+    return await invoke<string>("build_art_url", { source: source, appid: appid, gridType: gridType });
+  }
+
+  /**
+   * Downloads an app's official store art for a grid type. Icons aren't available.
+   * @param appid The id of the app.
+   * @param gridType The grid type to download art for.
+   * @param destPath The path to write the file to.
+   * @param timeout The time before the request times out.
+   * @returns A promise resolving to the download's status.
+   */
+  static async downloadStoreArt(appid: string, gridType: GridTypes, destPath: string, timeout: number): Promise<string> {
+    return await invoke<string>("download_store_art", { appid: appid, gridType: gridType, destPath: destPath, timeout: timeout });
+  }
+
+  /**
+   * Cancels an in-flight download.
+   * @param jobId The id the download was started with.
+   * @returns A promise resolving to true if a download was cancelled.
+   */
+  static async cancelDownload(jobId: string): Promise<boolean> {
+    return await invoke<boolean>("cancel_download", { jobId: jobId });
+  }
+
+  /**
+   * Checks if SteamGridDB accepts an API key.
+   * @param key The API key to check.
+   * @returns A promise resolving to whether the key is valid, and a message explaining why.
+   */
+  static async validateSteamGridDBKey(key: string): Promise<{ valid: boolean, message: string }> {
+    return await invoke<{ valid: boolean, message: string }>("validate_steamgriddb_key", { key: key });
+  }
+
+  /**
+   * Gets a page of SteamGridDB's art for a game, so results can be loaded as the user scrolls.
+   * @param gameId The SteamGridDB id of the game.
+   * @param gridType The grid type to get art for.
+   * @param page The page to get, starting from 0.
+   * @param pageSize The number of results per page.
+   * @param filters The optional styles, dimensions, and nsfw filters.
+   * @returns A promise resolving to the page's results and whether there are more. Rejects if SteamGridDB couldn't be searched.
+   */
+  static async searchGridsPaged(gameId: string, gridType: GridTypes, page: number, pageSize: number, filters?: { styles?: string[], dimensions?: string[], nsfw?: "true" | "false" | "any" }): Promise<{ results: any[], page: number, pageSize: number, total: number | null, hasMore: boolean }> {
+    return JSON.parse(await invoke<string>("search_grids_paged", { gameId: gameId, gridType: gridType, page: page, pageSize: pageSize, filters: filters }));
+  }
+
+  /**
+   * Removes the grids of the provided types for each of the provided apps, continuing past failures.
+   * @param activeUserId The id of the active user.
+   * @param appids The ids of the apps to remove grids for.
+   * @param gridTypes The grid types to remove. An empty list removes all of them.
+   * @returns A promise resolving to the removed grid paths, and the ones that couldn't be removed.
+   */
+  static async removeGrids(activeUserId: string, appids: string[], gridTypes: GridTypes[]): Promise<{ removed: string[], failed: { path: string, error: string }[] }> {
+    return await invoke<{ removed: string[], failed: { path: string, error: string }[] }>("remove_grids", { steamActiveUserId: activeUserId, appids: appids, gridTypes: gridTypes });
+  }
+
+  /**
+   * Renames grids whose extension doesn't match their actual image format.
+   * @param steamActiveUserId The id of the active user.
+   * @returns A promise resolving to the renames that were performed, as full grid paths.
+   */
+  static async repairGridExtensions(steamActiveUserId: string): Promise<{ from: string, to: string }[]> {
+    return await invoke<{ from: string, to: string }[]>("repair_grid_extensions", { steamActiveUserId: steamActiveUserId });
+  }
+
   /**
    * Cleans the grids directory.
    * @param steamActiveUserId The id of the active user.
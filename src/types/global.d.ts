@@ -12,11 +12,29 @@ type AppSettings = {
     [userId32: string]: string
   };
   hiddenGameIds: [],
-  manualSteamGames: GameStruct[]
+  manualSteamGames: GameStruct[],
+  backupRetention: number,
+  optimizeGrids: boolean,
+  optimizeMaxDimension: number,
+  optimizeQuality: number,
+  optimizeFormat: "original" | "webp",
+  gridsDirectoryOverrideMap: {
+    [userId: string]: string
+  },
+  localArtLibraryFolder: string,
+  maxDownloadSizeMB: number,
+  customGridTypes: {
+    [gridType: string]: string
+  },
+  artUrlTemplates: {
+    [source: string]: {
+      [gridType: string]: string
+    }
+  }
 };
 
 type SteamRegistryApp = {
-  appid: string,
+  appId: number,
   name: string
 }
 
@@ -55,6 +73,134 @@ type SteamUser = {
   Timestamp: string
 }
 
+type HeroicGame = {
+  appId: string,
+  name: string,
+  runner: string
+}
+
+type LutrisGame = {
+  slug: string,
+  name: string,
+  runner: string,
+  exe: string,
+  workingDir: string
+}
+
+type SteamInstall = {
+  kind: "native" | "flatpak" | "snap",
+  root: string,
+  users: { [id: string]: SteamUser }
+}
+
+type GridFile = {
+  name: string,
+  path: string
+}
+
+type FuzzyMatch = {
+  importedName: string,
+  localName: string,
+  appId: string,
+  similarity: number
+}
+
+type ImportMatches = {
+  fuzzyMatches: FuzzyMatch[],
+  unmatched: string[]
+}
+
+type PlannedImport = {
+  name: string,
+  appId: string,
+  gridType: string,
+  action: "add" | "replace" | "confirm" | "unmatched"
+}
+
+type ImportPreview = ImportMatches & {
+  entries: PlannedImport[]
+}
+
+type ZipExtractionReport = {
+  succeeded: string[],
+  failed: { name: string, error: string }[]
+}
+
+type ArtPackMetadata = {
+  name: string,
+  author: string,
+  description: string,
+  version: string
+}
+
+type ArtPackInfo = ArtPackMetadata & {
+  createdAt: string,
+  games: { [appid: string]: string | null }
+}
+
+type GridDimensions = {
+  width: number,
+  height: number,
+  matches: boolean,
+  expected: { width: number, height: number }
+}
+
+type UserSaveResult = {
+  success: boolean,
+  changedCount: number,
+  error: string | null
+}
+
+type ArtManifest = {
+  version: number,
+  apps: {
+    [appid: string]: {
+      name: string | null,
+      grids: { [gridType: string]: string | null }
+    }
+  }
+}
+
+type SaveSummary = {
+  applied: number,
+  removed: number,
+  failed: number,
+  errors: { appId: string, gridType: string, error: string }[],
+  warnings: { appId: string, gridType: string, sourcePath: string, warning: string }[],
+  changedPaths: ChangedPath[],
+  failedPaths: ChangedPath[]
+}
+
+type GridFileInfo = {
+  path: string,
+  appId: string | null,
+  gridType: string | null,
+  width: number,
+  height: number,
+  format: string,
+  bytes: number,
+  mtime: number
+}
+
+type LocalArtInfo = {
+  path: string,
+  source: "grids" | "library",
+  appId: string | null,
+  gridType: string | null,
+  guessedGridType: string | null,
+  width: number,
+  height: number,
+  format: string
+}
+
+type ArtProfileDiff = {
+  appId: string,
+  gridType: string,
+  change: "added" | "removed" | "changed",
+  from: string | null,
+  to: string | null
+}
+
 type LibraryCacheEntry = {
   "Capsule": string,
   "Wide Capsule": string,
@@ -63,6 +209,12 @@ type LibraryCacheEntry = {
   "Icon": string,
 }
 
+type LibraryCacheArt = {
+  appId: string,
+  artName: string,
+  path: string
+}
+
 type ChangedPath = {
   appId: string,
   gridType: string,